@@ -3,7 +3,9 @@ use sawtooth_sdk::messages::batch::BatchList;
 
 use common::addressing;
 use common::proto::organization::Organization_Authorization_Role;
-use common::proto::payload::{AuthorizeAgentAction, CreateAgentAction};
+use common::proto::payload::{
+    AuthorizeAgentAction, CreateAgentAction, RevokeAgentAuthorizationAction,
+};
 use common::proto::payload::{CertificateRegistryPayload, CertificateRegistryPayload_Action};
 use error::CliError;
 use transaction::{create_batch, create_batch_list_from_one, create_transaction};
@@ -19,6 +21,7 @@ pub fn run<'a>(args: &ArgMatches<'a>) -> Result<(), CliError> {
     match args.subcommand() {
         ("create", Some(args)) => run_create_command(args),
         ("authorize", Some(args)) => run_authorize_command(args),
+        ("revoke", Some(args)) => run_revoke_command(args),
         _ => Err(CliError::InvalidInputError(String::from(
             "Invalid subcommand. Pass --help for usage",
         ))),
@@ -79,6 +82,34 @@ fn run_authorize_command<'a>(args: &ArgMatches<'a>) -> Result<(), CliError> {
     agent_status_handler(url, &batch_list)
 }
 
+fn run_revoke_command<'a>(args: &ArgMatches<'a>) -> Result<(), CliError> {
+    let agent_to_be_revoked = args.value_of("revoke_agent").unwrap(); // Pub key of agent we want to revoke
+    let org_id = args.value_of("org_id").unwrap();
+    let role = args.value_of("role").unwrap();
+    let url = args.value_of("url").unwrap_or("http://localhost:9009");
+    let key = args.value_of("key"); // Priv key file of the admin doing the revoking
+
+    let private_key = key::load_signing_key(key)?;
+    let context = signing::create_context("secp256k1")?;
+    let public_key = context.get_public_key(&private_key)?.as_hex();
+    let factory = signing::CryptoFactory::new(&*context);
+    let signer = factory.new_signer(&private_key);
+
+    let payload = revoke_agent_authorization_payload(agent_to_be_revoked, role)?;
+    let addresses_input =
+        authorize_agent_transaction_addresses_input(&public_key, &org_id, &agent_to_be_revoked);
+    let addresses_output = vec![
+        addressing::make_organization_address(&org_id),
+        addressing::make_agent_address(&agent_to_be_revoked),
+    ];
+
+    let txn = create_transaction(&payload, &signer, addresses_input, addresses_output)?;
+    let batch = create_batch(txn, &signer)?;
+    let batch_list = create_batch_list_from_one(batch);
+
+    agent_status_handler(url, &batch_list)
+}
+
 fn agent_status_handler(url: &str, batch_list: &BatchList) -> Result<(), CliError> {
     let mut agent_status = submit::submit_batch_list(url, batch_list)
         .and_then(|link| submit::wait_for_status(url, &link))?;
@@ -143,6 +174,30 @@ fn authorize_agent_payload(pub_key: &str, role: &str) -> CertificateRegistryPayl
     payload
 }
 
+/// Returns a payload for revoking a role from an Agent
+fn revoke_agent_authorization_payload(
+    pub_key: &str,
+    role: &str,
+) -> Result<CertificateRegistryPayload, CliError> {
+    let mut revoke = RevokeAgentAuthorizationAction::new();
+    revoke.set_public_key(String::from(pub_key));
+    match role {
+        "1" => revoke.set_role(Organization_Authorization_Role::ADMIN),
+        "2" => revoke.set_role(Organization_Authorization_Role::TRANSACTOR),
+        x => {
+            return Err(CliError::UserError(format!(
+                "Unexpected invalid role {:?}",
+                x
+            )))
+        }
+    }
+
+    let mut payload = CertificateRegistryPayload::new();
+    payload.action = CertificateRegistryPayload_Action::REVOKE_AGENT_AUTHORIZATION;
+    payload.set_revoke_agent_authorization(revoke);
+    Ok(payload)
+}
+
 pub fn create_agent_transaction_addresses(public_key: &str) -> Vec<String> {
     let agent_address = addressing::make_agent_address(public_key);
     vec![agent_address]
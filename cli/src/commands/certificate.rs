@@ -32,7 +32,9 @@ fn run_create_command<'a>(args: &ArgMatches<'a>) -> Result<(), CliError> {
     let factory_id = args.value_of("factory_id").unwrap();
     let source = args.value_of("source").unwrap();
     let request_id = args.value_of("request_id");
+    let audit_id = args.value_of("audit_id");
     let standard_id = args.value_of("standard_id").unwrap();
+    let standard_version = args.value_of("standard_version").unwrap();
     let valid_from = args.value_of("valid_from").unwrap();
     let valid_to = args.value_of("valid_to").unwrap();
 
@@ -70,19 +72,29 @@ fn run_create_command<'a>(args: &ArgMatches<'a>) -> Result<(), CliError> {
         factory_id,
         source,
         request_id,
+        audit_id,
         standard_id,
+        standard_version,
         cert_data?,
         &valid_from,
         &valid_to,
     )?;
 
-    let mut header_input =
-        make_header_input(&public_key, &certifying_body_id, &cert_id, &factory_id);
+    let mut header_input = make_header_input(
+        &public_key,
+        &certifying_body_id,
+        &cert_id,
+        &factory_id,
+        &standard_id,
+    );
     let mut header_output = vec![addressing::make_certificate_address(cert_id)];
     if payload.get_issue_certificate().get_source() == IssueCertificateAction_Source::FROM_REQUEST {
         let request_address = addressing::make_request_address(request_id.unwrap());
         header_input.push(request_address.clone());
         header_output.push(request_address.clone());
+        if let Some(audit_id) = audit_id {
+            header_input.push(addressing::make_audit_address(audit_id));
+        }
     }
     let txn = create_transaction(&payload, &signer, header_input, header_output)?;
     let batch = create_batch(txn, &signer)?;
@@ -124,7 +136,9 @@ fn issue_certificate_payload(
     factory_id: &str,
     source: &str,
     request_id: Option<&str>,
+    audit_id: Option<&str>,
     standard_id: &str,
+    standard_version: &str,
     cert_data: Vec<Certificate_CertificateData>,
     valid_from: &str,
     valid_to: &str,
@@ -139,6 +153,9 @@ fn issue_certificate_payload(
                 ));
             }
             certificate.set_request_id(request_id.unwrap().to_string());
+            if let Some(audit_id) = audit_id {
+                certificate.set_audit_id(audit_id.to_string());
+            }
             Ok(IssueCertificateAction_Source::FROM_REQUEST)
         }
         "2" => {
@@ -151,6 +168,7 @@ fn issue_certificate_payload(
         )),
     }?;
     certificate.set_source(source_enum);
+    certificate.set_standard_version(standard_version.to_string());
     certificate.set_certificate_data(::protobuf::RepeatedField::from_vec(cert_data));
     certificate.set_valid_from(valid_from.parse().unwrap());
     certificate.set_valid_to(valid_to.parse().unwrap());
@@ -166,10 +184,18 @@ fn make_header_input(
     certifying_body_id: &str,
     certificate_id: &str,
     factory_id: &str,
+    standard_id: &str,
 ) -> Vec<String> {
     let agent_address = addressing::make_agent_address(public_key);
     let org_address = addressing::make_organization_address(certifying_body_id);
     let cert_address = addressing::make_certificate_address(certificate_id);
     let factory_address = addressing::make_organization_address(factory_id);
-    vec![agent_address, org_address, cert_address, factory_address]
+    let standard_address = addressing::make_standard_address(standard_id);
+    vec![
+        agent_address,
+        org_address,
+        cert_address,
+        factory_address,
+        standard_address,
+    ]
 }
@@ -6,11 +6,15 @@ use std::time::{SystemTime, UNIX_EPOCH};
 
 use chrono::NaiveDate;
 use clap::ArgMatches;
-use common::proto::organization::Organization_Type;
+use common::proto::organization::{Organization_ApprovalStatus, Organization_Type};
+use common::proto::payload::{
+    BatchActionsAction, CertificateRegistryPayload, CertificateRegistryPayload_Action,
+};
 use protobuf;
 use protobuf::Message;
 use sawtooth_sdk::messages::batch::Batch;
 use sawtooth_sdk::messages::batch::BatchList;
+use sawtooth_sdk::messages::transaction::Transaction;
 use sawtooth_sdk::signing;
 use serde;
 use serde_yaml;
@@ -20,6 +24,10 @@ use commands::agent::{create_agent_payload, create_agent_transaction_addresses};
 use commands::organization::{
     create_organization_payload, create_organization_transaction_addresses,
 };
+use commands::registry::{
+    review_organization_payload, review_organization_transaction_addresses,
+    update_registry_operators_payload, update_registry_operators_transaction_addresses,
+};
 use commands::standard::{create_standard_payload, create_standard_transaction_addresses};
 use error::CliError;
 use transaction::{create_batch, create_transaction};
@@ -92,22 +100,47 @@ pub fn run<'a>(args: &ArgMatches<'a>) -> Result<(), CliError> {
 
     let mut batches = vec![];
 
+    // The registry operator approves the standards bodies and certifying bodies created below.
+    // Its public key must be listed in the consensource.registry.operators setting of the
+    // genesis block, so it is written to the keys directory as registry-operator.pub.
+    let operator_private_key = context.new_random_private_key()?;
+    let operator_signer = factory.new_signer(&*operator_private_key);
+    let operator_public_key = operator_signer.get_public_key()?.as_hex();
+
+    let payload = update_registry_operators_payload(&[&operator_public_key]);
+    let (inputs, outputs) = update_registry_operators_transaction_addresses();
+    let txn = create_transaction(&payload, &operator_signer, inputs, outputs)?;
+    batches.push(create_batch(txn, &operator_signer)?);
+
+    if let Some(key_dir) = generated_keys_dir {
+        store_key(
+            &operator_signer,
+            &*operator_private_key,
+            "registry-operator",
+            key_dir,
+        )?;
+    }
+
     for agent in agents {
         let private_key = context.new_random_private_key()?;
         let signer = factory.new_signer(&*private_key);
 
         let create_time = current_epoch_time();
         let payload = create_agent_payload(&agent.email, create_time);
-
-        let header_input = create_agent_transaction_addresses(&signer.get_public_key()?.as_hex());
-        let header_output = header_input.clone();
-        let txn = create_transaction(&payload, &signer, header_input, header_output)?;
-        let batch = create_batch(txn, &signer)?;
-        batches.push(batch);
-
-        if let Some(org) = agent.organization {
-            let mut org_batches = create_org_batches(&signer, &org)?;
-            batches.append(&mut org_batches);
+        let addresses = create_agent_transaction_addresses(&signer.get_public_key()?.as_hex());
+
+        match agent.organization {
+            // The agent and its organization are created together, so a failure cannot
+            // leave an agent without its organization
+            Some(org) => {
+                let mut org_batches =
+                    create_org_batches(&signer, &operator_signer, &org, (payload, addresses))?;
+                batches.append(&mut org_batches);
+            }
+            None => {
+                let txn = create_transaction(&payload, &signer, addresses.clone(), addresses)?;
+                batches.push(create_batch(txn, &signer)?);
+            }
         }
 
         if let Some(key_dir) = generated_keys_dir {
@@ -128,7 +161,9 @@ pub fn run<'a>(args: &ArgMatches<'a>) -> Result<(), CliError> {
 
 fn create_org_batches<'s>(
     signer: &'s signing::Signer,
+    operator_signer: &'s signing::Signer,
     org: &GenesisOrganization,
+    create_agent: (CertificateRegistryPayload, Vec<String>),
 ) -> Result<Vec<Batch>, CliError> {
     let mut batches = vec![];
     let org_id = Uuid::new_v4().to_string();
@@ -177,14 +212,28 @@ fn create_org_batches<'s>(
         address.as_ref().map(|a| &*a.country.as_str()),
     );
 
-    let header_input =
+    let addresses =
         create_organization_transaction_addresses(&signer.get_public_key()?.as_hex(), &org_id);
-    let header_output = header_input.clone();
 
-    let txn = create_transaction(&payload, &signer, header_input, header_output)?;
+    let txn = create_batch_actions_transaction(
+        &signer,
+        vec![
+            (create_agent.0, create_agent.1.clone(), create_agent.1),
+            (payload, addresses.clone(), addresses),
+        ],
+    )?;
     batches.push(create_batch(txn, &signer)?);
 
+    if organization_type != Organization_Type::FACTORY {
+        let payload = review_organization_payload(&org_id, Organization_ApprovalStatus::APPROVED);
+        let (inputs, outputs) = review_organization_transaction_addresses(&org_id);
+        let txn = create_transaction(&payload, &operator_signer, inputs, outputs)?;
+        batches.push(create_batch(txn, &operator_signer)?);
+    }
+
+    // A standards body's standards are created together once it has been approved
     if let Some(standards) = standards {
+        let mut actions = vec![];
         for standard in standards {
             let payload = create_standard_payload(
                 &standard.name,
@@ -198,7 +247,10 @@ fn create_org_batches<'s>(
                 payload.get_create_standard().get_standard_id(),
                 &org_id,
             )?;
-            let txn = create_transaction(&payload, &signer, inputs, outputs)?;
+            actions.push((payload, inputs, outputs));
+        }
+        if !actions.is_empty() {
+            let txn = create_batch_actions_transaction(&signer, actions)?;
             batches.push(create_batch(txn, &signer)?);
         }
     }
@@ -206,6 +258,35 @@ fn create_org_batches<'s>(
     Ok(batches)
 }
 
+/// Returns a Transaction with a BATCH_ACTIONS payload that applies the provided payloads in
+/// order. Its inputs and outputs are the union of the payloads' inputs and outputs.
+fn create_batch_actions_transaction(
+    signer: &signing::Signer,
+    actions: Vec<(CertificateRegistryPayload, Vec<String>, Vec<String>)>,
+) -> Result<Transaction, CliError> {
+    let mut payloads = vec![];
+    let mut inputs = vec![];
+    let mut outputs = vec![];
+    for (payload, mut action_inputs, mut action_outputs) in actions {
+        payloads.push(payload);
+        inputs.append(&mut action_inputs);
+        outputs.append(&mut action_outputs);
+    }
+    inputs.sort();
+    inputs.dedup();
+    outputs.sort();
+    outputs.dedup();
+
+    let mut batch_actions = BatchActionsAction::new();
+    batch_actions.set_actions(protobuf::RepeatedField::from_vec(payloads));
+
+    let mut payload = CertificateRegistryPayload::new();
+    payload.action = CertificateRegistryPayload_Action::BATCH_ACTIONS;
+    payload.set_batch_actions(batch_actions);
+
+    create_transaction(&payload, signer, inputs, outputs)
+}
+
 fn store_key(
     signer: &signing::Signer,
     private_key: &signing::PrivateKey,
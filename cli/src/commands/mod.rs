@@ -3,4 +3,5 @@ pub mod agent;
 pub mod certificate;
 pub mod genesis;
 pub mod organization;
+pub mod registry;
 pub mod standard;
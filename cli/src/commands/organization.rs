@@ -43,7 +43,7 @@ fn run_create_command<'a>(args: &ArgMatches<'a>) -> Result<(), CliError> {
         "1" => Ok(Organization_Type::CERTIFYING_BODY),
         "2" => Ok(Organization_Type::STANDARDS_BODY),
         "3" => Ok(Organization_Type::FACTORY),
-        "4" => Err(CliError::InvalidInputError(format!("Not implemented"))),
+        "4" => Ok(Organization_Type::RETAILER),
         other => Err(CliError::UserError(format!(
             "Invalid organization type: {:?}.
             It can be 1 for CERTIFYING_BODY or 2 for STANDARDS_BODY ",
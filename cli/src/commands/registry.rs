@@ -0,0 +1,163 @@
+use clap::ArgMatches;
+
+use common::addressing;
+use common::proto::organization::Organization_ApprovalStatus;
+use common::proto::payload::{ReviewOrganizationAction, UpdateRegistryOperatorsAction};
+
+use common::proto::payload::{CertificateRegistryPayload, CertificateRegistryPayload_Action};
+use error::CliError;
+use transaction::{create_batch, create_batch_list_from_one, create_transaction};
+
+use key;
+use protobuf;
+use sawtooth_sdk::messages::batch::BatchList;
+use sawtooth_sdk::signing;
+use submit;
+
+use std::{thread, time};
+
+pub fn run<'a>(args: &ArgMatches<'a>) -> Result<(), CliError> {
+    match args.subcommand() {
+        ("operators", Some(args)) => run_operators_command(args),
+        ("review", Some(args)) => run_review_command(args),
+        _ => Err(CliError::InvalidInputError(String::from(
+            "Invalid subcommand. Pass --help for usage",
+        ))),
+    }
+}
+
+fn run_operators_command<'a>(args: &ArgMatches<'a>) -> Result<(), CliError> {
+    let operators: Vec<&str> = args.values_of("operators").unwrap().collect();
+    let key = args.value_of("key");
+    let url = args.value_of("url").unwrap_or("http://localhost:9009");
+
+    let private_key = key::load_signing_key(key)?;
+    let context = signing::create_context("secp256k1")?;
+    let factory = signing::CryptoFactory::new(&*context);
+    let signer = factory.new_signer(&private_key);
+
+    let payload = update_registry_operators_payload(&operators);
+    let (inputs, outputs) = update_registry_operators_transaction_addresses();
+
+    let txn = create_transaction(&payload, &signer, inputs, outputs)?;
+    let batch = create_batch(txn, &signer)?;
+    let batch_list = create_batch_list_from_one(batch);
+
+    registry_status_handler(url, &batch_list)
+}
+
+fn run_review_command<'a>(args: &ArgMatches<'a>) -> Result<(), CliError> {
+    let organization_id = args.value_of("organization_id").unwrap();
+    let decision = args.value_of("decision").unwrap();
+    let key = args.value_of("key");
+    let url = args.value_of("url").unwrap_or("http://localhost:9009");
+
+    let approval_status = match decision {
+        "1" => Organization_ApprovalStatus::APPROVED,
+        "2" => Organization_ApprovalStatus::REJECTED,
+        x => {
+            return Err(CliError::UserError(format!(
+                "Unexpected invalid decision {:?}",
+                x
+            )))
+        }
+    };
+
+    let private_key = key::load_signing_key(key)?;
+    let context = signing::create_context("secp256k1")?;
+    let factory = signing::CryptoFactory::new(&*context);
+    let signer = factory.new_signer(&private_key);
+
+    let payload = review_organization_payload(organization_id, approval_status);
+    let (inputs, outputs) = review_organization_transaction_addresses(organization_id);
+
+    let txn = create_transaction(&payload, &signer, inputs, outputs)?;
+    let batch = create_batch(txn, &signer)?;
+    let batch_list = create_batch_list_from_one(batch);
+
+    registry_status_handler(url, &batch_list)
+}
+
+fn registry_status_handler(url: &str, batch_list: &BatchList) -> Result<(), CliError> {
+    let mut status = submit::submit_batch_list(url, batch_list)
+        .and_then(|link| submit::wait_for_status(url, &link))?;
+
+    loop {
+        match status
+            .data
+            .get(0)
+            .expect("Expected a batch status, but was not found")
+            .status
+            .as_ref()
+        {
+            "COMMITTED" => break Ok(()),
+            "INVALID" => {
+                break Err(CliError::InvalidTransactionError(
+                    status.data[0]
+                        .invalid_transactions
+                        .get(0)
+                        .expect("Expected a transaction status, but was not found")
+                        .message
+                        .clone(),
+                ));
+            }
+            // "PENDING" case where we should recheck
+            _ => {
+                thread::sleep(time::Duration::from_millis(3000));
+                status = submit::wait_for_status(url, &status.link)?;
+            }
+        }
+    }
+}
+
+/// Returns a payload for replacing the registry operators
+pub fn update_registry_operators_payload(operators: &[&str]) -> CertificateRegistryPayload {
+    let mut update_operators = UpdateRegistryOperatorsAction::new();
+    update_operators.set_operators(protobuf::RepeatedField::from_vec(
+        operators
+            .iter()
+            .map(|operator| operator.to_string())
+            .collect(),
+    ));
+
+    let mut payload = CertificateRegistryPayload::new();
+    payload.action = CertificateRegistryPayload_Action::UPDATE_REGISTRY_OPERATORS;
+    payload.set_update_registry_operators(update_operators);
+    payload
+}
+
+/// Returns a payload for approving or rejecting an organization
+pub fn review_organization_payload(
+    organization_id: &str,
+    approval_status: Organization_ApprovalStatus,
+) -> CertificateRegistryPayload {
+    let mut review = ReviewOrganizationAction::new();
+    review.set_organization_id(String::from(organization_id));
+    review.set_approval_status(approval_status);
+
+    let mut payload = CertificateRegistryPayload::new();
+    payload.action = CertificateRegistryPayload_Action::REVIEW_ORGANIZATION;
+    payload.set_review_organization(review);
+    payload
+}
+
+pub fn update_registry_operators_transaction_addresses() -> (Vec<String>, Vec<String>) {
+    let registry_address = addressing::make_registry_address();
+    // The setting is read when the first submission creates the registry
+    let setting_address = addressing::make_setting_address(addressing::REGISTRY_OPERATORS_SETTING);
+    (
+        vec![registry_address.clone(), setting_address],
+        vec![registry_address],
+    )
+}
+
+pub fn review_organization_transaction_addresses(
+    organization_id: &str,
+) -> (Vec<String>, Vec<String>) {
+    let registry_address = addressing::make_registry_address();
+    let organization_address = addressing::make_organization_address(organization_id);
+    (
+        vec![registry_address, organization_address.clone()],
+        vec![organization_address],
+    )
+}
@@ -53,6 +53,7 @@ fn main() {
         ("certificate", Some(args)) => commands::certificate::run(args),
         ("standard", Some(args)) => commands::standard::run(args),
         ("accreditation", Some(args)) => commands::accreditation::run(args),
+        ("registry", Some(args)) => commands::registry::run(args),
         _ => Err(CliError::InvalidInputError(String::from(
             "Invalid subcommand. Pass --help for usage",
         ))),
@@ -89,10 +90,18 @@ fn parse_args<'a>() -> ArgMatches<'a> {
                 (@arg key: -k --key +takes_value "Signing key of the admin doing the authoriation")
                 (@arg url: --url +takes_value "URL to the Sawtooth REST API")
             )
+            (@subcommand revoke =>
+                (about: "revoke a role from an agent")
+                (@arg revoke_agent: +required "Pub key of the agent we are revoking")
+                (@arg org_id: +required "Organization agent is associated with")
+                (@arg role: +required "Role to revoke: 1 (ADMIN) or 2 (TRANSACTOR)")
+                (@arg key: -k --key +takes_value "Signing key of the admin doing the revocation")
+                (@arg url: --url +takes_value "URL to the Sawtooth REST API")
+            )
         )
 
         (@subcommand genesis =>
-            (about: "Generate batches in order to bootstrap a genesis block")
+            (about: "Generate batches in order to bootstrap the registry once the genesis block is committed")
             (@arg dry_run: --("dry-run")
              "Processes the input and generates the transactions, but does not generate the output")
             (@arg output: -o --output +takes_value default_value("creg-genesis.batch")
@@ -108,7 +117,7 @@ fn parse_args<'a>() -> ArgMatches<'a> {
                 (about: "create an organization")
                 (@arg name: +required "Name of the organization to be created")
                 (@arg org_type: +required "Type of the organization to be created:
-                1 (CERTIFYING_BODY), 2 (STANDARDS_BODY), or 3 (FACTORY)")
+                1 (CERTIFYING_BODY), 2 (STANDARDS_BODY), 3 (FACTORY), or 4 (RETAILER)")
                 (@arg contact_name: +required "Name of the organization's contact")
                 (@arg contact_phone_number: +required "Phone number of the organization's contact")
                 (@arg contact_language_code: +required "Language of the organization's contact")
@@ -132,7 +141,9 @@ fn parse_args<'a>() -> ArgMatches<'a> {
                 2 (INDEPENDENT):  it means the IssueCertificateAction is not associated with a request made by a factory.
                 The field factory_name must passed as well")
                 (@arg request_id: --request_id +takes_value "Id of the certificate request made by the factory")
+                (@arg audit_id: --audit_id +takes_value "Id of the passed audit recorded for the request")
                 (@arg standard_id: "Standard that this certificate is for")
+                (@arg standard_version: --standard_version +takes_value +required "Version of the standard that this certificate is for")
                 (@arg cert_data: -cd --cert_data +takes_value +multiple "Optional cert data")
                 (@arg valid_from: +required "Start timestamp of the certificate")
                 (@arg valid_to: +required "End timestamp of the certificate")
@@ -167,6 +178,22 @@ fn parse_args<'a>() -> ArgMatches<'a> {
                 (@arg url: --url +takes_value "URL to the Sawtooth REST API")
             )
         )
+        (@subcommand registry =>
+            (about: "manage registry governance")
+            (@subcommand operators =>
+                (about: "replace the set of registry operators")
+                (@arg operators: +required +multiple "Pub keys of the registry operators")
+                (@arg key: -k --key +takes_value "Signing key of a current registry operator")
+                (@arg url: --url +takes_value "URL to the Sawtooth REST API")
+            )
+            (@subcommand review =>
+                (about: "approve or reject a standards body or certifying body")
+                (@arg organization_id: +required "Id of the organization being reviewed")
+                (@arg decision: +required "Decision: 1 (APPROVED) or 2 (REJECTED)")
+                (@arg key: -k --key +takes_value "Signing key of a registry operator")
+                (@arg url: --url +takes_value "URL to the Sawtooth REST API")
+            )
+        )
     );
     app.get_matches()
 }
@@ -81,6 +81,9 @@ pub fn create_transaction(
     txn_header.set_signer_public_key(signer.get_public_key()?.as_hex());
     txn_header.set_batcher_public_key(signer.get_public_key()?.as_hex());
 
+    // Every transaction may read the block time recorded by BlockInfo
+    let mut inputs = inputs;
+    inputs.push(addressing::BLOCK_INFO_NAMESPACE.to_string());
     txn_header.set_inputs(protobuf::RepeatedField::from_vec(inputs));
     txn_header.set_outputs(protobuf::RepeatedField::from_vec(outputs));
 
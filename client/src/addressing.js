@@ -1,7 +1,7 @@
 const crypto = require("crypto")
 
 const FAMILY_NAME = "certificate_registry"
-const FAMILY_VERSION = "0.1"
+const FAMILY_VERSION = "0.2"
 const AGENT = "00"
 const CERTIFICATE = "01"
 const ORGANIZATION = "02"
@@ -10,6 +10,7 @@ const CERTIFICATE_REQUEST = "04"
 
 const PREFIX_SIZE = 6
 const RESERVED_SPACE = "00"
+const BLOCK_INFO_NAMESPACE = "00b10c"
 
 function hash(object, num) {
   let sha = crypto.createHash("sha256")
@@ -42,6 +43,7 @@ module.exports = {
 
   familyName: FAMILY_NAME,
   familyVersion: FAMILY_VERSION,
+  blockInfoNamespace: BLOCK_INFO_NAMESPACE,
 
   agentAddressPrefix: AGENT_ADDRESS_PREFIX,
   organizationAddressPrefix: ORGANIZATION_ADDRESS_PREFIX,
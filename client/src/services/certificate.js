@@ -29,6 +29,7 @@ const issueCertificate = (issueCertificateData, orgId, factoryId, signer) => {
       requestId: issueCertificateData.requestId,
       validFrom: issueCertificateData.validFrom,
       validTo: issueCertificateData.validTo,
+      standardVersion: issueCertificateData.standardVersion,
       source: IssueCertificateAction.Source.FROM_REQUEST
     })
 
@@ -42,9 +43,10 @@ const issueCertificate = (issueCertificateData, orgId, factoryId, signer) => {
     let agentAddress = addressing.makeAgentAddress(signer.getPublicKey().asHex())
     let organizationAddress = addressing.makeOrganizationAddress(orgId)
     let certificateAddress = addressing.makeCertificateAddress(issueCertificateData.id)
+    let standardAddress = addressing.makeStandardAddress(issueCertificateData.standardId)
 
     return transactionService.submitTransaction({
-        payloadBytes, inputs: [factoryAddress, certRequestAddress, agentAddress, organizationAddress, certificateAddress],
+        payloadBytes, inputs: [factoryAddress, certRequestAddress, agentAddress, organizationAddress, certificateAddress, standardAddress],
         outputs: [certRequestAddress, certificateAddress]
     }, signer)
 
@@ -31,7 +31,8 @@ const openCertificateRequest = (certRequest, signer) => {
     let requestAction = OpenRequestAction.create({
         id: requestId,
         standardId: certRequest.standardId,
-        requestDate: certRequest.requestDate
+        requestDate: certRequest.requestDate,
+        certifyingBodyId: certRequest.certifyingBodyId
     })
 
     let payloadBytes = CertificateRegistryPayload.encode({
@@ -43,8 +44,9 @@ const openCertificateRequest = (certRequest, signer) => {
     let certRequestAddress = addressing.makeCertificateRequestAddress(requestId)
     let factoryAddress = addressing.makeOrganizationAddress(certRequest.factoryId)
     let standardAddress = addressing.makeStandardAddress(certRequest.standardId)
+    let certifyingBodyAddress = addressing.makeOrganizationAddress(certRequest.certifyingBodyId)
 
-    let inputs = [agentAddress, certRequestAddress, factoryAddress, standardAddress]
+    let inputs = [agentAddress, certRequestAddress, factoryAddress, standardAddress, certifyingBodyAddress]
     let outputs = [certRequestAddress]
 
     return transactionService.submitTransaction({
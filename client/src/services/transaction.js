@@ -24,7 +24,8 @@ const createTransaction = (payloadInfo, signer) => {
   const transactionHeaderBytes = TransactionHeader.encode({
     familyName: addressing.familyName,
     familyVersion: addressing.familyVersion,
-    inputs,
+    // Every transaction may read the block time recorded by BlockInfo
+    inputs: inputs.concat([addressing.blockInfoNamespace]),
     outputs,
     signerPublicKey: pubkey,
     batcherPublicKey: pubkey,
@@ -12,6 +12,8 @@ var DatePicker = require('mithril-datepicker')
 var IssueCertificateData = {
     id: "",
     requestId: "",
+    standardId: "",
+    standardVersion: "",
     validFrom: new Date().getTime() / 1000,
     validTo: 0,
     certificateData: [],
@@ -27,6 +29,14 @@ var IssueCertificateData = {
         IssueCertificateData.requestId = requestId
     },
 
+    setStandardId: (standardId) => {
+        IssueCertificateData.standardId = standardId
+    },
+
+    setStandardVersion: (standardVersion) => {
+        IssueCertificateData.standardVersion = standardVersion
+    },
+
     setValidFrom: (timestamp) => {
         IssueCertificateData.validFrom = timestamp
     },
@@ -42,6 +52,8 @@ var IssueCertificateData = {
     clear: () => {
       IssueCertificateData.id = ''
       IssueCertificateData.requestId = ''
+      IssueCertificateData.standardId = ''
+      IssueCertificateData.standardVersion = ''
       IssueCertificateData.validFrom = new Date().getTime() / 1000
       IssueCertificateData.validTo = 0
       IssueCertificateData.certificateData = []
@@ -145,14 +157,24 @@ var CertificateCreate = {
                 m('label[for=certificateStandard]', 'Standard'),
                 m("input.form-control-plaintext[type=text][readonly=true]",  {value: vnode.state.request.standard.name }),
               ]),
+              m('div.form-group.row', [
+                m('label[for=certificateStandardVersion]', 'Standard Version'),
+                m('select.form-control', {
+                  oninput: m.withAttr("value", IssueCertificateData.setStandardVersion),
+                  value: IssueCertificateData.standardVersion
+                }, m('option[selected="selected"][value=""][disabled="disabled"]', 'Choose the version you are accredited for'),
+                vnode.state.request.standard.versions.map((version) =>
+                  m('option', { value: version.version, text: version.version })))
+              ]),
             ]),
           ]),
             m("button.btn.btn-primary", {
                 onclick: () => {
                   IssueCertificateData.setRequestId(vnode.state.request.id)
+                  IssueCertificateData.setStandardId(vnode.state.request.standard.standard_id)
                   IssueCertificateData.submit(vnode.state.agent.organization.id, vnode.state.request.factory.id)
                 },
-                disabled: IssueCertificateData.submitting,
+                disabled: IssueCertificateData.submitting || IssueCertificateData.standardVersion === "",
             }, "Issue Certificate"),
         ]),
     ]
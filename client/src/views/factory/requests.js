@@ -5,6 +5,7 @@ const authService = require('App/services/auth')
 const blockService = require('App/services/block')
 const certRequestService = require('App/services/certificate_request')
 const standardService = require('App/services/standards')
+const organizationService = require('App/services/organization')
 const { Request: RequestProto } = require('App/protobuf')
 const agentService = require('App/services/agent')
 const factoryService = require('App/services/factory')
@@ -14,6 +15,7 @@ var CertificateRequest = {
   requestId: "",
   status: 0,
   standardId: "",
+  certifyingBodyId: "",
   factoryId: "",
   requestDate: 0,
 
@@ -26,6 +28,10 @@ var CertificateRequest = {
     CertificateRequest.standardId = standardId
   },
 
+  setCertifyingBodyId: (certifyingBodyId) => {
+    CertificateRequest.certifyingBodyId = certifyingBodyId
+  },
+
   initialize: (factory) => {
     CertificateRequest.factoryId = factory.id
   },
@@ -38,6 +44,7 @@ var CertificateRequest = {
     CertificateRequest.requestId = ''
     CertificateRequest.status = 0
     CertificateRequest.standardId = ''
+    CertificateRequest.certifyingBodyId = ''
     CertificateRequest.factoryId = ''
     CertificateRequest.requestDate = 0
   },
@@ -112,6 +119,35 @@ const CertificationStandards = {
   }
 }
 
+const CertifyingBodies = {
+  list: [],
+  names: {},
+  get: () => {
+    organizationService.loadOrganizations({'organization_type': 1})
+      .then((certifyingBodies) => {
+        certifyingBodies.data.forEach((certifyingBody) => {
+          CertifyingBodies.names[certifyingBody.id] = certifyingBody.name
+          // Requests can only be addressed to certifying bodies a registry operator approved
+          if (certifyingBody.approval_status === 'Pending' ||
+              certifyingBody.approval_status === 'Rejected') {
+            return
+          }
+          CertifyingBodies.list.push(m('option', {
+            value: certifyingBody.id,
+            text: certifyingBody.name
+          }))
+        })
+      })
+  }
+}
+
+const _renderCertifyingBody = (certifyingBodyId) => {
+  if (!certifyingBodyId) {
+    return m('span.dash', '—')
+  }
+  return CertifyingBodies.names[certifyingBodyId] || certifyingBodyId
+}
+
 const _formatDate = (requestDate) => {
   // Convert back to milli
   var currentdate = new Date(requestDate * 1000)
@@ -131,7 +167,7 @@ const _renderRows = (items, renderer, emptyElement) => {
 
 const _renderActionButton = (status, requestId, vnode) => {
   let actions = []
-  if (status === "Open") {
+  if (status === "Accepted") {
     actions.push(m("button.btn.action-btn[type=submit]", {
       onclick: (e) => {
         e.preventDefault()
@@ -193,6 +229,7 @@ const FactoryRequestForm = {
     blockService.addBlockUpdateListener(vnode.state._listener)
     FactoryRequestForm.loadData(vnode)
     CertificationStandards.get()
+    CertifyingBodies.get()
   },
 
   onremove: (vnode) => {
@@ -212,6 +249,7 @@ const FactoryRequestForm = {
           m('thead',  m('tr', [
             m('th[scope=col]', "Request Date"),
             m('th[scope=col]', "Standard"),
+            m('th[scope=col]', "Certifying Body"),
             m('th[scope=col]', "Status"),
             m('th[scope=col]', "Actions"),
           ])),
@@ -222,6 +260,10 @@ const FactoryRequestForm = {
                 oninput: m.withAttr("value", CertificateRequest.setStandardId),
                 value: CertificateRequest.standardId
               }, m('option[selected="selected"][value=""][disabled="disabled"]', 'Choose Standard'), CertificationStandards.list)),
+              m('td.pl-5',m('select.form-control.standard-select', {
+                oninput: m.withAttr("value", CertificateRequest.setCertifyingBodyId),
+                value: CertificateRequest.certifyingBodyId
+              }, m('option[selected="selected"][value=""][disabled="disabled"]', 'Choose Certifying Body'), CertifyingBodies.list)),
               m('td[align=center]', m('span.dash', '—')),
               m('td.pl-5', m("button.btn.btn.submitRequest", {
                 onclick: (e) => {
@@ -229,7 +271,9 @@ const FactoryRequestForm = {
                   CertificateRequest.submit()
                     .then(() => FactoryRequestForm.loadData(vnode))
                 },
-                disabled: CertificateRequest.submitting || CertificateRequest.standardId === ""
+                disabled: CertificateRequest.submitting ||
+                  CertificateRequest.standardId === "" ||
+                  CertificateRequest.certifyingBodyId === ""
 
               }, "Submit Request")),
             ]),
@@ -238,10 +282,11 @@ const FactoryRequestForm = {
               ((request) => [m(`tr.select-row`, [
                 m('td.pl-5', _formatDate(request.request_date)),
                 m('td.pl-5', request.standard.name),
+                m('td.pl-5', _renderCertifyingBody(request.certifying_body_id)),
                 m('td.pl-5', _renderRequestStatus(request.status)),
                 m('td.pl-5', _renderActionButton(request.status, request.id, vnode))
               ])]),
-              m('tr', m('td[colspan=4]', 'No open requests found')))
+              m('tr', m('td[colspan=5]', 'No open requests found')))
 
             )
         ]),
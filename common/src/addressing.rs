@@ -2,16 +2,36 @@ use crypto::digest::Digest;
 use crypto::sha2::Sha256;
 
 pub const FAMILY_NAMESPACE: &str = "certificate_registry";
-pub const FAMILY_VERSION: &str = "0.1";
+pub const FAMILY_VERSION: &str = "0.2";
+/// Every family version the transaction processor accepts, oldest first
+pub const FAMILY_VERSIONS: [&str; 2] = ["0.1", "0.2"];
 const AGENT: &str = "00";
 const CERTIFICATE: &str = "01";
 const ORGANIZATION: &str = "02";
 const STANDARD: &str = "03";
 const REQUEST: &str = "04";
+const REGISTRY: &str = "05";
+const AUDIT: &str = "06";
+const NON_CONFORMANCE: &str = "07";
+const PROPOSAL: &str = "08";
+const ACCREDITATION_REQUEST: &str = "09";
 
 const PREFIX_SIZE: usize = 6;
 const RESERVED_SPACE: &str = "00";
 
+/// Namespace of the Sawtooth BlockInfo transaction family
+pub const BLOCK_INFO_NAMESPACE: &str = "00b10c";
+const BLOCK_INFO_BLOCK: &str = "00";
+const BLOCK_INFO_CONFIG: &str = "01";
+
+/// Namespace of the Sawtooth Settings transaction family
+pub const SETTINGS_NAMESPACE: &str = "000000";
+const SETTING_KEY_PARTS: usize = 4;
+const SETTING_KEY_PART_SIZE: usize = 16;
+
+/// Setting listing the public keys permitted to create the registry
+pub const REGISTRY_OPERATORS_SETTING: &str = "consensource.registry.operators";
+
 fn hash(object: &str, num: usize) -> String {
     let mut sha = Sha256::new();
     sha.input_str(object);
@@ -48,6 +68,53 @@ pub fn make_standard_address(standard_id: &str) -> String {
     get_family_namespace_prefix() + RESERVED_SPACE + STANDARD + &hash(standard_id, 60)
 }
 
+/// Returns the address for an audit based on the provided audit id
+pub fn make_audit_address(audit_id: &str) -> String {
+    get_family_namespace_prefix() + RESERVED_SPACE + AUDIT + &hash(audit_id, 60)
+}
+
+/// Returns the address for a non-conformance based on the provided non-conformance id
+pub fn make_non_conformance_address(non_conformance_id: &str) -> String {
+    get_family_namespace_prefix() + RESERVED_SPACE + NON_CONFORMANCE + &hash(non_conformance_id, 60)
+}
+
+/// Returns the address for a proposal based on the provided proposal id
+pub fn make_proposal_address(proposal_id: &str) -> String {
+    get_family_namespace_prefix() + RESERVED_SPACE + PROPOSAL + &hash(proposal_id, 60)
+}
+
+/// Returns the address for an accreditation request based on the provided request id
+pub fn make_accreditation_request_address(request_id: &str) -> String {
+    get_family_namespace_prefix() + RESERVED_SPACE + ACCREDITATION_REQUEST + &hash(request_id, 60)
+}
+
+/// Returns the address of the registry governance settings
+pub fn make_registry_address() -> String {
+    get_family_namespace_prefix() + RESERVED_SPACE + REGISTRY + &hash(FAMILY_NAMESPACE, 60)
+}
+
+/// Returns the address of the BlockInfo family's config, which tracks the latest block
+pub fn make_block_info_config_address() -> String {
+    String::from(BLOCK_INFO_NAMESPACE) + BLOCK_INFO_CONFIG + &"0".repeat(62)
+}
+
+/// Returns the address of the BlockInfo family's record for the provided block number
+pub fn make_block_info_address(block_num: u64) -> String {
+    String::from(BLOCK_INFO_NAMESPACE) + BLOCK_INFO_BLOCK + &format!("{:062x}", block_num)
+}
+
+/// Returns the address of a Sawtooth setting. The key is split on '.' into at most four
+/// parts, the last of which keeps any remaining dots, and missing parts hash the empty string
+pub fn make_setting_address(key: &str) -> String {
+    let mut parts: Vec<&str> = key.splitn(SETTING_KEY_PARTS, '.').collect();
+    parts.resize(SETTING_KEY_PARTS, "");
+    parts
+        .iter()
+        .fold(String::from(SETTINGS_NAMESPACE), |address, part| {
+            address + &hash(part, SETTING_KEY_PART_SIZE)
+        })
+}
+
 #[derive(Debug)]
 pub enum AddressSpace {
     Organization,
@@ -55,6 +122,11 @@ pub enum AddressSpace {
     Certificate,
     Request,
     Standard,
+    Registry,
+    Audit,
+    NonConformance,
+    Proposal,
+    AccreditationRequest,
     AnotherFamily,
 }
 
@@ -74,6 +146,16 @@ pub fn get_address_type(address: &str) -> AddressSpace {
         AddressSpace::Request
     } else if infix == STANDARD {
         AddressSpace::Standard
+    } else if infix == REGISTRY {
+        AddressSpace::Registry
+    } else if infix == AUDIT {
+        AddressSpace::Audit
+    } else if infix == NON_CONFORMANCE {
+        AddressSpace::NonConformance
+    } else if infix == PROPOSAL {
+        AddressSpace::Proposal
+    } else if infix == ACCREDITATION_REQUEST {
+        AddressSpace::AccreditationRequest
     } else {
         AddressSpace::AnotherFamily
     }
@@ -0,0 +1,26 @@
+/// Prefix shared by every event emitted by the Certificate Registry family
+pub const EVENT_PREFIX: &str = "certificate_registry/";
+
+/// Emitted when a certificate is issued, including renewals
+pub const CERTIFICATE_ISSUED: &str = "certificate_registry/certificate-issued";
+/// Emitted when a certificate is revoked, suspended or reinstated
+pub const CERTIFICATE_STATUS_CHANGED: &str = "certificate_registry/certificate-status-changed";
+/// Emitted when a request is opened or its status changes
+pub const REQUEST_STATUS_CHANGED: &str = "certificate_registry/request-status-changed";
+/// Emitted when an accreditation request is opened or its status changes
+pub const ACCREDITATION_REQUEST_STATUS_CHANGED: &str =
+    "certificate_registry/accreditation-request-status-changed";
+/// Emitted when a certifying body is accredited for a standard version
+pub const ACCREDITATION_GRANTED: &str = "certificate_registry/accreditation-granted";
+/// Emitted when a certifying body's accreditation is revoked
+pub const ACCREDITATION_REVOKED: &str = "certificate_registry/accreditation-revoked";
+
+/// Every event type emitted by the Certificate Registry family
+pub const EVENT_TYPES: [&str; 6] = [
+    CERTIFICATE_ISSUED,
+    CERTIFICATE_STATUS_CHANGED,
+    REQUEST_STATUS_CHANGED,
+    ACCREDITATION_REQUEST_STATUS_CHANGED,
+    ACCREDITATION_GRANTED,
+    ACCREDITATION_REVOKED,
+];
@@ -5,3 +5,4 @@ include!("../build/gen_source.rs");
 
 // exported modules
 pub mod addressing;
+pub mod events;
@@ -14,6 +14,7 @@ pub struct Role;
 pub enum RoleEnum {
     Admin,
     Transactor,
+    Custom,
     UnsetRole,
 }
 
@@ -22,6 +23,7 @@ impl ToSql<Role, Pg> for RoleEnum {
         match *self {
             RoleEnum::Admin => out.write_all(b"ADMIN")?,
             RoleEnum::Transactor => out.write_all(b"TRANSACTOR")?,
+            RoleEnum::Custom => out.write_all(b"CUSTOM")?,
             RoleEnum::UnsetRole => out.write_all(b"UNSET_ROLE")?,
         }
         Ok(IsNull::No)
@@ -33,6 +35,7 @@ impl FromSql<Role, Pg> for RoleEnum {
         match not_none!(bytes) {
             b"ADMIN" => Ok(RoleEnum::Admin),
             b"TRANSACTOR" => Ok(RoleEnum::Transactor),
+            b"CUSTOM" => Ok(RoleEnum::Custom),
             b"UNSET_ROLE" => Ok(RoleEnum::UnsetRole),
             _ => Err("Unrecognized enum variant".into()),
         }
@@ -51,6 +54,7 @@ pub enum OrganizationTypeEnum {
     StandardsBody,
     CertifyingBody,
     Factory,
+    Retailer,
     UnsetType,
 }
 
@@ -60,6 +64,7 @@ impl ToSql<OrganizationType, Pg> for OrganizationTypeEnum {
             OrganizationTypeEnum::CertifyingBody => out.write_all(b"CERTIFYING_BODY")?,
             OrganizationTypeEnum::StandardsBody => out.write_all(b"STANDARDS_BODY")?,
             OrganizationTypeEnum::Factory => out.write_all(b"FACTORY")?,
+            OrganizationTypeEnum::Retailer => out.write_all(b"RETAILER")?,
             OrganizationTypeEnum::UnsetType => out.write_all(b"UNSET_TYPE")?,
         }
         Ok(IsNull::No)
@@ -72,6 +77,7 @@ impl FromSql<OrganizationType, Pg> for OrganizationTypeEnum {
             b"CERTIFYING_BODY" => Ok(OrganizationTypeEnum::CertifyingBody),
             b"STANDARDS_BODY" => Ok(OrganizationTypeEnum::StandardsBody),
             b"FACTORY" => Ok(OrganizationTypeEnum::Factory),
+            b"RETAILER" => Ok(OrganizationTypeEnum::Retailer),
             b"UNSET" => Ok(OrganizationTypeEnum::UnsetType),
             _ => Err("Unrecognized enum variant".into()),
         }
@@ -91,6 +97,8 @@ pub enum RequestStatusEnum {
     InProgress,
     Closed,
     Certified,
+    Accepted,
+    Declined,
     UnsetStatus,
 }
 
@@ -101,6 +109,8 @@ impl ToSql<RequestStatus, Pg> for RequestStatusEnum {
             RequestStatusEnum::InProgress => out.write_all(b"IN_PROGRESS")?,
             RequestStatusEnum::Closed => out.write_all(b"CLOSED")?,
             RequestStatusEnum::Certified => out.write_all(b"CERTIFIED")?,
+            RequestStatusEnum::Accepted => out.write_all(b"ACCEPTED")?,
+            RequestStatusEnum::Declined => out.write_all(b"DECLINED")?,
             RequestStatusEnum::UnsetStatus => out.write_all(b"UNSET_STATUS")?,
         }
         Ok(IsNull::No)
@@ -114,8 +124,377 @@ impl FromSql<RequestStatus, Pg> for RequestStatusEnum {
             b"IN_PROGRESS" => Ok(RequestStatusEnum::InProgress),
             b"CLOSED" => Ok(RequestStatusEnum::Closed),
             b"CERTIFIED" => Ok(RequestStatusEnum::Certified),
+            b"ACCEPTED" => Ok(RequestStatusEnum::Accepted),
+            b"DECLINED" => Ok(RequestStatusEnum::Declined),
             b"UNSET_STATUS" => Ok(RequestStatusEnum::UnsetStatus),
             _ => Err("Unrecognized enum variant".into()),
         }
     }
 }
+
+// CertificateStatus
+
+#[derive(SqlType)]
+#[postgres(type_name = "certificatestatus")]
+pub struct CertificateStatus;
+
+#[derive(Debug, PartialEq, FromSqlRow, AsExpression, Serialize, Clone)]
+#[sql_type = "CertificateStatus"]
+pub enum CertificateStatusEnum {
+    Valid,
+    Revoked,
+    Suspended,
+    UnsetStatus,
+}
+
+impl Default for CertificateStatusEnum {
+    fn default() -> Self {
+        CertificateStatusEnum::UnsetStatus
+    }
+}
+
+impl ToSql<CertificateStatus, Pg> for CertificateStatusEnum {
+    fn to_sql<W: Write>(&self, out: &mut Output<W, Pg>) -> serialize::Result {
+        match *self {
+            CertificateStatusEnum::Valid => out.write_all(b"VALID")?,
+            CertificateStatusEnum::Revoked => out.write_all(b"REVOKED")?,
+            CertificateStatusEnum::Suspended => out.write_all(b"SUSPENDED")?,
+            CertificateStatusEnum::UnsetStatus => out.write_all(b"UNSET_STATUS")?,
+        }
+        Ok(IsNull::No)
+    }
+}
+
+impl FromSql<CertificateStatus, Pg> for CertificateStatusEnum {
+    fn from_sql(bytes: Option<&[u8]>) -> deserialize::Result<Self> {
+        match not_none!(bytes) {
+            b"VALID" => Ok(CertificateStatusEnum::Valid),
+            b"REVOKED" => Ok(CertificateStatusEnum::Revoked),
+            b"SUSPENDED" => Ok(CertificateStatusEnum::Suspended),
+            b"UNSET_STATUS" => Ok(CertificateStatusEnum::UnsetStatus),
+            _ => Err("Unrecognized enum variant".into()),
+        }
+    }
+}
+
+// ApprovalStatus
+
+#[derive(SqlType, QueryId)]
+#[postgres(type_name = "approvalstatus")]
+pub struct ApprovalStatus;
+
+#[derive(Debug, PartialEq, FromSqlRow, AsExpression, Serialize, Clone)]
+#[sql_type = "ApprovalStatus"]
+pub enum ApprovalStatusEnum {
+    Pending,
+    Approved,
+    Rejected,
+    UnsetApproval,
+}
+
+impl ToSql<ApprovalStatus, Pg> for ApprovalStatusEnum {
+    fn to_sql<W: Write>(&self, out: &mut Output<W, Pg>) -> serialize::Result {
+        match *self {
+            ApprovalStatusEnum::Pending => out.write_all(b"PENDING")?,
+            ApprovalStatusEnum::Approved => out.write_all(b"APPROVED")?,
+            ApprovalStatusEnum::Rejected => out.write_all(b"REJECTED")?,
+            ApprovalStatusEnum::UnsetApproval => out.write_all(b"UNSET_APPROVAL")?,
+        }
+        Ok(IsNull::No)
+    }
+}
+
+impl FromSql<ApprovalStatus, Pg> for ApprovalStatusEnum {
+    fn from_sql(bytes: Option<&[u8]>) -> deserialize::Result<Self> {
+        match not_none!(bytes) {
+            b"PENDING" => Ok(ApprovalStatusEnum::Pending),
+            b"APPROVED" => Ok(ApprovalStatusEnum::Approved),
+            b"REJECTED" => Ok(ApprovalStatusEnum::Rejected),
+            b"UNSET_APPROVAL" => Ok(ApprovalStatusEnum::UnsetApproval),
+            _ => Err("Unrecognized enum variant".into()),
+        }
+    }
+}
+
+// AuditOutcome
+
+#[derive(SqlType, QueryId)]
+#[postgres(type_name = "auditoutcome")]
+pub struct AuditOutcome;
+
+#[derive(Debug, PartialEq, FromSqlRow, AsExpression, Serialize, Clone)]
+#[sql_type = "AuditOutcome"]
+pub enum AuditOutcomeEnum {
+    Passed,
+    Failed,
+    UnsetOutcome,
+}
+
+impl ToSql<AuditOutcome, Pg> for AuditOutcomeEnum {
+    fn to_sql<W: Write>(&self, out: &mut Output<W, Pg>) -> serialize::Result {
+        match *self {
+            AuditOutcomeEnum::Passed => out.write_all(b"PASSED")?,
+            AuditOutcomeEnum::Failed => out.write_all(b"FAILED")?,
+            AuditOutcomeEnum::UnsetOutcome => out.write_all(b"UNSET_OUTCOME")?,
+        }
+        Ok(IsNull::No)
+    }
+}
+
+impl FromSql<AuditOutcome, Pg> for AuditOutcomeEnum {
+    fn from_sql(bytes: Option<&[u8]>) -> deserialize::Result<Self> {
+        match not_none!(bytes) {
+            b"PASSED" => Ok(AuditOutcomeEnum::Passed),
+            b"FAILED" => Ok(AuditOutcomeEnum::Failed),
+            b"UNSET_OUTCOME" => Ok(AuditOutcomeEnum::UnsetOutcome),
+            _ => Err("Unrecognized enum variant".into()),
+        }
+    }
+}
+
+// FindingSeverity
+
+#[derive(SqlType, QueryId)]
+#[postgres(type_name = "findingseverity")]
+pub struct FindingSeverity;
+
+#[derive(Debug, PartialEq, FromSqlRow, AsExpression, Serialize, Clone)]
+#[sql_type = "FindingSeverity"]
+pub enum FindingSeverityEnum {
+    Minor,
+    Major,
+    Critical,
+    UnsetSeverity,
+}
+
+impl ToSql<FindingSeverity, Pg> for FindingSeverityEnum {
+    fn to_sql<W: Write>(&self, out: &mut Output<W, Pg>) -> serialize::Result {
+        match *self {
+            FindingSeverityEnum::Minor => out.write_all(b"MINOR")?,
+            FindingSeverityEnum::Major => out.write_all(b"MAJOR")?,
+            FindingSeverityEnum::Critical => out.write_all(b"CRITICAL")?,
+            FindingSeverityEnum::UnsetSeverity => out.write_all(b"UNSET_SEVERITY")?,
+        }
+        Ok(IsNull::No)
+    }
+}
+
+impl FromSql<FindingSeverity, Pg> for FindingSeverityEnum {
+    fn from_sql(bytes: Option<&[u8]>) -> deserialize::Result<Self> {
+        match not_none!(bytes) {
+            b"MINOR" => Ok(FindingSeverityEnum::Minor),
+            b"MAJOR" => Ok(FindingSeverityEnum::Major),
+            b"CRITICAL" => Ok(FindingSeverityEnum::Critical),
+            b"UNSET_SEVERITY" => Ok(FindingSeverityEnum::UnsetSeverity),
+            _ => Err("Unrecognized enum variant".into()),
+        }
+    }
+}
+
+// NonConformanceStatus
+
+#[derive(SqlType, QueryId)]
+#[postgres(type_name = "nonconformancestatus")]
+pub struct NonConformanceStatus;
+
+#[derive(Debug, PartialEq, FromSqlRow, AsExpression, Serialize, Clone)]
+#[sql_type = "NonConformanceStatus"]
+pub enum NonConformanceStatusEnum {
+    Open,
+    CorrectiveActionSubmitted,
+    Closed,
+    UnsetStatus,
+}
+
+impl ToSql<NonConformanceStatus, Pg> for NonConformanceStatusEnum {
+    fn to_sql<W: Write>(&self, out: &mut Output<W, Pg>) -> serialize::Result {
+        match *self {
+            NonConformanceStatusEnum::Open => out.write_all(b"OPEN")?,
+            NonConformanceStatusEnum::CorrectiveActionSubmitted => {
+                out.write_all(b"CORRECTIVE_ACTION_SUBMITTED")?
+            }
+            NonConformanceStatusEnum::Closed => out.write_all(b"CLOSED")?,
+            NonConformanceStatusEnum::UnsetStatus => out.write_all(b"UNSET_STATUS")?,
+        }
+        Ok(IsNull::No)
+    }
+}
+
+impl FromSql<NonConformanceStatus, Pg> for NonConformanceStatusEnum {
+    fn from_sql(bytes: Option<&[u8]>) -> deserialize::Result<Self> {
+        match not_none!(bytes) {
+            b"OPEN" => Ok(NonConformanceStatusEnum::Open),
+            b"CORRECTIVE_ACTION_SUBMITTED" => {
+                Ok(NonConformanceStatusEnum::CorrectiveActionSubmitted)
+            }
+            b"CLOSED" => Ok(NonConformanceStatusEnum::Closed),
+            b"UNSET_STATUS" => Ok(NonConformanceStatusEnum::UnsetStatus),
+            _ => Err("Unrecognized enum variant".into()),
+        }
+    }
+}
+
+// DataFieldType
+
+#[derive(SqlType, QueryId)]
+#[postgres(type_name = "datafieldtype")]
+pub struct DataFieldType;
+
+#[derive(Debug, PartialEq, FromSqlRow, AsExpression, Serialize, Clone)]
+#[sql_type = "DataFieldType"]
+pub enum DataFieldTypeEnum {
+    String,
+    Integer,
+    Decimal,
+    Boolean,
+    Enumeration,
+    UnsetType,
+}
+
+impl ToSql<DataFieldType, Pg> for DataFieldTypeEnum {
+    fn to_sql<W: Write>(&self, out: &mut Output<W, Pg>) -> serialize::Result {
+        match *self {
+            DataFieldTypeEnum::String => out.write_all(b"STRING")?,
+            DataFieldTypeEnum::Integer => out.write_all(b"INTEGER")?,
+            DataFieldTypeEnum::Decimal => out.write_all(b"DECIMAL")?,
+            DataFieldTypeEnum::Boolean => out.write_all(b"BOOLEAN")?,
+            DataFieldTypeEnum::Enumeration => out.write_all(b"ENUMERATION")?,
+            DataFieldTypeEnum::UnsetType => out.write_all(b"UNSET_TYPE")?,
+        }
+        Ok(IsNull::No)
+    }
+}
+
+impl FromSql<DataFieldType, Pg> for DataFieldTypeEnum {
+    fn from_sql(bytes: Option<&[u8]>) -> deserialize::Result<Self> {
+        match not_none!(bytes) {
+            b"STRING" => Ok(DataFieldTypeEnum::String),
+            b"INTEGER" => Ok(DataFieldTypeEnum::Integer),
+            b"DECIMAL" => Ok(DataFieldTypeEnum::Decimal),
+            b"BOOLEAN" => Ok(DataFieldTypeEnum::Boolean),
+            b"ENUMERATION" => Ok(DataFieldTypeEnum::Enumeration),
+            b"UNSET_TYPE" => Ok(DataFieldTypeEnum::UnsetType),
+            _ => Err("Unrecognized enum variant".into()),
+        }
+    }
+}
+
+// ProposalStatus
+
+#[derive(SqlType, QueryId)]
+#[postgres(type_name = "proposalstatus")]
+pub struct ProposalStatus;
+
+#[derive(Debug, PartialEq, FromSqlRow, AsExpression, Serialize, Clone)]
+#[sql_type = "ProposalStatus"]
+pub enum ProposalStatusEnum {
+    Open,
+    Accepted,
+    Rejected,
+    Withdrawn,
+    UnsetStatus,
+}
+
+impl ToSql<ProposalStatus, Pg> for ProposalStatusEnum {
+    fn to_sql<W: Write>(&self, out: &mut Output<W, Pg>) -> serialize::Result {
+        match *self {
+            ProposalStatusEnum::Open => out.write_all(b"OPEN")?,
+            ProposalStatusEnum::Accepted => out.write_all(b"ACCEPTED")?,
+            ProposalStatusEnum::Rejected => out.write_all(b"REJECTED")?,
+            ProposalStatusEnum::Withdrawn => out.write_all(b"WITHDRAWN")?,
+            ProposalStatusEnum::UnsetStatus => out.write_all(b"UNSET_STATUS")?,
+        }
+        Ok(IsNull::No)
+    }
+}
+
+impl FromSql<ProposalStatus, Pg> for ProposalStatusEnum {
+    fn from_sql(bytes: Option<&[u8]>) -> deserialize::Result<Self> {
+        match not_none!(bytes) {
+            b"OPEN" => Ok(ProposalStatusEnum::Open),
+            b"ACCEPTED" => Ok(ProposalStatusEnum::Accepted),
+            b"REJECTED" => Ok(ProposalStatusEnum::Rejected),
+            b"WITHDRAWN" => Ok(ProposalStatusEnum::Withdrawn),
+            b"UNSET_STATUS" => Ok(ProposalStatusEnum::UnsetStatus),
+            _ => Err("Unrecognized enum variant".into()),
+        }
+    }
+}
+
+// AccreditationRequestStatus
+
+#[derive(SqlType, QueryId)]
+#[postgres(type_name = "accreditationrequeststatus")]
+pub struct AccreditationRequestStatus;
+
+#[derive(Debug, PartialEq, FromSqlRow, AsExpression, Serialize, Clone)]
+#[sql_type = "AccreditationRequestStatus"]
+pub enum AccreditationRequestStatusEnum {
+    Open,
+    InReview,
+    Approved,
+    Rejected,
+    UnsetStatus,
+}
+
+impl ToSql<AccreditationRequestStatus, Pg> for AccreditationRequestStatusEnum {
+    fn to_sql<W: Write>(&self, out: &mut Output<W, Pg>) -> serialize::Result {
+        match *self {
+            AccreditationRequestStatusEnum::Open => out.write_all(b"OPEN")?,
+            AccreditationRequestStatusEnum::InReview => out.write_all(b"IN_REVIEW")?,
+            AccreditationRequestStatusEnum::Approved => out.write_all(b"APPROVED")?,
+            AccreditationRequestStatusEnum::Rejected => out.write_all(b"REJECTED")?,
+            AccreditationRequestStatusEnum::UnsetStatus => out.write_all(b"UNSET_STATUS")?,
+        }
+        Ok(IsNull::No)
+    }
+}
+
+impl FromSql<AccreditationRequestStatus, Pg> for AccreditationRequestStatusEnum {
+    fn from_sql(bytes: Option<&[u8]>) -> deserialize::Result<Self> {
+        match not_none!(bytes) {
+            b"OPEN" => Ok(AccreditationRequestStatusEnum::Open),
+            b"IN_REVIEW" => Ok(AccreditationRequestStatusEnum::InReview),
+            b"APPROVED" => Ok(AccreditationRequestStatusEnum::Approved),
+            b"REJECTED" => Ok(AccreditationRequestStatusEnum::Rejected),
+            b"UNSET_STATUS" => Ok(AccreditationRequestStatusEnum::UnsetStatus),
+            _ => Err("Unrecognized enum variant".into()),
+        }
+    }
+}
+
+// StandardStatus
+
+#[derive(SqlType, QueryId)]
+#[postgres(type_name = "standardstatus")]
+pub struct StandardStatus;
+
+#[derive(Debug, PartialEq, FromSqlRow, AsExpression, Serialize, Clone)]
+#[sql_type = "StandardStatus"]
+pub enum StandardStatusEnum {
+    Active,
+    Retired,
+    UnsetStatus,
+}
+
+impl ToSql<StandardStatus, Pg> for StandardStatusEnum {
+    fn to_sql<W: Write>(&self, out: &mut Output<W, Pg>) -> serialize::Result {
+        match *self {
+            StandardStatusEnum::Active => out.write_all(b"ACTIVE")?,
+            StandardStatusEnum::Retired => out.write_all(b"RETIRED")?,
+            StandardStatusEnum::UnsetStatus => out.write_all(b"UNSET_STATUS")?,
+        }
+        Ok(IsNull::No)
+    }
+}
+
+impl FromSql<StandardStatus, Pg> for StandardStatusEnum {
+    fn from_sql(bytes: Option<&[u8]>) -> deserialize::Result<Self> {
+        match not_none!(bytes) {
+            b"ACTIVE" => Ok(StandardStatusEnum::Active),
+            b"RETIRED" => Ok(StandardStatusEnum::Retired),
+            b"UNSET_STATUS" => Ok(StandardStatusEnum::UnsetStatus),
+            _ => Err("Unrecognized enum variant".into()),
+        }
+    }
+}
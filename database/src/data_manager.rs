@@ -15,6 +15,7 @@ pub struct DataManager {
 
 pub enum OperationType {
     CreateAgent(Vec<NewAgent>),
+    CreateRegistryOperators(Vec<NewRegistryOperator>),
     CreateOrganization(
         Vec<(
             NewOrganization,
@@ -22,11 +23,30 @@ pub enum OperationType {
             Option<NewAddress>,
             Vec<NewAuthorization>,
             Vec<NewContact>,
+            Option<Vec<NewRetailerFactory>>,
+        )>,
+    ),
+    CreateCertificate(
+        Vec<(
+            NewCertificate,
+            Vec<NewCertificateSuspension>,
+            Vec<NewDocumentReference>,
+        )>,
+    ),
+    CreateRequest(Vec<(NewRequest, Vec<NewDocumentReference>)>),
+    CreateAudit(Vec<(NewAudit, Vec<NewAuditFinding>)>),
+    CreateNonConformance(Vec<NewNonConformance>),
+    CreateProposal(Vec<(NewProposal, Vec<NewProposalVote>)>),
+    CreateAccreditationRequest(Vec<NewAccreditationRequest>),
+    CreateEvents(Vec<(NewEvent, Vec<NewEventAttribute>)>),
+    CreateStandard(
+        Vec<(
+            NewStandard,
+            Vec<NewStandardVersion>,
+            Vec<NewStandardDataField>,
+            Vec<NewStandardEquivalence>,
         )>,
     ),
-    CreateCertificate(Vec<NewCertificate>),
-    CreateRequest(Vec<NewRequest>),
-    CreateStandard(Vec<(NewStandard, Vec<NewStandardVersion>)>),
 }
 
 impl DataManager {
@@ -80,8 +100,13 @@ impl DataManager {
     fn execute_operation(&self, operation: OperationType) -> Result<(), DatabaseError> {
         match operation {
             OperationType::CreateAgent(agents) => self.insert_agent(&agents),
+            OperationType::CreateRegistryOperators(operators) => {
+                self.insert_registry_operators(&operators)
+            }
             OperationType::CreateOrganization(orgs_authorization) => {
-                for (org, accreditations, address, authorizations, contacts) in orgs_authorization {
+                for (org, accreditations, address, authorizations, contacts, suppliers) in
+                    orgs_authorization
+                {
                     self.insert_organization(&org)?;
                     self.insert_authorizations(&authorizations, &org)?;
                     self.insert_contacts(&contacts, &org)?;
@@ -91,17 +116,59 @@ impl DataManager {
                     if org.organization_type == OrganizationTypeEnum::CertifyingBody {
                         self.insert_accreditations(&accreditations.unwrap(), &org)?;
                     }
+                    if org.organization_type == OrganizationTypeEnum::Retailer {
+                        self.insert_retailer_factories(&suppliers.unwrap(), &org)?;
+                    }
                 }
                 Ok(())
             }
             OperationType::CreateCertificate(certificates) => {
-                self.insert_certificate(&certificates)
+                for (certificate, suspensions, documents) in certificates {
+                    self.insert_certificate(&certificate)?;
+                    self.insert_certificate_suspensions(&suspensions, &certificate)?;
+                    self.insert_certificate_documents(&documents, &certificate)?;
+                }
+                Ok(())
+            }
+            OperationType::CreateRequest(requests) => {
+                for (request, documents) in requests {
+                    self.insert_request(&request)?;
+                    self.insert_request_documents(&documents, &request)?;
+                }
+                Ok(())
+            }
+            OperationType::CreateAudit(audits) => {
+                for (audit, findings) in audits {
+                    self.insert_audit(&audit)?;
+                    self.insert_audit_findings(&findings, &audit)?;
+                }
+                Ok(())
+            }
+            OperationType::CreateNonConformance(non_conformances) => {
+                self.insert_non_conformance(&non_conformances)
+            }
+            OperationType::CreateProposal(proposals) => {
+                for (proposal, votes) in proposals {
+                    self.insert_proposal(&proposal)?;
+                    self.insert_proposal_votes(&votes, &proposal)?;
+                }
+                Ok(())
+            }
+            OperationType::CreateAccreditationRequest(accreditation_requests) => {
+                self.insert_accreditation_request(&accreditation_requests)
+            }
+            OperationType::CreateEvents(events) => {
+                for (event, attributes) in events {
+                    self.insert_event(&event, &attributes)?;
+                }
+                Ok(())
             }
-            OperationType::CreateRequest(requests) => self.insert_request(&requests),
             OperationType::CreateStandard(standards) => {
-                for (standard, versions) in standards {
+                for (standard, versions, data_fields, equivalences) in standards {
                     self.insert_standard(&standard)?;
                     self.insert_standard_versions(&versions, &standard)?;
+                    self.insert_standard_data_fields(&data_fields, &standard)?;
+                    self.insert_standard_equivalences(&equivalences, &standard)?;
                 }
                 Ok(())
             }
@@ -133,6 +200,9 @@ impl DataManager {
     fn insert_agent(&self, agents: &[NewAgent]) -> Result<(), DatabaseError> {
         for agent in agents {
             self.update_agent(&agent.public_key, agent.start_block_num)?;
+            if let Some(successor_public_key) = agent.successor_public_key.as_ref() {
+                self.rotate_user(&agent.public_key, successor_public_key)?;
+            }
         }
         diesel::insert_into(agents::table)
             .values(agents)
@@ -140,6 +210,17 @@ impl DataManager {
         Ok(())
     }
 
+    fn rotate_user(
+        &self,
+        previous_public_key: &str,
+        successor_public_key: &str,
+    ) -> Result<(), DatabaseError> {
+        diesel::update(users::table.filter(users::public_key.eq(previous_public_key)))
+            .set(users::public_key.eq(successor_public_key))
+            .execute(&*self.conn)?;
+        Ok(())
+    }
+
     fn update_agent(
         &self,
         agent_public_key: &str,
@@ -154,6 +235,28 @@ impl DataManager {
         Ok(())
     }
 
+    fn insert_registry_operators(
+        &self,
+        operators: &[NewRegistryOperator],
+    ) -> Result<(), DatabaseError> {
+        if let Some(operator) = operators.first() {
+            self.update_registry_operators(operator.start_block_num)?;
+        }
+        diesel::insert_into(registry_operators::table)
+            .values(operators)
+            .execute(&*self.conn)?;
+        Ok(())
+    }
+
+    fn update_registry_operators(&self, current_block_num: i64) -> Result<(), DatabaseError> {
+        let modified_operators_query =
+            registry_operators::table.filter(registry_operators::end_block_num.eq(MAX_BLOCK_NUM));
+        diesel::update(modified_operators_query)
+            .set(registry_operators::end_block_num.eq(current_block_num))
+            .execute(&*self.conn)?;
+        Ok(())
+    }
+
     fn insert_organization(&self, org: &NewOrganization) -> Result<(), DatabaseError> {
         self.update_organization(&org.organization_id, org.start_block_num)?;
         diesel::insert_into(organizations::table)
@@ -254,12 +357,10 @@ impl DataManager {
         Ok(())
     }
 
-    fn insert_certificate(&self, certs: &[NewCertificate]) -> Result<(), DatabaseError> {
-        for cert in certs {
-            self.update_certificate(&cert.certificate_id, cert.start_block_num)?;
-        }
+    fn insert_certificate(&self, cert: &NewCertificate) -> Result<(), DatabaseError> {
+        self.update_certificate(&cert.certificate_id, cert.start_block_num)?;
         diesel::insert_into(certificates::table)
-            .values(certs)
+            .values(cert)
             .execute(&*self.conn)?;
         Ok(())
     }
@@ -278,12 +379,62 @@ impl DataManager {
         Ok(())
     }
 
-    fn insert_request(&self, requests: &[NewRequest]) -> Result<(), DatabaseError> {
-        for request in requests {
-            self.update_request(&request.request_id, request.start_block_num)?;
-        }
+    fn insert_certificate_suspensions(
+        &self,
+        suspensions: &[NewCertificateSuspension],
+        cert: &NewCertificate,
+    ) -> Result<(), DatabaseError> {
+        self.update_certificate_suspensions(&cert.certificate_id, cert.start_block_num)?;
+        diesel::insert_into(certificate_suspensions::table)
+            .values(suspensions)
+            .execute(&*self.conn)?;
+        Ok(())
+    }
+
+    fn update_certificate_suspensions(
+        &self,
+        certificate_id: &str,
+        current_block_num: i64,
+    ) -> Result<(), DatabaseError> {
+        let modified_suspensions_query = certificate_suspensions::table
+            .filter(certificate_suspensions::end_block_num.eq(MAX_BLOCK_NUM))
+            .filter(certificate_suspensions::certificate_id.eq(certificate_id));
+        diesel::update(modified_suspensions_query)
+            .set(certificate_suspensions::end_block_num.eq(current_block_num))
+            .execute(&*self.conn)?;
+        Ok(())
+    }
+
+    fn insert_certificate_documents(
+        &self,
+        documents: &[NewDocumentReference],
+        cert: &NewCertificate,
+    ) -> Result<(), DatabaseError> {
+        self.update_certificate_documents(&cert.certificate_id, cert.start_block_num)?;
+        diesel::insert_into(document_references::table)
+            .values(documents)
+            .execute(&*self.conn)?;
+        Ok(())
+    }
+
+    fn update_certificate_documents(
+        &self,
+        certificate_id: &str,
+        current_block_num: i64,
+    ) -> Result<(), DatabaseError> {
+        let modified_documents_query = document_references::table
+            .filter(document_references::end_block_num.eq(MAX_BLOCK_NUM))
+            .filter(document_references::certificate_id.eq(certificate_id));
+        diesel::update(modified_documents_query)
+            .set(document_references::end_block_num.eq(current_block_num))
+            .execute(&*self.conn)?;
+        Ok(())
+    }
+
+    fn insert_request(&self, request: &NewRequest) -> Result<(), DatabaseError> {
+        self.update_request(&request.request_id, request.start_block_num)?;
         diesel::insert_into(requests::table)
-            .values(requests)
+            .values(request)
             .execute(&*self.conn)?;
         Ok(())
     }
@@ -302,6 +453,226 @@ impl DataManager {
         Ok(())
     }
 
+    fn insert_request_documents(
+        &self,
+        documents: &[NewDocumentReference],
+        request: &NewRequest,
+    ) -> Result<(), DatabaseError> {
+        self.update_request_documents(&request.request_id, request.start_block_num)?;
+        diesel::insert_into(document_references::table)
+            .values(documents)
+            .execute(&*self.conn)?;
+        Ok(())
+    }
+
+    fn update_request_documents(
+        &self,
+        request_id: &str,
+        current_block_num: i64,
+    ) -> Result<(), DatabaseError> {
+        let modified_documents_query = document_references::table
+            .filter(document_references::end_block_num.eq(MAX_BLOCK_NUM))
+            .filter(document_references::request_id.eq(request_id));
+        diesel::update(modified_documents_query)
+            .set(document_references::end_block_num.eq(current_block_num))
+            .execute(&*self.conn)?;
+        Ok(())
+    }
+
+    fn insert_audit(&self, audit: &NewAudit) -> Result<(), DatabaseError> {
+        self.update_audit(&audit.audit_id, audit.start_block_num)?;
+        diesel::insert_into(audits::table)
+            .values(audit)
+            .execute(&*self.conn)?;
+        Ok(())
+    }
+
+    fn update_audit(&self, audit_id: &str, current_block_num: i64) -> Result<(), DatabaseError> {
+        let modified_audit_query = audits::table
+            .filter(audits::end_block_num.eq(MAX_BLOCK_NUM))
+            .filter(audits::audit_id.eq(audit_id));
+        diesel::update(modified_audit_query)
+            .set(audits::end_block_num.eq(current_block_num))
+            .execute(&*self.conn)?;
+        Ok(())
+    }
+
+    fn insert_audit_findings(
+        &self,
+        findings: &[NewAuditFinding],
+        audit: &NewAudit,
+    ) -> Result<(), DatabaseError> {
+        self.update_audit_findings(&audit.audit_id, audit.start_block_num)?;
+        diesel::insert_into(audit_findings::table)
+            .values(findings)
+            .execute(&*self.conn)?;
+        Ok(())
+    }
+
+    fn update_audit_findings(
+        &self,
+        audit_id: &str,
+        current_block_num: i64,
+    ) -> Result<(), DatabaseError> {
+        let modified_findings_query = audit_findings::table
+            .filter(audit_findings::end_block_num.eq(MAX_BLOCK_NUM))
+            .filter(audit_findings::audit_id.eq(audit_id));
+        diesel::update(modified_findings_query)
+            .set(audit_findings::end_block_num.eq(current_block_num))
+            .execute(&*self.conn)?;
+        Ok(())
+    }
+
+    /// Events are never modified once emitted, so they are inserted without ending
+    /// previous records
+    fn insert_event(
+        &self,
+        event: &NewEvent,
+        attributes: &[NewEventAttribute],
+    ) -> Result<(), DatabaseError> {
+        diesel::insert_into(events::table)
+            .values(event)
+            .execute(&*self.conn)?;
+        diesel::insert_into(event_attributes::table)
+            .values(attributes)
+            .execute(&*self.conn)?;
+        Ok(())
+    }
+
+    fn insert_non_conformance(
+        &self,
+        non_conformances: &[NewNonConformance],
+    ) -> Result<(), DatabaseError> {
+        for non_conformance in non_conformances {
+            self.update_non_conformance(
+                &non_conformance.non_conformance_id,
+                non_conformance.start_block_num,
+            )?;
+        }
+        diesel::insert_into(non_conformances::table)
+            .values(non_conformances)
+            .execute(&*self.conn)?;
+        Ok(())
+    }
+
+    fn update_non_conformance(
+        &self,
+        non_conformance_id: &str,
+        current_block_num: i64,
+    ) -> Result<(), DatabaseError> {
+        let modified_non_conformance_query = non_conformances::table
+            .filter(non_conformances::end_block_num.eq(MAX_BLOCK_NUM))
+            .filter(non_conformances::non_conformance_id.eq(non_conformance_id));
+        diesel::update(modified_non_conformance_query)
+            .set(non_conformances::end_block_num.eq(current_block_num))
+            .execute(&*self.conn)?;
+        Ok(())
+    }
+
+    fn insert_retailer_factories(
+        &self,
+        retailer_factories: &[NewRetailerFactory],
+        org: &NewOrganization,
+    ) -> Result<(), DatabaseError> {
+        self.update_retailer_factories(&org.organization_id, org.start_block_num)?;
+        diesel::insert_into(retailer_factories::table)
+            .values(retailer_factories)
+            .execute(&*self.conn)?;
+        Ok(())
+    }
+
+    fn update_retailer_factories(
+        &self,
+        retailer_id: &str,
+        current_block_num: i64,
+    ) -> Result<(), DatabaseError> {
+        let modified_retailer_factories_query = retailer_factories::table
+            .filter(retailer_factories::end_block_num.eq(MAX_BLOCK_NUM))
+            .filter(retailer_factories::retailer_id.eq(retailer_id));
+        diesel::update(modified_retailer_factories_query)
+            .set(retailer_factories::end_block_num.eq(current_block_num))
+            .execute(&*self.conn)?;
+        Ok(())
+    }
+
+    fn insert_proposal(&self, proposal: &NewProposal) -> Result<(), DatabaseError> {
+        self.update_proposal(&proposal.proposal_id, proposal.start_block_num)?;
+        diesel::insert_into(proposals::table)
+            .values(proposal)
+            .execute(&*self.conn)?;
+        Ok(())
+    }
+
+    fn update_proposal(
+        &self,
+        proposal_id: &str,
+        current_block_num: i64,
+    ) -> Result<(), DatabaseError> {
+        let modified_proposal_query = proposals::table
+            .filter(proposals::end_block_num.eq(MAX_BLOCK_NUM))
+            .filter(proposals::proposal_id.eq(proposal_id));
+        diesel::update(modified_proposal_query)
+            .set(proposals::end_block_num.eq(current_block_num))
+            .execute(&*self.conn)?;
+        Ok(())
+    }
+
+    fn insert_proposal_votes(
+        &self,
+        votes: &[NewProposalVote],
+        proposal: &NewProposal,
+    ) -> Result<(), DatabaseError> {
+        self.update_proposal_votes(&proposal.proposal_id, proposal.start_block_num)?;
+        diesel::insert_into(proposal_votes::table)
+            .values(votes)
+            .execute(&*self.conn)?;
+        Ok(())
+    }
+
+    fn update_proposal_votes(
+        &self,
+        proposal_id: &str,
+        current_block_num: i64,
+    ) -> Result<(), DatabaseError> {
+        let modified_votes_query = proposal_votes::table
+            .filter(proposal_votes::end_block_num.eq(MAX_BLOCK_NUM))
+            .filter(proposal_votes::proposal_id.eq(proposal_id));
+        diesel::update(modified_votes_query)
+            .set(proposal_votes::end_block_num.eq(current_block_num))
+            .execute(&*self.conn)?;
+        Ok(())
+    }
+
+    fn insert_accreditation_request(
+        &self,
+        accreditation_requests: &[NewAccreditationRequest],
+    ) -> Result<(), DatabaseError> {
+        for accreditation_request in accreditation_requests {
+            self.update_accreditation_request(
+                &accreditation_request.accreditation_request_id,
+                accreditation_request.start_block_num,
+            )?;
+        }
+        diesel::insert_into(accreditation_requests::table)
+            .values(accreditation_requests)
+            .execute(&*self.conn)?;
+        Ok(())
+    }
+
+    fn update_accreditation_request(
+        &self,
+        accreditation_request_id: &str,
+        current_block_num: i64,
+    ) -> Result<(), DatabaseError> {
+        let modified_accreditation_request_query = accreditation_requests::table
+            .filter(accreditation_requests::end_block_num.eq(MAX_BLOCK_NUM))
+            .filter(accreditation_requests::accreditation_request_id.eq(accreditation_request_id));
+        diesel::update(modified_accreditation_request_query)
+            .set(accreditation_requests::end_block_num.eq(current_block_num))
+            .execute(&*self.conn)?;
+        Ok(())
+    }
+
     fn insert_standard(&self, standard: &NewStandard) -> Result<(), DatabaseError> {
         self.update_standard(&standard.standard_id, standard.start_block_num)?;
         diesel::insert_into(standards::table)
@@ -350,6 +721,58 @@ impl DataManager {
         Ok(())
     }
 
+    fn insert_standard_data_fields(
+        &self,
+        data_fields: &[NewStandardDataField],
+        standard: &NewStandard,
+    ) -> Result<(), DatabaseError> {
+        self.update_standard_data_fields(&standard.standard_id, standard.start_block_num)?;
+        diesel::insert_into(standard_data_fields::table)
+            .values(data_fields)
+            .execute(&*self.conn)?;
+        Ok(())
+    }
+
+    fn update_standard_data_fields(
+        &self,
+        standard_id: &str,
+        current_block_num: i64,
+    ) -> Result<(), DatabaseError> {
+        let modified_data_fields_query = standard_data_fields::table
+            .filter(standard_data_fields::end_block_num.eq(MAX_BLOCK_NUM))
+            .filter(standard_data_fields::standard_id.eq(standard_id));
+        diesel::update(modified_data_fields_query)
+            .set(standard_data_fields::end_block_num.eq(current_block_num))
+            .execute(&*self.conn)?;
+        Ok(())
+    }
+
+    fn insert_standard_equivalences(
+        &self,
+        equivalences: &[NewStandardEquivalence],
+        standard: &NewStandard,
+    ) -> Result<(), DatabaseError> {
+        self.update_standard_equivalences(&standard.standard_id, standard.start_block_num)?;
+        diesel::insert_into(standard_equivalences::table)
+            .values(equivalences)
+            .execute(&*self.conn)?;
+        Ok(())
+    }
+
+    fn update_standard_equivalences(
+        &self,
+        standard_id: &str,
+        current_block_num: i64,
+    ) -> Result<(), DatabaseError> {
+        let modified_equivalences_query = standard_equivalences::table
+            .filter(standard_equivalences::end_block_num.eq(MAX_BLOCK_NUM))
+            .filter(standard_equivalences::standard_id.eq(standard_id));
+        diesel::update(modified_equivalences_query)
+            .set(standard_equivalences::end_block_num.eq(current_block_num))
+            .execute(&*self.conn)?;
+        Ok(())
+    }
+
     fn insert_accreditations(
         &self,
         accreditations: &[NewAccreditation],
@@ -16,6 +16,7 @@ pub struct Agent {
     pub name: String,
     pub organization_id: Option<String>,
     pub timestamp: i64,
+    pub successor_public_key: Option<String>,
 }
 
 #[derive(Queryable, Insertable)]
@@ -27,6 +28,7 @@ pub struct NewAgent {
     pub name: String,
     pub organization_id: Option<String>,
     pub timestamp: i64,
+    pub successor_public_key: Option<String>,
 }
 
 #[derive(Queryable, Serialize)]
@@ -37,6 +39,9 @@ pub struct Authorization {
     pub organization_id: String,
     pub public_key: String,
     pub role: RoleEnum,
+    pub role_name: Option<String>,
+    pub valid_from: Option<i64>,
+    pub valid_to: Option<i64>,
 }
 
 #[derive(Queryable, Insertable)]
@@ -47,6 +52,9 @@ pub struct NewAuthorization {
     pub organization_id: String,
     pub public_key: String,
     pub role: RoleEnum,
+    pub role_name: Option<String>,
+    pub valid_from: Option<i64>,
+    pub valid_to: Option<i64>,
 }
 
 #[derive(Queryable, Insertable, Default, Clone)]
@@ -86,6 +94,7 @@ pub struct Organization {
     pub organization_id: String,
     pub name: String,
     pub organization_type: OrganizationTypeEnum,
+    pub approval_status: ApprovalStatusEnum,
 }
 
 #[derive(Queryable, Insertable)]
@@ -96,6 +105,23 @@ pub struct NewOrganization {
     pub organization_id: String,
     pub name: String,
     pub organization_type: OrganizationTypeEnum,
+    pub approval_status: ApprovalStatusEnum,
+}
+
+#[derive(Queryable, Serialize)]
+pub struct RegistryOperator {
+    pub id: i64,
+    pub start_block_num: i64,
+    pub end_block_num: i64,
+    pub public_key: String,
+}
+
+#[derive(Queryable, Insertable)]
+#[table_name = "registry_operators"]
+pub struct NewRegistryOperator {
+    pub start_block_num: i64,
+    pub end_block_num: i64,
+    pub public_key: String,
 }
 
 #[derive(Queryable, Serialize)]
@@ -132,6 +158,10 @@ pub struct Certificate {
     pub standard_version: String,
     pub valid_from: i64,
     pub valid_to: i64,
+    pub status: CertificateStatusEnum,
+    pub revoked_at: Option<i64>,
+    pub previous_certificate_id: Option<String>,
+    pub audit_id: Option<String>,
 }
 
 #[derive(Queryable, Insertable)]
@@ -146,6 +176,58 @@ pub struct NewCertificate {
     pub standard_version: String,
     pub valid_from: i64,
     pub valid_to: i64,
+    pub status: CertificateStatusEnum,
+    pub revoked_at: Option<i64>,
+    pub previous_certificate_id: Option<String>,
+    pub audit_id: Option<String>,
+}
+
+#[derive(Queryable, Serialize)]
+pub struct CertificateSuspension {
+    pub id: i64,
+    pub start_block_num: i64,
+    pub end_block_num: i64,
+    pub certificate_id: String,
+    pub reason: String,
+    pub suspended_at: i64,
+    pub reinstated_at: Option<i64>,
+}
+
+#[derive(Queryable, Insertable)]
+#[table_name = "certificate_suspensions"]
+pub struct NewCertificateSuspension {
+    pub start_block_num: i64,
+    pub end_block_num: i64,
+    pub certificate_id: String,
+    pub reason: String,
+    pub suspended_at: i64,
+    pub reinstated_at: Option<i64>,
+}
+
+#[derive(Queryable, Serialize, Debug)]
+pub struct DocumentReference {
+    pub id: i64,
+    pub start_block_num: i64,
+    pub end_block_num: i64,
+    pub certificate_id: Option<String>,
+    pub request_id: Option<String>,
+    pub sha256: String,
+    pub media_type: String,
+    pub title: String,
+    pub uri: Option<String>,
+}
+
+#[derive(Queryable, Insertable)]
+#[table_name = "document_references"]
+pub struct NewDocumentReference {
+    pub start_block_num: i64,
+    pub end_block_num: i64,
+    pub certificate_id: Option<String>,
+    pub request_id: Option<String>,
+    pub sha256: String,
+    pub media_type: String,
+    pub title: String,
+    pub uri: Option<String>,
 }
 
 #[derive(Queryable, Serialize)]
@@ -188,6 +270,8 @@ pub struct Request {
     pub standard_id: String,
     pub status: RequestStatusEnum,
     pub request_date: i64,
+    pub certifying_body_id: Option<String>,
+    pub decline_reason: Option<String>,
 }
 
 #[derive(Queryable, Insertable)]
@@ -200,6 +284,210 @@ pub struct NewRequest {
     pub standard_id: String,
     pub status: RequestStatusEnum,
     pub request_date: i64,
+    pub certifying_body_id: Option<String>,
+    pub decline_reason: Option<String>,
+}
+
+#[derive(Queryable, Serialize, Debug)]
+pub struct Audit {
+    pub id: i64,
+    pub start_block_num: i64,
+    pub end_block_num: i64,
+    pub audit_id: String,
+    pub request_id: String,
+    pub factory_id: String,
+    pub certifying_body_id: String,
+    pub auditor_public_key: String,
+    pub audit_date: i64,
+    pub outcome: AuditOutcomeEnum,
+}
+
+#[derive(Queryable, Insertable)]
+#[table_name = "audits"]
+pub struct NewAudit {
+    pub start_block_num: i64,
+    pub end_block_num: i64,
+    pub audit_id: String,
+    pub request_id: String,
+    pub factory_id: String,
+    pub certifying_body_id: String,
+    pub auditor_public_key: String,
+    pub audit_date: i64,
+    pub outcome: AuditOutcomeEnum,
+}
+
+#[derive(Queryable, Serialize, Debug)]
+pub struct AuditFinding {
+    pub id: i64,
+    pub start_block_num: i64,
+    pub end_block_num: i64,
+    pub audit_id: String,
+    pub description: String,
+    pub severity: FindingSeverityEnum,
+}
+
+#[derive(Queryable, Insertable)]
+#[table_name = "audit_findings"]
+pub struct NewAuditFinding {
+    pub start_block_num: i64,
+    pub end_block_num: i64,
+    pub audit_id: String,
+    pub description: String,
+    pub severity: FindingSeverityEnum,
+}
+
+#[derive(Queryable, Serialize, Debug)]
+pub struct Proposal {
+    pub id: i64,
+    pub start_block_num: i64,
+    pub end_block_num: i64,
+    pub proposal_id: String,
+    pub organization_id: String,
+    pub proposer: String,
+    pub action: String,
+    pub payload: String,
+    pub required_approvals: i32,
+    pub status: ProposalStatusEnum,
+    pub proposed_at: i64,
+}
+
+#[derive(Queryable, Insertable)]
+#[table_name = "proposals"]
+pub struct NewProposal {
+    pub start_block_num: i64,
+    pub end_block_num: i64,
+    pub proposal_id: String,
+    pub organization_id: String,
+    pub proposer: String,
+    pub action: String,
+    pub payload: String,
+    pub required_approvals: i32,
+    pub status: ProposalStatusEnum,
+    pub proposed_at: i64,
+}
+
+#[derive(Queryable, Serialize, Debug)]
+pub struct ProposalVote {
+    pub id: i64,
+    pub start_block_num: i64,
+    pub end_block_num: i64,
+    pub proposal_id: String,
+    pub public_key: String,
+    pub accept: bool,
+}
+
+#[derive(Queryable, Insertable)]
+#[table_name = "proposal_votes"]
+pub struct NewProposalVote {
+    pub start_block_num: i64,
+    pub end_block_num: i64,
+    pub proposal_id: String,
+    pub public_key: String,
+    pub accept: bool,
+}
+
+#[derive(Queryable, Serialize, Debug)]
+pub struct AccreditationRequest {
+    pub id: i64,
+    pub start_block_num: i64,
+    pub end_block_num: i64,
+    pub accreditation_request_id: String,
+    pub certifying_body_id: String,
+    pub standards_body_id: String,
+    pub standard_id: String,
+    pub status: AccreditationRequestStatusEnum,
+    pub request_date: i64,
+    pub rejection_reason: Option<String>,
+    pub standard_version: Option<String>,
+}
+
+#[derive(Queryable, Insertable)]
+#[table_name = "accreditation_requests"]
+pub struct NewAccreditationRequest {
+    pub start_block_num: i64,
+    pub end_block_num: i64,
+    pub accreditation_request_id: String,
+    pub certifying_body_id: String,
+    pub standards_body_id: String,
+    pub standard_id: String,
+    pub status: AccreditationRequestStatusEnum,
+    pub request_date: i64,
+    pub rejection_reason: Option<String>,
+    pub standard_version: Option<String>,
+}
+
+#[derive(Queryable, Serialize, Debug)]
+pub struct Event {
+    pub id: i64,
+    pub start_block_num: i64,
+    pub end_block_num: i64,
+    pub event_id: String,
+    pub event_type: String,
+}
+
+#[derive(Queryable, Insertable)]
+#[table_name = "events"]
+pub struct NewEvent {
+    pub start_block_num: i64,
+    pub end_block_num: i64,
+    pub event_id: String,
+    pub event_type: String,
+}
+
+#[derive(Queryable, Serialize, Debug)]
+pub struct EventAttribute {
+    pub id: i64,
+    pub start_block_num: i64,
+    pub end_block_num: i64,
+    pub event_id: String,
+    pub key: String,
+    pub value: String,
+}
+
+#[derive(Queryable, Insertable)]
+#[table_name = "event_attributes"]
+pub struct NewEventAttribute {
+    pub start_block_num: i64,
+    pub end_block_num: i64,
+    pub event_id: String,
+    pub key: String,
+    pub value: String,
+}
+
+#[derive(Queryable, Serialize, Debug)]
+pub struct NonConformance {
+    pub id: i64,
+    pub start_block_num: i64,
+    pub end_block_num: i64,
+    pub non_conformance_id: String,
+    pub factory_id: String,
+    pub certifying_body_id: String,
+    pub audit_id: Option<String>,
+    pub description: String,
+    pub severity: FindingSeverityEnum,
+    pub status: NonConformanceStatusEnum,
+    pub corrective_action_plan: Option<String>,
+    pub raised_at: i64,
+    pub corrective_action_submitted_at: Option<i64>,
+    pub closed_at: Option<i64>,
+}
+
+#[derive(Queryable, Insertable)]
+#[table_name = "non_conformances"]
+pub struct NewNonConformance {
+    pub start_block_num: i64,
+    pub end_block_num: i64,
+    pub non_conformance_id: String,
+    pub factory_id: String,
+    pub certifying_body_id: String,
+    pub audit_id: Option<String>,
+    pub description: String,
+    pub severity: FindingSeverityEnum,
+    pub status: NonConformanceStatusEnum,
+    pub corrective_action_plan: Option<String>,
+    pub raised_at: i64,
+    pub corrective_action_submitted_at: Option<i64>,
+    pub closed_at: Option<i64>,
 }
 
 #[derive(Queryable, Serialize)]
@@ -210,6 +498,7 @@ pub struct Standard {
     pub standard_id: String,
     pub organization_id: String,
     pub name: String,
+    pub status: StandardStatusEnum,
 }
 
 #[derive(Queryable, Insertable)]
@@ -220,6 +509,7 @@ pub struct NewStandard {
     pub standard_id: String,
     pub organization_id: String,
     pub name: String,
+    pub status: StandardStatusEnum,
 }
 
 #[derive(Queryable, Serialize)]
@@ -232,6 +522,7 @@ pub struct StandardVersion {
     pub link: String,
     pub description: String,
     pub approval_date: i64,
+    pub retirement_date: Option<i64>,
 }
 
 #[derive(Queryable, Insertable)]
@@ -244,21 +535,75 @@ pub struct NewStandardVersion {
     pub link: String,
     pub description: String,
     pub approval_date: i64,
+    pub retirement_date: Option<i64>,
+}
+
+#[derive(Queryable, Serialize)]
+pub struct StandardDataField {
+    pub id: i64,
+    pub start_block_num: i64,
+    pub end_block_num: i64,
+    pub standard_id: String,
+    pub version: String,
+    pub name: String,
+    pub data_type: DataFieldTypeEnum,
+    pub required: bool,
+    pub allowed_values: Vec<String>,
+    pub pattern: Option<String>,
+}
+
+#[derive(Queryable, Insertable)]
+#[table_name = "standard_data_fields"]
+pub struct NewStandardDataField {
+    pub start_block_num: i64,
+    pub end_block_num: i64,
+    pub standard_id: String,
+    pub version: String,
+    pub name: String,
+    pub data_type: DataFieldTypeEnum,
+    pub required: bool,
+    pub allowed_values: Vec<String>,
+    pub pattern: Option<String>,
+}
+
+#[derive(Queryable, Serialize)]
+pub struct StandardEquivalence {
+    pub id: i64,
+    pub start_block_num: i64,
+    pub end_block_num: i64,
+    pub standard_id: String,
+    pub version: String,
+    pub equivalent_standard_id: String,
+    pub equivalent_standard_version: String,
+}
+
+#[derive(Queryable, Insertable)]
+#[table_name = "standard_equivalences"]
+pub struct NewStandardEquivalence {
+    pub start_block_num: i64,
+    pub end_block_num: i64,
+    pub standard_id: String,
+    pub version: String,
+    pub equivalent_standard_id: String,
+    pub equivalent_standard_version: String,
 }
 
 #[derive(Queryable, Serialize)]
-pub struct RetailerFactories {
+pub struct RetailerFactory {
     pub id: i64,
+    pub start_block_num: i64,
+    pub end_block_num: i64,
+    pub retailer_id: String,
+    pub factory_id: String,
+}
+
+#[derive(Queryable, Insertable)]
+#[table_name = "retailer_factories"]
+pub struct NewRetailerFactory {
+    pub start_block_num: i64,
+    pub end_block_num: i64,
+    pub retailer_id: String,
     pub factory_id: String,
-    pub factory_name: String,
-    pub contact_name: String,
-    pub contact_phone_number: String,
-    pub contact_language_code: String,
-    pub country: String,
-    pub state_province: String,
-    pub city: String,
-    pub street_line_1: String,
-    pub street_line_2: String,
 }
 
 #[derive(Queryable, Serialize)]
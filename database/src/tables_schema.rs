@@ -9,6 +9,7 @@ table! {
         name -> Varchar,
         organization_id -> Nullable<Varchar>,
         timestamp -> Int8,
+        successor_public_key -> Nullable<Varchar>,
     }
 }
 
@@ -33,6 +34,9 @@ table! {
         organization_id -> Varchar,
         public_key -> Varchar,
         role -> Role,
+        role_name -> Nullable<Varchar>,
+        valid_from -> Nullable<Int8>,
+        valid_to -> Nullable<Int8>,
     }
 }
 
@@ -55,6 +59,8 @@ table! {
 }
 
 table! {
+    use diesel::sql_types::*;
+    use super::CertificateStatus;
     certificates (id) {
         id -> Int8,
         start_block_num -> Int8,
@@ -66,6 +72,36 @@ table! {
         standard_version -> Varchar,
         valid_from -> Int8,
         valid_to -> Int8,
+        status -> CertificateStatus,
+        revoked_at -> Nullable<Int8>,
+        previous_certificate_id -> Nullable<Varchar>,
+        audit_id -> Nullable<Varchar>,
+    }
+}
+
+table! {
+    certificate_suspensions (id) {
+        id -> Int8,
+        start_block_num -> Int8,
+        end_block_num -> Int8,
+        certificate_id -> Varchar,
+        reason -> Varchar,
+        suspended_at -> Int8,
+        reinstated_at -> Nullable<Int8>,
+    }
+}
+
+table! {
+    document_references (id) {
+        id -> Int8,
+        start_block_num -> Int8,
+        end_block_num -> Int8,
+        certificate_id -> Nullable<Varchar>,
+        request_id -> Nullable<Varchar>,
+        sha256 -> Varchar,
+        media_type -> Varchar,
+        title -> Varchar,
+        uri -> Nullable<Varchar>,
     }
 }
 
@@ -95,6 +131,7 @@ table! {
 table! {
     use diesel::sql_types::*;
     use super::OrganizationType;
+    use super::ApprovalStatus;
     organizations (id) {
         id -> Int8,
         start_block_num -> Int8,
@@ -102,6 +139,16 @@ table! {
         organization_id -> Varchar,
         name -> Varchar,
         organization_type -> OrganizationType,
+        approval_status -> ApprovalStatus,
+    }
+}
+
+table! {
+    registry_operators (id) {
+        id -> Int8,
+        start_block_num -> Int8,
+        end_block_num -> Int8,
+        public_key -> Varchar,
     }
 }
 
@@ -129,10 +176,66 @@ table! {
         standard_id -> Varchar,
         status -> RequestStatus,
         request_date -> Int8,
+        certifying_body_id -> Nullable<Varchar>,
+        decline_reason -> Nullable<Varchar>,
+    }
+}
+
+table! {
+    use diesel::sql_types::*;
+    use super::AuditOutcome;
+    audits (id) {
+        id -> Int8,
+        start_block_num -> Int8,
+        end_block_num -> Int8,
+        audit_id -> Varchar,
+        request_id -> Varchar,
+        factory_id -> Varchar,
+        certifying_body_id -> Varchar,
+        auditor_public_key -> Varchar,
+        audit_date -> Int8,
+        outcome -> AuditOutcome,
+    }
+}
+
+table! {
+    use diesel::sql_types::*;
+    use super::FindingSeverity;
+    audit_findings (id) {
+        id -> Int8,
+        start_block_num -> Int8,
+        end_block_num -> Int8,
+        audit_id -> Varchar,
+        description -> Varchar,
+        severity -> FindingSeverity,
     }
 }
 
 table! {
+    use diesel::sql_types::*;
+    use super::FindingSeverity;
+    use super::NonConformanceStatus;
+    non_conformances (id) {
+        id -> Int8,
+        start_block_num -> Int8,
+        end_block_num -> Int8,
+        non_conformance_id -> Varchar,
+        factory_id -> Varchar,
+        certifying_body_id -> Varchar,
+        audit_id -> Nullable<Varchar>,
+        description -> Varchar,
+        severity -> FindingSeverity,
+        status -> NonConformanceStatus,
+        corrective_action_plan -> Nullable<Varchar>,
+        raised_at -> Int8,
+        corrective_action_submitted_at -> Nullable<Int8>,
+        closed_at -> Nullable<Int8>,
+    }
+}
+
+table! {
+    use diesel::sql_types::*;
+    use super::StandardStatus;
     standards (id) {
         id -> Int8,
         start_block_num -> Int8,
@@ -140,6 +243,7 @@ table! {
         standard_id -> Varchar,
         organization_id -> Varchar,
         name -> Varchar,
+        status -> StandardStatus,
     }
 }
 
@@ -153,22 +257,114 @@ table! {
         link -> Varchar,
         description -> Varchar,
         approval_date -> Int8,
+        retirement_date -> Nullable<Int8>,
+    }
+}
+
+table! {
+    use diesel::sql_types::*;
+    use super::DataFieldType;
+    standard_data_fields (id) {
+        id -> Int8,
+        start_block_num -> Int8,
+        end_block_num -> Int8,
+        standard_id -> Varchar,
+        version -> Varchar,
+        name -> Varchar,
+        data_type -> DataFieldType,
+        required -> Bool,
+        allowed_values -> Array<Varchar>,
+        pattern -> Nullable<Varchar>,
+    }
+}
+
+table! {
+    standard_equivalences (id) {
+        id -> Int8,
+        start_block_num -> Int8,
+        end_block_num -> Int8,
+        standard_id -> Varchar,
+        version -> Varchar,
+        equivalent_standard_id -> Varchar,
+        equivalent_standard_version -> Varchar,
+    }
+}
+
+table! {
+    use diesel::sql_types::*;
+    use super::ProposalStatus;
+    proposals (id) {
+        id -> Int8,
+        start_block_num -> Int8,
+        end_block_num -> Int8,
+        proposal_id -> Varchar,
+        organization_id -> Varchar,
+        proposer -> Varchar,
+        action -> Varchar,
+        payload -> Varchar,
+        required_approvals -> Int4,
+        status -> ProposalStatus,
+        proposed_at -> Int8,
+    }
+}
+
+table! {
+    proposal_votes (id) {
+        id -> Int8,
+        start_block_num -> Int8,
+        end_block_num -> Int8,
+        proposal_id -> Varchar,
+        public_key -> Varchar,
+        accept -> Bool,
+    }
+}
+
+table! {
+    use diesel::sql_types::*;
+    use super::AccreditationRequestStatus;
+    accreditation_requests (id) {
+        id -> Int8,
+        start_block_num -> Int8,
+        end_block_num -> Int8,
+        accreditation_request_id -> Varchar,
+        certifying_body_id -> Varchar,
+        standards_body_id -> Varchar,
+        standard_id -> Varchar,
+        status -> AccreditationRequestStatus,
+        request_date -> Int8,
+        rejection_reason -> Nullable<Varchar>,
+        standard_version -> Nullable<Varchar>,
+    }
+}
+
+table! {
+    events (id) {
+        id -> Int8,
+        start_block_num -> Int8,
+        end_block_num -> Int8,
+        event_id -> Varchar,
+        event_type -> Varchar,
+    }
+}
+
+table! {
+    event_attributes (id) {
+        id -> Int8,
+        start_block_num -> Int8,
+        end_block_num -> Int8,
+        event_id -> Varchar,
+        key -> Varchar,
+        value -> Varchar,
     }
 }
 
 table! {
     retailer_factories (id) {
         id -> Int8,
+        start_block_num -> Int8,
+        end_block_num -> Int8,
+        retailer_id -> Varchar,
         factory_id -> Varchar,
-        factory_name -> Varchar,
-        contact_name -> Varchar,
-        contact_phone_number -> Varchar,
-        contact_language_code -> Varchar,
-        country -> Varchar,
-        state_province -> Varchar,
-        city -> Varchar,
-        street_line_1 -> Varchar,
-        street_line_2 -> Varchar,
     }
 }
 
@@ -194,12 +390,24 @@ allow_tables_to_appear_in_same_query!(
     blocks,
     certificate_data,
     certificates,
+    certificate_suspensions,
+    document_references,
     chain_record,
     organizations,
+    registry_operators,
     requests,
+    audits,
+    audit_findings,
+    non_conformances,
+    proposals,
+    proposal_votes,
+    events,
+    event_attributes,
     contacts,
     standards,
     standard_versions,
+    standard_data_fields,
+    standard_equivalences,
     retailer_factories,
     accreditations,
 );
@@ -1,9 +1,18 @@
 
 -- Create custom types
 
-CREATE TYPE Role AS ENUM ('ADMIN', 'TRANSACTOR', 'UNSET_ROLE');
-CREATE TYPE OrganizationType AS ENUM ('STANDARDS_BODY', 'CERTIFYING_BODY', 'FACTORY', 'UNSET_TYPE');
-CREATE TYPE RequestStatus AS ENUM ('OPEN', 'IN_PROGRESS', 'CLOSED', 'CERTIFIED', 'UNSET_STATUS');
+CREATE TYPE Role AS ENUM ('ADMIN', 'TRANSACTOR', 'CUSTOM', 'UNSET_ROLE');
+CREATE TYPE OrganizationType AS ENUM ('STANDARDS_BODY', 'CERTIFYING_BODY', 'FACTORY', 'RETAILER', 'UNSET_TYPE');
+CREATE TYPE RequestStatus AS ENUM ('OPEN', 'IN_PROGRESS', 'CLOSED', 'CERTIFIED', 'ACCEPTED', 'DECLINED', 'UNSET_STATUS');
+CREATE TYPE CertificateStatus AS ENUM ('VALID', 'REVOKED', 'SUSPENDED', 'UNSET_STATUS');
+CREATE TYPE ApprovalStatus AS ENUM ('PENDING', 'APPROVED', 'REJECTED', 'UNSET_APPROVAL');
+CREATE TYPE AuditOutcome AS ENUM ('PASSED', 'FAILED', 'UNSET_OUTCOME');
+CREATE TYPE FindingSeverity AS ENUM ('MINOR', 'MAJOR', 'CRITICAL', 'UNSET_SEVERITY');
+CREATE TYPE NonConformanceStatus AS ENUM ('OPEN', 'CORRECTIVE_ACTION_SUBMITTED', 'CLOSED', 'UNSET_STATUS');
+CREATE TYPE ProposalStatus AS ENUM ('OPEN', 'ACCEPTED', 'REJECTED', 'WITHDRAWN', 'UNSET_STATUS');
+CREATE TYPE AccreditationRequestStatus AS ENUM ('OPEN', 'IN_REVIEW', 'APPROVED', 'REJECTED', 'UNSET_STATUS');
+CREATE TYPE StandardStatus AS ENUM ('ACTIVE', 'RETIRED', 'UNSET_STATUS');
+CREATE TYPE DataFieldType AS ENUM ('STRING', 'INTEGER', 'DECIMAL', 'BOOLEAN', 'ENUMERATION', 'UNSET_TYPE');
 
 
 -- Create tables
@@ -26,7 +35,8 @@ CREATE TABLE IF NOT EXISTS agents (
   public_key                 VARCHAR    NOT NULL,
   name                       VARCHAR    NOT NULL,
   organization_id            VARCHAR,
-  timestamp                  BIGINT     NOT NULL
+  timestamp                  BIGINT     NOT NULL,
+  successor_public_key       VARCHAR
 ) INHERITS (chain_record);
 
 CREATE INDEX IF NOT EXISTS agents_pub_key_index ON agents (public_key);
@@ -36,12 +46,21 @@ CREATE TABLE IF NOT EXISTS organizations (
   id                         BIGSERIAL  PRIMARY KEY,
   organization_id            VARCHAR    NOT NULL,
   name                       VARCHAR    NOT NULL,
-  organization_type          OrganizationType  NOT NULL
+  organization_type          OrganizationType  NOT NULL,
+  approval_status            ApprovalStatus    NOT NULL
 ) INHERITS (chain_record);
 
 CREATE INDEX IF NOT EXISTS organizations_organization_id_index ON organizations (organization_id);
 CREATE INDEX IF NOT EXISTS organizations_block_index ON organizations (end_block_num);
 
+CREATE TABLE IF NOT EXISTS registry_operators (
+  id                         BIGSERIAL  PRIMARY KEY,
+  public_key                 VARCHAR    NOT NULL
+) INHERITS (chain_record);
+
+CREATE INDEX IF NOT EXISTS registry_operators_public_key_index ON registry_operators (public_key);
+CREATE INDEX IF NOT EXISTS registry_operators_block_index ON registry_operators (end_block_num);
+
 CREATE TABLE IF NOT EXISTS contacts (
   id                         BIGSERIAL  PRIMARY KEY,
   organization_id            VARCHAR    NOT NULL,
@@ -57,7 +76,10 @@ CREATE TABLE IF NOT EXISTS authorizations (
   id                         BIGSERIAL  PRIMARY KEY,
   organization_id            VARCHAR    NOT NULL,
   public_key                 VARCHAR    NOT NULL,
-  role                       Role       NOT NULL
+  role                       Role       NOT NULL,
+  role_name                  VARCHAR,
+  valid_from                 BIGINT,
+  valid_to                   BIGINT
 ) INHERITS (chain_record);
 
 CREATE INDEX IF NOT EXISTS authorizations_organization_id_index ON authorizations (organization_id);
@@ -85,11 +107,42 @@ CREATE TABLE IF NOT EXISTS certificates (
   standard_id                VARCHAR    NOT NULL,
   standard_version           VARCHAR    NOT NULL,
   valid_from                 BIGINT     NOT NULL,
-  valid_to                   BIGINT     NOT NULL
+  valid_to                   BIGINT     NOT NULL,
+  status                     CertificateStatus  NOT NULL,
+  revoked_at                 BIGINT,
+  previous_certificate_id    VARCHAR,
+  audit_id                   VARCHAR
 ) INHERITS (chain_record);
 
 CREATE INDEX IF NOT EXISTS certificates_certificate_id_index ON certificates (certificate_id);
 CREATE INDEX IF NOT EXISTS certificates_block_index ON certificates (end_block_num);
+CREATE INDEX IF NOT EXISTS certificates_previous_certificate_id_index ON certificates (previous_certificate_id);
+
+CREATE TABLE IF NOT EXISTS certificate_suspensions (
+  id                         BIGSERIAL  PRIMARY KEY,
+  certificate_id             VARCHAR    NOT NULL,
+  reason                     VARCHAR    NOT NULL,
+  suspended_at               BIGINT     NOT NULL,
+  reinstated_at              BIGINT
+) INHERITS (chain_record);
+
+CREATE INDEX IF NOT EXISTS certificate_suspensions_certificate_id_index ON certificate_suspensions (certificate_id);
+CREATE INDEX IF NOT EXISTS certificate_suspensions_block_index ON certificate_suspensions (end_block_num);
+
+CREATE TABLE IF NOT EXISTS document_references (
+  id                         BIGSERIAL  PRIMARY KEY,
+  certificate_id             VARCHAR,
+  request_id                 VARCHAR,
+  sha256                     VARCHAR    NOT NULL,
+  media_type                 VARCHAR    NOT NULL,
+  title                      VARCHAR    NOT NULL,
+  uri                        VARCHAR
+) INHERITS (chain_record);
+
+CREATE INDEX IF NOT EXISTS document_references_certificate_id_index ON document_references (certificate_id);
+CREATE INDEX IF NOT EXISTS document_references_request_id_index ON document_references (request_id);
+CREATE INDEX IF NOT EXISTS document_references_sha256_index ON document_references (sha256);
+CREATE INDEX IF NOT EXISTS document_references_block_index ON document_references (end_block_num);
 
 CREATE TABLE IF NOT EXISTS addresses (
   id                 BIGSERIAL   PRIMARY KEY,
@@ -120,7 +173,8 @@ CREATE TABLE IF NOT EXISTS standards (
   id                         BIGSERIAL  PRIMARY KEY,
   standard_id                VARCHAR    NOT NULL,
   organization_id            VARCHAR    NOT NULL,
-  name                       VARCHAR    NOT NULL
+  name                       VARCHAR    NOT NULL,
+  status                     StandardStatus NOT NULL
 ) INHERITS (chain_record);
 
 CREATE INDEX IF NOT EXISTS standards_id_index ON standards (standard_id);
@@ -132,12 +186,38 @@ CREATE TABLE IF NOT EXISTS standard_versions (
   version                    VARCHAR     NOT NULL,
   link                       VARCHAR     NOT NULL,
   description                VARCHAR     NOT NULL,
-  approval_date              BIGINT      NOT NULL
+  approval_date              BIGINT      NOT NULL,
+  retirement_date            BIGINT
 ) INHERITS (chain_record);
 
 CREATE INDEX IF NOT EXISTS standard_versions_id_index ON standard_versions (standard_id);
 CREATE INDEX IF NOT EXISTS standard_versions_block_index ON standard_versions (end_block_num);
 
+CREATE TABLE IF NOT EXISTS standard_data_fields (
+  id                         BIGSERIAL      PRIMARY KEY,
+  standard_id                VARCHAR        NOT NULL,
+  version                    VARCHAR        NOT NULL,
+  name                       VARCHAR        NOT NULL,
+  data_type                  DataFieldType  NOT NULL,
+  required                   BOOLEAN        NOT NULL,
+  allowed_values             VARCHAR[]      NOT NULL,
+  pattern                    VARCHAR
+) INHERITS (chain_record);
+
+CREATE INDEX IF NOT EXISTS standard_data_fields_id_index ON standard_data_fields (standard_id);
+CREATE INDEX IF NOT EXISTS standard_data_fields_block_index ON standard_data_fields (end_block_num);
+
+CREATE TABLE IF NOT EXISTS standard_equivalences (
+  id                          BIGSERIAL   PRIMARY KEY,
+  standard_id                 VARCHAR     NOT NULL,
+  version                     VARCHAR     NOT NULL,
+  equivalent_standard_id      VARCHAR     NOT NULL,
+  equivalent_standard_version VARCHAR     NOT NULL
+) INHERITS (chain_record);
+
+CREATE INDEX IF NOT EXISTS standard_equivalences_id_index ON standard_equivalences (standard_id);
+CREATE INDEX IF NOT EXISTS standard_equivalences_block_index ON standard_equivalences (end_block_num);
+
 CREATE TABLE IF NOT EXISTS users (
   public_key                 VARCHAR     PRIMARY KEY,
   transaction_id             VARCHAR     NOT NULL,
@@ -153,19 +233,121 @@ CREATE TABLE IF NOT EXISTS requests (
   factory_id                  VARCHAR        NOT NULL,
   standard_id                 VARCHAR        NOT NULL,
   status                      RequestStatus  NOT NULL,
-  request_date                BIGINT         NOT NULL
+  request_date                BIGINT         NOT NULL,
+  certifying_body_id          VARCHAR,
+  decline_reason              VARCHAR
+) INHERITS (chain_record);
+
+CREATE TABLE IF NOT EXISTS audits (
+  id                          BIGSERIAL      PRIMARY KEY,
+  audit_id                    VARCHAR        NOT NULL,
+  request_id                  VARCHAR        NOT NULL,
+  factory_id                  VARCHAR        NOT NULL,
+  certifying_body_id          VARCHAR        NOT NULL,
+  auditor_public_key          VARCHAR        NOT NULL,
+  audit_date                  BIGINT         NOT NULL,
+  outcome                     AuditOutcome   NOT NULL
 ) INHERITS (chain_record);
 
+CREATE INDEX IF NOT EXISTS audits_audit_id_index ON audits (audit_id);
+CREATE INDEX IF NOT EXISTS audits_request_id_index ON audits (request_id);
+CREATE INDEX IF NOT EXISTS audits_block_index ON audits (end_block_num);
+
+CREATE TABLE IF NOT EXISTS audit_findings (
+  id                          BIGSERIAL        PRIMARY KEY,
+  audit_id                    VARCHAR          NOT NULL,
+  description                 VARCHAR          NOT NULL,
+  severity                    FindingSeverity  NOT NULL
+) INHERITS (chain_record);
+
+CREATE INDEX IF NOT EXISTS audit_findings_audit_id_index ON audit_findings (audit_id);
+CREATE INDEX IF NOT EXISTS audit_findings_block_index ON audit_findings (end_block_num);
+
+CREATE TABLE IF NOT EXISTS non_conformances (
+  id                              BIGSERIAL             PRIMARY KEY,
+  non_conformance_id              VARCHAR               NOT NULL,
+  factory_id                      VARCHAR               NOT NULL,
+  certifying_body_id              VARCHAR               NOT NULL,
+  audit_id                        VARCHAR,
+  description                     VARCHAR               NOT NULL,
+  severity                        FindingSeverity       NOT NULL,
+  status                          NonConformanceStatus  NOT NULL,
+  corrective_action_plan          VARCHAR,
+  raised_at                       BIGINT                NOT NULL,
+  corrective_action_submitted_at  BIGINT,
+  closed_at                       BIGINT
+) INHERITS (chain_record);
+
+CREATE INDEX IF NOT EXISTS non_conformances_non_conformance_id_index ON non_conformances (non_conformance_id);
+CREATE INDEX IF NOT EXISTS non_conformances_factory_id_index ON non_conformances (factory_id);
+CREATE INDEX IF NOT EXISTS non_conformances_block_index ON non_conformances (end_block_num);
+
+CREATE TABLE IF NOT EXISTS proposals (
+  id                          BIGSERIAL        PRIMARY KEY,
+  proposal_id                 VARCHAR          NOT NULL,
+  organization_id             VARCHAR          NOT NULL,
+  proposer                    VARCHAR          NOT NULL,
+  action                      VARCHAR          NOT NULL,
+  payload                     VARCHAR          NOT NULL,
+  required_approvals          INTEGER          NOT NULL,
+  status                      ProposalStatus   NOT NULL,
+  proposed_at                 BIGINT           NOT NULL
+) INHERITS (chain_record);
+
+CREATE INDEX IF NOT EXISTS proposals_proposal_id_index ON proposals (proposal_id);
+CREATE INDEX IF NOT EXISTS proposals_organization_id_index ON proposals (organization_id);
+CREATE INDEX IF NOT EXISTS proposals_block_index ON proposals (end_block_num);
+
+CREATE TABLE IF NOT EXISTS proposal_votes (
+  id                          BIGSERIAL      PRIMARY KEY,
+  proposal_id                 VARCHAR        NOT NULL,
+  public_key                  VARCHAR        NOT NULL,
+  accept                      BOOLEAN        NOT NULL
+) INHERITS (chain_record);
+
+CREATE INDEX IF NOT EXISTS proposal_votes_proposal_id_index ON proposal_votes (proposal_id);
+CREATE INDEX IF NOT EXISTS proposal_votes_block_index ON proposal_votes (end_block_num);
+
+CREATE TABLE IF NOT EXISTS accreditation_requests (
+  id                          BIGSERIAL                    PRIMARY KEY,
+  accreditation_request_id    VARCHAR                      NOT NULL,
+  certifying_body_id          VARCHAR                      NOT NULL,
+  standards_body_id           VARCHAR                      NOT NULL,
+  standard_id                 VARCHAR                      NOT NULL,
+  status                      AccreditationRequestStatus   NOT NULL,
+  request_date                BIGINT                       NOT NULL,
+  rejection_reason            VARCHAR,
+  standard_version            VARCHAR
+) INHERITS (chain_record);
+
+CREATE INDEX IF NOT EXISTS accreditation_requests_accreditation_request_id_index ON accreditation_requests (accreditation_request_id);
+CREATE INDEX IF NOT EXISTS accreditation_requests_certifying_body_id_index ON accreditation_requests (certifying_body_id);
+CREATE INDEX IF NOT EXISTS accreditation_requests_block_index ON accreditation_requests (end_block_num);
+
+CREATE TABLE IF NOT EXISTS events (
+  id                          BIGSERIAL      PRIMARY KEY,
+  event_id                    VARCHAR        NOT NULL,
+  event_type                  VARCHAR        NOT NULL
+) INHERITS (chain_record);
+
+CREATE INDEX IF NOT EXISTS events_event_id_index ON events (event_id);
+CREATE INDEX IF NOT EXISTS events_event_type_index ON events (event_type);
+
+CREATE TABLE IF NOT EXISTS event_attributes (
+  id                          BIGSERIAL      PRIMARY KEY,
+  event_id                    VARCHAR        NOT NULL,
+  key                         VARCHAR        NOT NULL,
+  value                       VARCHAR        NOT NULL
+) INHERITS (chain_record);
+
+CREATE INDEX IF NOT EXISTS event_attributes_event_id_index ON event_attributes (event_id);
+CREATE INDEX IF NOT EXISTS event_attributes_key_value_index ON event_attributes (key, value);
+
 CREATE TABLE IF NOT EXISTS retailer_factories (
   id                          BIGSERIAL      PRIMARY KEY,
-  factory_id                  VARCHAR,
-  factory_name                VARCHAR,
-  contact_name                VARCHAR,
-  contact_phone_number        VARCHAR,
-  contact_language_code       VARCHAR,
-  country                     VARCHAR,
-  state_province              VARCHAR,
-  city                        VARCHAR,
-  street_line_1               VARCHAR,
-  street_line_2               VARCHAR
-)
+  retailer_id                 VARCHAR        NOT NULL,
+  factory_id                  VARCHAR        NOT NULL
+) INHERITS (chain_record);
+
+CREATE INDEX IF NOT EXISTS retailer_factories_retailer_id_index ON retailer_factories (retailer_id);
+CREATE INDEX IF NOT EXISTS retailer_factories_block_index ON retailer_factories (end_block_num);
@@ -70,6 +70,9 @@ class ConsensourceTest(unittest.TestCase):
         cls.signer1 = make_signer()
         cls.signer2 = make_signer()
 
+        # Timestamps are validated against the block time recorded by BlockInfo
+        cls.timestamp = int(time.time())
+
     # Agent Tests
     # Notes:
     #     CreateAgent validation rules:
@@ -82,7 +85,7 @@ class ConsensourceTest(unittest.TestCase):
         status_result = self.client.create_agent(
             signer=self.signer1,
             name='alice',
-            timestamp=0)[0]
+            timestamp=self.timestamp)[0]
         invalid_txns = status_result.get('invalid_transactions')
         self.assertEqual(
             status_result['status'],
@@ -94,7 +97,7 @@ class ConsensourceTest(unittest.TestCase):
         expected = agent_pb2.Agent(
             public_key=self.signer1.get_public_key().as_hex(),
             name='alice',
-            timestamp=0)
+            timestamp=self.timestamp)
         self.assertEqual(
             actual,
             expected,
@@ -107,7 +110,7 @@ class ConsensourceTest(unittest.TestCase):
         status_result = self.client.create_agent(
             signer=self.signer2,
             name=None,
-            timestamp=1)[0]
+            timestamp=self.timestamp)[0]
         invalid_txns = status_result.get('invalid_transactions')
         self.assertEqual(
             status_result['status'],
@@ -128,7 +131,7 @@ class ConsensourceTest(unittest.TestCase):
         status_result = self.client.create_agent(
             signer=self.signer2,
             name='',
-            timestamp=2)[0]
+            timestamp=self.timestamp)[0]
         invalid_txns = status_result.get('invalid_transactions')
         self.assertEqual(
             status_result['status'],
@@ -149,7 +152,7 @@ class ConsensourceTest(unittest.TestCase):
         status_result = self.client.create_agent(
             signer=self.signer1,
             name='bob',
-            timestamp=3)[0]
+            timestamp=self.timestamp)[0]
         invalid_txns = status_result.get('invalid_transactions')
         self.assertEqual(
             status_result['status'],
@@ -20,6 +20,10 @@ from sawtooth_sdk.protobuf import transaction_pb2
 from protobuf import agent_pb2
 from protobuf import payload_pb2
 
+# Namespace of the Sawtooth BlockInfo transaction family, read by every
+# Consensource transaction to validate dates against the block time
+BLOCK_INFO_NAMESPACE = '00b10c'
+
 
 class ConsensourceTestClient():
     """Client application for Consensource used for testing.
@@ -68,7 +72,7 @@ class ConsensourceTestClient():
 
         batch = self._make_batch(
             payload=payload,
-            inputs=[agent_address],
+            inputs=[agent_address, BLOCK_INFO_NAMESPACE],
             outputs=[agent_address],
             signer=signer)
 
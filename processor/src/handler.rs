@@ -17,10 +17,19 @@ cfg_if! {
 }
 
 use common::addressing;
+use common::events;
 use common::proto;
-use payload::{Action, CertPayload};
+use payload::{compile_data_pattern, validate_proposed_payload, Action, CertPayload};
 use protobuf;
 use state::CertState;
+use std::collections::HashSet;
+
+/// Number of seconds a client supplied timestamp may precede the block time
+const MAX_TIMESTAMP_DRIFT: u64 = 300;
+
+/// Number of seconds a client supplied timestamp may follow the block time. It is wider than
+/// MAX_TIMESTAMP_DRIFT as the block time recorded by BlockInfo lags by one block.
+const MAX_TIMESTAMP_LEAD: u64 = 600;
 
 pub struct CertTransactionHandler {
     family_name: String,
@@ -32,7 +41,10 @@ impl CertTransactionHandler {
     pub fn new() -> CertTransactionHandler {
         CertTransactionHandler {
             family_name: addressing::FAMILY_NAMESPACE.to_string(),
-            family_versions: vec![addressing::FAMILY_VERSION.to_string()],
+            family_versions: addressing::FAMILY_VERSIONS
+                .iter()
+                .map(|version| version.to_string())
+                .collect(),
             namespaces: vec![addressing::get_family_namespace_prefix()],
         }
     }
@@ -42,12 +54,13 @@ impl CertTransactionHandler {
     /// # Errors
     /// Returns an error if:
     ///     - Signer public key already associated with an agent
+    ///     - The timestamp drifts too far from the block time
     ///     - It fails to submit the new Agent to state.
     /// ```
     pub fn create_agent(
         &self,
         payload: &proto::payload::CreateAgentAction,
-        mut state: CertState,
+        state: &mut CertState,
         signer_public_key: &str,
     ) -> Result<(), ApplyError> {
         match state.get_agent(signer_public_key) {
@@ -59,6 +72,8 @@ impl CertTransactionHandler {
             Err(err) => Err(err),
         }?;
 
+        self.validate_timestamp(state, payload.get_timestamp(), "Timestamp")?;
+
         // Create agent
         let mut new_agent = proto::agent::Agent::new();
         new_agent.set_public_key(signer_public_key.to_string());
@@ -84,7 +99,7 @@ impl CertTransactionHandler {
     pub fn create_organization(
         &self,
         payload: &proto::payload::CreateOrganizationAction,
-        mut state: CertState,
+        state: &mut CertState,
         signer_public_key: &str,
     ) -> Result<(), ApplyError> {
         match state.get_organization(payload.get_id()) {
@@ -106,6 +121,14 @@ impl CertTransactionHandler {
             Err(err) => Err(err),
         }?;
 
+        if !agent.get_successor_public_key().is_empty() {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Agent {} has been rotated to {}",
+                signer_public_key,
+                agent.get_successor_public_key()
+            )));
+        }
+
         if !agent.get_organization_id().is_empty() {
             return Err(ApplyError::InvalidTransaction(format!(
                 "Agent is already associated with an organization: {}",
@@ -140,10 +163,20 @@ impl CertTransactionHandler {
             transactor_authorization,
         ]));
 
-        if payload.get_organization_type() == proto::organization::Organization_Type::FACTORY {
-            let mut factory_details = proto::organization::Factory::new();
-            factory_details.set_address(payload.get_address().clone());
-            new_organization.set_factory_details(factory_details);
+        match payload.get_organization_type() {
+            proto::organization::Organization_Type::FACTORY => {
+                let mut factory_details = proto::organization::Factory::new();
+                factory_details.set_address(payload.get_address().clone());
+                new_organization.set_factory_details(factory_details);
+            }
+            proto::organization::Organization_Type::RETAILER => {
+                new_organization.set_retailer_details(proto::organization::Retailer::new());
+            }
+            _ => {
+                // Standards bodies and certifying bodies must be approved by a registry operator
+                new_organization
+                    .set_approval_status(proto::organization::Organization_ApprovalStatus::PENDING);
+            }
         }
 
         // Put organization in state
@@ -160,13 +193,13 @@ impl CertTransactionHandler {
     ///   - the Organization to be updated does not exist
     ///   - an Agent with the signer public key does not exist
     ///   - the Agent submitting the transaction is not associated with the organization
-    ///   - the Agent submitting the transaction is not authorized as an ADMIN of the organization
+    ///   - the Agent submitting the transaction lacks the MANAGE_ORGANIZATION permission
     ///   - it fails to submit the Organization to state.
     /// ```
     pub fn update_organization(
         &self,
         payload: &proto::payload::UpdateOrganizationAction,
-        mut state: CertState,
+        state: &mut CertState,
         signer_public_key: &str,
     ) -> Result<(), ApplyError> {
         // Check agent
@@ -197,17 +230,13 @@ impl CertTransactionHandler {
         }?;
 
         // Validate agent is authorized
-        let mut is_admin = false;
-        for authorization in organization.get_authorizations() {
-            if authorization.get_public_key() == signer_public_key
-                && authorization.get_role()
-                    == proto::organization::Organization_Authorization_Role::ADMIN
-            {
-                is_admin = true;
-                break;
-            }
-        }
-        if !is_admin {
+        let block_time = state.get_block_timestamp()?;
+        if !is_authorized(
+            signer_public_key,
+            &organization,
+            proto::organization::Organization_Permission::MANAGE_ORGANIZATION,
+            block_time,
+        ) {
             return Err(ApplyError::InvalidTransaction(format!(
                 "Agent is not authorized to update organization: {}",
                 agent.get_organization_id()
@@ -247,16 +276,17 @@ impl CertTransactionHandler {
     /// Returns an error if
     ///   - the Organization to be updated does not exist
     ///   - an Agent with the signer public key does not exist
-    ///   - the Agent submitting the transaction is not authorized as an ADMIN of the organization
+    ///   - the Agent submitting the transaction lacks the MANAGE_ORGANIZATION permission
     ///   - the Agent submitting the transaction is not associated with the organization
     ///   - and Agent with the public key being authorized does not exist
     ///   - the Agent being authorized is already associated with a different Organization
+    ///   - a CUSTOM role is not defined by the Organization
     ///   - it fails to submit the Organization to state.
     /// ```
     pub fn authorize_agent(
         &self,
         payload: &proto::payload::AuthorizeAgentAction,
-        mut state: CertState,
+        state: &mut CertState,
         signer_public_key: &str,
     ) -> Result<(), ApplyError> {
         // Validate an agent associated with the signer public key exists
@@ -291,20 +321,19 @@ impl CertTransactionHandler {
             organization.unwrap()
         };
 
-        {
-            // Validate signer agent is an ADMIN
-            let authorization = organization.get_authorizations().iter().find(|auth| {
-                auth.get_public_key() == signer_public_key
-                    && auth.get_role()
-                        == proto::organization::Organization_Authorization_Role::ADMIN
-            });
-            if authorization.is_none() {
-                return Err(ApplyError::InvalidTransaction(format!(
-                    "Signing agent {} is not an authorized ADMIN for the organization: {}",
-                    signer_public_key,
-                    signer_agent.get_organization_id()
-                )));
-            }
+        // Validate signer agent may manage the organization
+        let block_time = state.get_block_timestamp()?;
+        if !is_authorized(
+            signer_public_key,
+            &organization,
+            proto::organization::Organization_Permission::MANAGE_ORGANIZATION,
+            block_time,
+        ) {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Signing agent {} is not an authorized ADMIN for the organization: {}",
+                signer_public_key,
+                signer_agent.get_organization_id()
+            )));
         }
 
         // Validate agent to be authorized exists.
@@ -319,6 +348,14 @@ impl CertTransactionHandler {
             agent_to_be_authorized.unwrap()
         };
 
+        if !agent_to_be_authorized.get_successor_public_key().is_empty() {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Agent {} has been rotated to {}",
+                payload.get_public_key(),
+                agent_to_be_authorized.get_successor_public_key()
+            )));
+        }
+
         // Validate agent to be authorized is not already associated with an org
         // if the org is the same as the signer org, it will be allowed, in case
         // an authorization is being updated, e.g. an ISSUER is being promoted to ADMIN.
@@ -331,23 +368,43 @@ impl CertTransactionHandler {
             )));
         }
 
+        // Validate a CUSTOM role is defined by the organization
+        if payload.get_role() == proto::organization::Organization_Authorization_Role::CUSTOM
+            && !organization
+                .get_roles()
+                .iter()
+                .any(|role| role.get_name() == payload.get_role_name())
         {
-            let authorization = organization.get_authorizations().iter().find(|auth| {
-                auth.get_public_key() == agent_to_be_authorized.get_public_key()
-                    && auth.get_role() == payload.get_role()
-            });
-            if authorization.is_some() {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Role {} is not defined by organization {}",
+                payload.get_role_name(),
+                organization.get_id()
+            )));
+        }
+
+        // An authorization that is no longer, or not yet, current is replaced by the new one
+        let existing_index = organization.get_authorizations().iter().position(|auth| {
+            auth.get_public_key() == agent_to_be_authorized.get_public_key()
+                && auth.get_role() == payload.get_role()
+                && auth.get_role_name() == payload.get_role_name()
+        });
+        if let Some(index) = existing_index {
+            if is_authorization_current_at(&organization.get_authorizations()[index], block_time) {
                 return Err(ApplyError::InvalidTransaction(format!(
                     "Agent {} is already authorized as {:?}",
                     agent_to_be_authorized.get_public_key(),
                     payload.get_role()
                 )));
             }
+            organization.authorizations.remove(index);
         }
 
         let mut new_authorization = proto::organization::Organization_Authorization::new();
         new_authorization.set_public_key(agent_to_be_authorized.get_public_key().to_string());
         new_authorization.set_role(payload.get_role());
+        new_authorization.set_role_name(payload.get_role_name().to_string());
+        new_authorization.set_valid_from(payload.get_valid_from());
+        new_authorization.set_valid_to(payload.get_valid_to());
 
         organization.authorizations.push(new_authorization);
 
@@ -361,6 +418,291 @@ impl CertTransactionHandler {
         Ok(())
     }
 
+    /// Updates an existing Organization to remove an authorization from an agent
+    /// and submits it to state
+    ///
+    /// ```
+    /// # Errors
+    /// Returns an error if
+    ///   - an Agent with the signer public key does not exist
+    ///   - the Agent submitting the transaction is not associated with an organization
+    ///   - the Organization the signer is associated with does not exist
+    ///   - the Agent submitting the transaction lacks the MANAGE_ORGANIZATION permission
+    ///   - an Agent with the public key being revoked does not exist
+    ///   - the Agent being revoked is not associated with the signer's Organization
+    ///   - the Agent being revoked does not hold the role
+    ///   - the Organization would be left without an Agent holding the MANAGE_ORGANIZATION
+    ///     permission
+    ///   - the Organization would have fewer ADMINs than its admin quorum
+    ///   - it fails to submit the Organization or Agent to state.
+    /// ```
+    pub fn revoke_agent_authorization(
+        &self,
+        payload: &proto::payload::RevokeAgentAuthorizationAction,
+        state: &mut CertState,
+        signer_public_key: &str,
+    ) -> Result<(), ApplyError> {
+        // Validate an agent associated with the signer public key exists
+        let signer_agent = {
+            let signer_agent = state.get_agent(signer_public_key)?;
+            if signer_agent.is_none() {
+                return Err(ApplyError::InvalidTransaction(format!(
+                    "Signing agent does not exist: {}",
+                    signer_public_key
+                )));
+            }
+            signer_agent.unwrap()
+        };
+
+        // Validate signer is associated with an organization
+        if signer_agent.get_organization_id().is_empty() {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Transaction signer is not associated with an organization: {}",
+                signer_agent.get_organization_id(),
+            )));
+        }
+
+        // Validate the organization the signer is associated with exists
+        let mut organization = {
+            let organization = state.get_organization(signer_agent.get_organization_id())?;
+            if organization.is_none() {
+                return Err(ApplyError::InvalidTransaction(format!(
+                    "Organization does not exist: {}",
+                    signer_agent.get_organization_id()
+                )));
+            }
+            organization.unwrap()
+        };
+
+        // Validate signer agent may manage the organization
+        let block_time = state.get_block_timestamp()?;
+        if !is_authorized(
+            signer_public_key,
+            &organization,
+            proto::organization::Organization_Permission::MANAGE_ORGANIZATION,
+            block_time,
+        ) {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Signing agent {} is not an authorized ADMIN for the organization: {}",
+                signer_public_key,
+                signer_agent.get_organization_id()
+            )));
+        }
+
+        // Validate agent being revoked exists.
+        let mut agent_to_be_revoked = {
+            let agent_to_be_revoked = state.get_agent(payload.get_public_key())?;
+            if agent_to_be_revoked.is_none() {
+                return Err(ApplyError::InvalidTransaction(format!(
+                    "No agent exists: {}",
+                    payload.get_public_key()
+                )));
+            }
+            agent_to_be_revoked.unwrap()
+        };
+
+        // Validate agent being revoked belongs to the signer's organization
+        if agent_to_be_revoked.get_organization_id() != signer_agent.get_organization_id() {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Agent {} is not associated with the organization: {}",
+                agent_to_be_revoked.get_public_key(),
+                signer_agent.get_organization_id(),
+            )));
+        }
+
+        let authorization_index = match organization.get_authorizations().iter().position(|auth| {
+            auth.get_public_key() == agent_to_be_revoked.get_public_key()
+                && auth.get_role() == payload.get_role()
+                && auth.get_role_name() == payload.get_role_name()
+        }) {
+            Some(index) => Ok(index),
+            None => Err(ApplyError::InvalidTransaction(format!(
+                "Agent {} is not authorized as {:?}",
+                agent_to_be_revoked.get_public_key(),
+                payload.get_role()
+            ))),
+        }?;
+
+        organization.authorizations.remove(authorization_index);
+
+        // Validate the organization keeps at least one current ADMIN. The signer holds
+        // MANAGE_ORGANIZATION, so this only fails when the signer revokes their own.
+        let remaining_admin_count = get_admin_keys(&organization, block_time).len();
+        if remaining_admin_count == 0 {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Cannot revoke the last ADMIN of the organization: {}",
+                signer_agent.get_organization_id()
+            )));
+        }
+
+        // Validate the organization keeps enough ADMINs to reach its admin quorum
+        if remaining_admin_count < organization.get_admin_quorum() as usize {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Cannot revoke agent {}, organization {} would have fewer ADMINs than its admin quorum of {}",
+                agent_to_be_revoked.get_public_key(),
+                signer_agent.get_organization_id(),
+                organization.get_admin_quorum()
+            )));
+        }
+
+        let has_remaining_roles = organization
+            .get_authorizations()
+            .iter()
+            .any(|auth| auth.get_public_key() == agent_to_be_revoked.get_public_key());
+
+        // Put updated organization in state
+        state.set_organization(signer_agent.get_organization_id(), organization)?;
+
+        // Detach the agent from the organization once it holds no roles
+        if !has_remaining_roles {
+            agent_to_be_revoked.clear_organization_id();
+            state.set_agent(payload.get_public_key(), agent_to_be_revoked)?;
+        }
+
+        Ok(())
+    }
+
+    /// Moves an Agent and its Organization authorizations to a new public key,
+    /// leaving a tombstone at the previous address. Other records holding the previous key
+    /// are not migrated: rotation is rejected while an open Proposal or the registry
+    /// operators reference the key, and Audits keep the key that recorded them.
+    ///
+    /// ```
+    /// # Errors
+    /// Returns an error if
+    ///   - an Agent with the current public key does not exist or was already rotated
+    ///   - an Agent with the new public key already exists
+    ///   - the signer is neither the Agent itself nor holds the MANAGE_ORGANIZATION permission
+    ///     within the Agent's organization
+    ///   - the Organization the Agent is associated with does not exist
+    ///   - an open Proposal of the Organization was made or voted on with the current key
+    ///   - the current key is a registry operator
+    ///   - it fails to submit the Agents or Organization to state.
+    /// ```
+    pub fn rotate_agent_key(
+        &self,
+        payload: &proto::payload::RotateAgentKeyAction,
+        state: &mut CertState,
+        signer_public_key: &str,
+    ) -> Result<(), ApplyError> {
+        // Validate the agent being rotated exists
+        let mut agent = match state.get_agent(payload.get_public_key()) {
+            Ok(Some(agent)) => Ok(agent),
+            Ok(None) => Err(ApplyError::InvalidTransaction(format!(
+                "No agent exists: {}",
+                payload.get_public_key()
+            ))),
+            Err(err) => Err(err),
+        }?;
+
+        if !agent.get_successor_public_key().is_empty() {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Agent {} has already been rotated to {}",
+                payload.get_public_key(),
+                agent.get_successor_public_key()
+            )));
+        }
+
+        // Validate no agent exists at the new public key
+        match state.get_agent(payload.get_new_public_key()) {
+            Ok(Some(_)) => Err(ApplyError::InvalidTransaction(format!(
+                "Agent already exists: {}",
+                payload.get_new_public_key()
+            ))),
+            Ok(None) => Ok(()),
+            Err(err) => Err(err),
+        }?;
+
+        let mut organization = if agent.get_organization_id().is_empty() {
+            None
+        } else {
+            match state.get_organization(agent.get_organization_id()) {
+                Ok(Some(organization)) => Ok(Some(organization)),
+                Ok(None) => Err(ApplyError::InvalidTransaction(format!(
+                    "Organization does not exist: {}",
+                    agent.get_organization_id()
+                ))),
+                Err(err) => Err(err),
+            }?
+        };
+
+        // Validate signer is the agent itself or may manage the agent's organization
+        let block_time = state.get_block_timestamp()?;
+        if signer_public_key != payload.get_public_key() {
+            let is_admin = organization.as_ref().map_or(false, |organization| {
+                is_authorized(
+                    signer_public_key,
+                    organization,
+                    proto::organization::Organization_Permission::MANAGE_ORGANIZATION,
+                    block_time,
+                )
+            });
+            if !is_admin {
+                return Err(ApplyError::InvalidTransaction(format!(
+                    "Signer {} is not authorized to rotate the key of agent {}",
+                    signer_public_key,
+                    payload.get_public_key()
+                )));
+            }
+        }
+
+        // Validate no open proposal of the organization was made or voted on with the key
+        if let Some(ref organization) = organization {
+            for proposal_id in organization.get_open_proposal_ids() {
+                if let Some(proposal) = state.get_proposal(proposal_id)? {
+                    if proposal.get_proposer() == payload.get_public_key()
+                        || proposal
+                            .get_votes()
+                            .iter()
+                            .any(|vote| vote.get_public_key() == payload.get_public_key())
+                    {
+                        return Err(ApplyError::InvalidTransaction(format!(
+                            "Agent {} is referenced by open proposal {}. The proposal must be closed before the key can be rotated",
+                            payload.get_public_key(),
+                            proposal_id
+                        )));
+                    }
+                }
+            }
+        }
+
+        // Validate the key is not a registry operator
+        if let Some(registry) = state.get_registry()? {
+            if registry
+                .get_operators()
+                .iter()
+                .any(|operator| operator == payload.get_public_key())
+            {
+                return Err(ApplyError::InvalidTransaction(format!(
+                    "Agent {} is a registry operator. The operators must be updated before the key can be rotated",
+                    payload.get_public_key()
+                )));
+            }
+        }
+
+        let mut rotated_agent = agent.clone();
+        rotated_agent.set_public_key(payload.get_new_public_key().to_string());
+
+        // Move every authorization held by the agent to the new public key
+        if let Some(mut organization) = organization.take() {
+            for authorization in organization.authorizations.iter_mut() {
+                if authorization.get_public_key() == payload.get_public_key() {
+                    authorization.set_public_key(payload.get_new_public_key().to_string());
+                }
+            }
+            state.set_organization(agent.get_organization_id(), organization)?;
+        }
+
+        state.set_agent(payload.get_new_public_key(), rotated_agent)?;
+
+        // Leave a tombstone at the previous address
+        agent.clear_organization_id();
+        agent.set_successor_public_key(payload.get_new_public_key().to_string());
+        state.set_agent(payload.get_public_key(), agent)?;
+
+        Ok(())
+    }
+
     /// Creates a new Certificate and submits it to state
     ///
     /// ```
@@ -369,19 +711,30 @@ impl CertTransactionHandler {
     ///   - a certificate with the certificate id already exist
     ///   - an Agent with the signer public key does not exist
     ///   - the Agent submitting the transaction is not associated with the organization
-    ///   - the Agent submitting the transaction is not authorized as a TRANSACTOR of the organization
+    ///   - the Agent submitting the transaction lacks the ISSUE_CERTIFICATE permission
     ///   - the Organization the Agent is associated with is not a CertifyingBody
-    ///   - the standard does not exist
+    ///   - the Organization has not been approved by a registry operator
+    ///   - the standard does not exist or has been retired
+    ///   - the standard version, or the latest version if none is provided, does not exist for
+    ///     the standard
+    ///   - the certificate has already expired at the block time
+    ///   - the certificate expires after the retirement date of the standard version
+    ///   - the CertifyingBody does not hold an accreditation for the standard version that is
+    ///     current at both the certificate's valid_from date and the block time
+    ///   - the certificate data does not conform to the data schema of the standard version
     ///   - if source is from request:
     ///        - the request does not exist
-    ///        - the request does not have status set to IN_PROGRESS
+    ///        - the request is addressed to a CertifyingBody other than the Agent's
+    ///        - the request does not have status set to ACCEPTED or IN_PROGRESS
+    ///        - an audit is referenced that does not exist, was not recorded for the request
+    ///          by the Agent's CertifyingBody, or did not pass
     ///   - the factory the certificate is for does not exist. x
     ///   - it fails to submit the new Certificate to state.
     /// ```
     pub fn issue_certificate(
         &self,
         payload: &proto::payload::IssueCertificateAction,
-        mut state: CertState,
+        state: &mut CertState,
         signer_public_key: &str,
     ) -> Result<(), ApplyError> {
         // Verify that certificate ID is not already associated with a Certificate object
@@ -430,19 +783,23 @@ impl CertTransactionHandler {
             )));
         }
 
-        // Validate agent is authorized
-        let mut is_transactor = false;
-        let authorizations = organization.get_authorizations();
-        for authorization in authorizations {
-            if authorization.get_public_key() == signer_public_key
-                && authorization.get_role()
-                    == proto::organization::Organization_Authorization_Role::TRANSACTOR
-            {
-                is_transactor = true;
-                break;
-            }
+        if organization.get_approval_status()
+            != proto::organization::Organization_ApprovalStatus::APPROVED
+        {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Organization {} has not been approved by a registry operator",
+                agent.get_organization_id()
+            )));
         }
-        if !is_transactor {
+
+        // Validate agent is authorized
+        let block_time = state.get_block_timestamp()?;
+        if !is_authorized(
+            signer_public_key,
+            &organization,
+            proto::organization::Organization_Permission::ISSUE_CERTIFICATE,
+            block_time,
+        ) {
             return Err(ApplyError::InvalidTransaction(format!(
                 "Agent is not authorized to issue certificate: {}",
                 payload.get_id()
@@ -458,6 +815,13 @@ impl CertTransactionHandler {
             ));
         }
 
+        if valid_to < block_time {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Invalid dates. Valid to {} is before the block time {}",
+                valid_to, block_time
+            )));
+        }
+
         let (factory_id, standard_id) = match payload.get_source() {
             proto::payload::IssueCertificateAction_Source::FROM_REQUEST => {
                 let request = match state.get_request(payload.get_request_id())? {
@@ -468,17 +832,61 @@ impl CertTransactionHandler {
                     ))),
                 }?;
 
-                if request.get_status() != proto::request::Request_Status::IN_PROGRESS {
+                // Requests opened under family version 0.1 are not addressed to a certifying
+                // body, and may be certified by any of them
+                if !request.get_certifying_body_id().is_empty()
+                    && request.get_certifying_body_id() != agent.get_organization_id()
+                {
                     return Err(ApplyError::InvalidTransaction(format!(
-                        "The request with id {} has its status set to {:?}. Only requests with status set to IN_PROGRESS can be certified.",
+                        "The request with id {} is not addressed to certifying body {}",
+                        request.get_id(),
+                        agent.get_organization_id()
+                    )));
+                }
+
+                if request.get_status() != proto::request::Request_Status::ACCEPTED
+                    && request.get_status() != proto::request::Request_Status::IN_PROGRESS
+                {
+                    return Err(ApplyError::InvalidTransaction(format!(
+                        "The request with id {} has its status set to {:?}. Only requests with status set to ACCEPTED or IN_PROGRESS can be certified.",
                         request.get_id(),
                         request.get_status()
                     )));
                 }
 
+                if !payload.get_audit_id().is_empty() {
+                    let audit = match state.get_audit(payload.get_audit_id())? {
+                        Some(audit) => Ok(audit),
+                        None => Err(ApplyError::InvalidTransaction(format!(
+                            "Audit does not exist: {}",
+                            payload.get_audit_id()
+                        ))),
+                    }?;
+
+                    if audit.get_request_id() != request.get_id()
+                        || audit.get_certifying_body_id() != agent.get_organization_id()
+                    {
+                        return Err(ApplyError::InvalidTransaction(format!(
+                            "Audit {} was not recorded for request {} by certifying body {}",
+                            audit.get_id(),
+                            request.get_id(),
+                            agent.get_organization_id()
+                        )));
+                    }
+
+                    if audit.get_outcome() != proto::audit::Audit_Outcome::PASSED {
+                        return Err(ApplyError::InvalidTransaction(format!(
+                            "Audit {} did not pass. Certificates can only reference audits with outcome PASSED.",
+                            audit.get_id()
+                        )));
+                    }
+                }
+
                 // update status of request
                 let mut updated_request = request.clone();
                 updated_request.set_status(proto::request::Request_Status::CERTIFIED);
+                updated_request.set_certifying_body_id(agent.get_organization_id().to_string());
+                add_request_status_changed_event(state, &updated_request)?;
                 state.set_request(payload.get_request_id(), updated_request)?;
 
                 Ok((
@@ -508,20 +916,62 @@ impl CertTransactionHandler {
             }
         }?;
 
-        // Get standard version from organization's cert_body_details
-        let certifying_body_details = organization.get_certifying_body_details();
-        let accreditations = certifying_body_details.get_accreditations().to_vec();
-        if accreditations
+        // Validate the standard version exists
+        let standard = match state.get_standard(&standard_id) {
+            Ok(Some(standard)) => Ok(standard),
+            Ok(None) => Err(ApplyError::InvalidTransaction(format!(
+                "No standard with ID {} exists",
+                standard_id
+            ))),
+            Err(err) => Err(err),
+        }?;
+        validate_standard_active(&standard)?;
+
+        // Payloads decoded from family version 0.1 do not carry a standard version. They are
+        // issued against the latest version of the standard.
+        let standard_version = if payload.get_standard_version().is_empty() {
+            standard
+                .get_versions()
+                .last()
+                .map(|version| version.get_version().to_string())
+                .unwrap_or_default()
+        } else {
+            payload.get_standard_version().to_string()
+        };
+
+        let version = match standard
+            .get_versions()
+            .iter()
+            .find(|version| version.get_version() == standard_version)
+        {
+            Some(version) => Ok(version),
+            None => Err(ApplyError::InvalidTransaction(format!(
+                "Version {} does not exist for Standard {}",
+                standard_version, standard_id
+            ))),
+        }?;
+        validate_expires_by_retirement(&standard_id, version, valid_to)?;
+
+        // Validate the certifying body holds an accreditation for the standard version that
+        // is current when the certificate becomes valid and at the block time
+        if organization
+            .get_certifying_body_details()
+            .get_accreditations()
             .iter()
-            .find(|accreditation| accreditation.get_standard_id() == standard_id.to_string())
+            .find(|accreditation| {
+                accreditation.get_standard_id() == standard_id
+                    && accreditation.get_standard_version() == standard_version
+                    && covers_issuance(accreditation, valid_from, block_time)
+            })
             .is_none()
         {
             return Err(ApplyError::InvalidTransaction(format!(
-                "Certifying body is not accredited for Standard {}",
-                standard_id.to_string()
+                "Certifying body does not hold a current accreditation for Standard {}, version {}",
+                standard_id, standard_version
             )));
         }
-        let latest_standard_version = accreditations.last().unwrap();
+
+        validate_certificate_data(version, payload.get_certificate_data())?;
 
         // Create certificate
         let mut new_certificate = proto::certificate::Certificate::new();
@@ -529,13 +979,19 @@ impl CertTransactionHandler {
         new_certificate.set_certifying_body_id(agent.get_organization_id().to_string());
         new_certificate.set_factory_id(factory_id);
         new_certificate.set_standard_id(standard_id.to_string());
-        new_certificate
-            .set_standard_version(latest_standard_version.get_standard_version().to_string());
+        new_certificate.set_standard_version(standard_version);
         new_certificate.set_certificate_data(::protobuf::RepeatedField::from_vec(
             payload.get_certificate_data().to_vec(),
         ));
         new_certificate.set_valid_from(valid_from);
         new_certificate.set_valid_to(valid_to);
+        new_certificate.set_status(proto::certificate::Certificate_Status::VALID);
+        new_certificate.set_audit_id(payload.get_audit_id().to_string());
+        new_certificate.set_documents(::protobuf::RepeatedField::from_vec(
+            payload.get_documents().to_vec(),
+        ));
+
+        add_certificate_event(state, events::CERTIFICATE_ISSUED, &new_certificate)?;
 
         // Put certificate in state
         state.set_certificate(payload.get_id(), new_certificate)?;
@@ -543,26 +999,39 @@ impl CertTransactionHandler {
         Ok(())
     }
 
-    /// Creates a new Request and submits it to state
+    /// Revokes an existing Certificate and submits it to state
     ///
     /// ```
     /// # Errors
     /// Returns an error if
-    ///   - a request with the request id already exist
+    ///   - the certificate does not exist
     ///   - an Agent with the signer public key does not exist
-    ///   - the Agent submitting the transaction is not associated with the organization
-    ///   - the Agent submitting the transaction is not authorized as a TRANSACTOR of the organization
-    ///   - the Organization the Agent is associated with is not a Factory
-    ///   - the standard does not exist
-    ///   - it fails to submit the new Request to state.
+    ///   - the Agent submitting the transaction is not associated with an organization
+    ///   - the Organization the Agent is associated with did not issue the certificate
+    ///   - the Agent submitting the transaction lacks the MANAGE_CERTIFICATES permission
+    ///   - the certificate has already been revoked
+    ///   - the effective date is before the certificate's valid_from date
+    ///   - the certificate is suspended and the effective date is before the suspension took
+    ///     effect
+    ///   - it fails to submit the updated Certificate to state.
     /// ```
-    pub fn open_request(
+    pub fn revoke_certificate(
         &self,
-        payload: &proto::payload::OpenRequestAction,
-        mut state: CertState,
+        payload: &proto::payload::RevokeCertificateAction,
+        state: &mut CertState,
         signer_public_key: &str,
     ) -> Result<(), ApplyError> {
-        // Validate that the signer associated with a factory
+        // Verify that the certificate exists
+        let mut certificate = match state.get_certificate(payload.get_certificate_id()) {
+            Ok(Some(certificate)) => Ok(certificate),
+            Ok(None) => Err(ApplyError::InvalidTransaction(format!(
+                "Certificate does not exist: {}",
+                payload.get_certificate_id()
+            ))),
+            Err(err) => Err(err),
+        }?;
+
+        // Validate signer public key and agent
         let agent = match state.get_agent(signer_public_key) {
             Ok(Some(agent)) => Ok(agent),
             Ok(None) => Err(ApplyError::InvalidTransaction(format!(
@@ -571,6 +1040,23 @@ impl CertTransactionHandler {
             ))),
             Err(err) => Err(err),
         }?;
+
+        if agent.get_organization_id().is_empty() {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Agent is not associated with an organization: {}",
+                agent.get_organization_id(),
+            )));
+        }
+
+        // Validate the agent's organization issued the certificate
+        if agent.get_organization_id() != certificate.get_certifying_body_id() {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Organization {} did not issue certificate {}",
+                agent.get_organization_id(),
+                certificate.get_id()
+            )));
+        }
+
         let organization = match state.get_organization(agent.get_organization_id()) {
             Ok(Some(organization)) => Ok(organization),
             Ok(None) => Err(ApplyError::InvalidTransaction(format!(
@@ -579,97 +1065,94 @@ impl CertTransactionHandler {
             ))),
             Err(err) => Err(err),
         }?;
-        if organization.get_organization_type() != proto::organization::Organization_Type::FACTORY {
+
+        // Validate agent is authorized
+        let block_time = state.get_block_timestamp()?;
+        if !is_authorized(
+            signer_public_key,
+            &organization,
+            proto::organization::Organization_Permission::MANAGE_CERTIFICATES,
+            block_time,
+        ) {
             return Err(ApplyError::InvalidTransaction(format!(
-                "Organization {} is not a factory",
-                agent.get_organization_id()
+                "Agent {} is not authorized to revoke certificate {}",
+                signer_public_key,
+                certificate.get_id()
             )));
         }
 
-        // Validate that agent is a transactor
-        let mut is_transactor = false;
-        let authorizations = organization.get_authorizations();
-        for authorization in authorizations {
-            if authorization.get_public_key() == signer_public_key
-                && authorization.get_role()
-                    == proto::organization::Organization_Authorization_Role::TRANSACTOR
-            {
-                is_transactor = true;
-                break;
-            }
-        }
-        if !is_transactor {
+        if certificate.get_status() == proto::certificate::Certificate_Status::REVOKED {
             return Err(ApplyError::InvalidTransaction(format!(
-                "Agent {} is not authorized to open a request",
-                agent.get_public_key(),
+                "Certificate {} has already been revoked",
+                certificate.get_id()
             )));
         }
 
-        // Verify that the request does not already exist
-        match state.get_request(&payload.get_id()) {
-            Ok(Some(_)) => Err(ApplyError::InvalidTransaction(format!(
-                "Request already exists: {}",
-                payload.get_id()
-            ))),
-            Ok(None) => Ok(()),
-            Err(err) => Err(err),
-        }?;
+        if payload.get_effective_date() < certificate.get_valid_from() {
+            return Err(ApplyError::InvalidTransaction(
+                "Invalid date. Revocation cannot take effect before the certificate is valid"
+                    .to_string(),
+            ));
+        }
 
-        // Validate that the standard_id and version are associated with a valid standard
-        match state.get_standard(&payload.get_standard_id()) {
-            Ok(Some(_)) => Ok(()),
-            Ok(None) => Err(ApplyError::InvalidTransaction(format!(
-                "No standard with ID {} exists",
-                payload.get_standard_id()
-            ))),
-            Err(err) => Err(err),
-        }?;
+        // Revoking a suspended certificate ends its current suspension
+        if certificate.get_status() == proto::certificate::Certificate_Status::SUSPENDED {
+            if let Some(suspension) = certificate.suspensions.last_mut() {
+                if payload.get_effective_date() < suspension.get_suspended_at() {
+                    return Err(ApplyError::InvalidTransaction(
+                        "Invalid date. Revocation must be after the suspension took effect"
+                            .to_string(),
+                    ));
+                }
 
-        // Create and open new certification request
-        let mut request = proto::request::Request::new();
-        request.set_id(payload.get_id().to_string());
-        request.set_status(proto::request::Request_Status::OPEN);
-        request.set_standard_id(payload.get_standard_id().to_string());
-        request.set_factory_id(agent.get_organization_id().to_string());
-        request.set_request_date(payload.get_request_date());
+                suspension.set_reinstated_at(payload.get_effective_date());
+            }
+        }
 
-        // Put new request in state
-        state.set_request(&payload.get_id(), request)?;
+        certificate.set_status(proto::certificate::Certificate_Status::REVOKED);
+        certificate.set_revocation_reason(payload.get_reason());
+        certificate.set_revoked_at(payload.get_effective_date());
+
+        add_certificate_event(state, events::CERTIFICATE_STATUS_CHANGED, &certificate)?;
+
+        // Put updated certificate in state
+        state.set_certificate(payload.get_certificate_id(), certificate)?;
 
         Ok(())
     }
 
-    /// Updates an existing Request status and submits it to state
+    /// Suspends a valid Certificate and submits it to state
     ///
     /// ```
     /// # Errors
     /// Returns an error if
-    ///   - a request with the request id already exist
+    ///   - the certificate does not exist
     ///   - an Agent with the signer public key does not exist
-    ///   - the Agent submitting the transaction is not associated with the organization
-    ///   - the Agent submitting the transaction is not authorized as a TRANSACTOR of the organization
-    ///   - the Organization the Agent is associated with is not a Factory
-    ///   - the new request status is not IN_PROGRESS or CLOSED.
-    ///   - the current request status is not OPEN or IN_PROGRESS.
-    ///   - it fails to submit the updated Request to state.
+    ///   - the Agent submitting the transaction is not associated with an organization
+    ///   - the Organization the Agent is associated with did not issue the certificate
+    ///   - the Agent submitting the transaction lacks the MANAGE_CERTIFICATES permission
+    ///   - the certificate is revoked or already suspended
+    ///   - the suspension date is before the certificate's valid_from date
+    ///   - the suspension date is before the previous suspension was reinstated
+    ///   - it fails to submit the updated Certificate to state.
     /// ```
-    pub fn change_request_status(
+    pub fn suspend_certificate(
         &self,
-        payload: &proto::payload::ChangeRequestStatusAction,
-        mut state: CertState,
+        payload: &proto::payload::SuspendCertificateAction,
+        state: &mut CertState,
         signer_public_key: &str,
     ) -> Result<(), ApplyError> {
-        // Verify that the request does exist
-        let mut request = match state.get_request(&payload.request_id) {
-            Ok(Some(request)) => Ok(request),
+        // Verify that the certificate exists
+        let mut certificate = match state.get_certificate(payload.get_certificate_id()) {
+            Ok(Some(certificate)) => Ok(certificate),
             Ok(None) => Err(ApplyError::InvalidTransaction(format!(
-                "Request does not exists: {}",
-                payload.request_id
+                "Certificate does not exist: {}",
+                payload.get_certificate_id()
             ))),
             Err(err) => Err(err),
         }?;
 
-        // Validate that the signer associated with a factory
+        // Validate signer public key and agent
         let agent = match state.get_agent(signer_public_key) {
             Ok(Some(agent)) => Ok(agent),
             Ok(None) => Err(ApplyError::InvalidTransaction(format!(
@@ -678,6 +1161,23 @@ impl CertTransactionHandler {
             ))),
             Err(err) => Err(err),
         }?;
+
+        if agent.get_organization_id().is_empty() {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Agent is not associated with an organization: {}",
+                agent.get_organization_id(),
+            )));
+        }
+
+        // Validate the agent's organization issued the certificate
+        if agent.get_organization_id() != certificate.get_certifying_body_id() {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Organization {} did not issue certificate {}",
+                agent.get_organization_id(),
+                certificate.get_id()
+            )));
+        }
+
         let organization = match state.get_organization(agent.get_organization_id()) {
             Ok(Some(organization)) => Ok(organization),
             Ok(None) => Err(ApplyError::InvalidTransaction(format!(
@@ -687,81 +1187,91 @@ impl CertTransactionHandler {
             Err(err) => Err(err),
         }?;
 
-        // Validate that agent is a transactor
-        let mut is_transactor = false;
-        let authorizations = organization.get_authorizations();
-        for authorization in authorizations {
-            if authorization.get_public_key() == signer_public_key
-                && authorization.get_role()
-                    == proto::organization::Organization_Authorization_Role::TRANSACTOR
-            {
-                is_transactor = true;
-                break;
-            }
-        }
-        if !is_transactor {
+        // Validate agent is authorized
+        let block_time = state.get_block_timestamp()?;
+        if !is_authorized(
+            signer_public_key,
+            &organization,
+            proto::organization::Organization_Permission::MANAGE_CERTIFICATES,
+            block_time,
+        ) {
             return Err(ApplyError::InvalidTransaction(format!(
-                "Agent {} is not authorized to update request {}",
-                agent.get_public_key(),
-                request.get_id()
+                "Agent {} is not authorized to suspend certificate {}",
+                signer_public_key,
+                certificate.get_id()
             )));
         }
 
-        if request.get_factory_id() != agent.get_organization_id() {
-            return Err(ApplyError::InvalidTransaction(format!(
-                "Agent {} is not authorized to update request {}",
-                agent.get_organization_id(),
-                request.get_factory_id()
-            )));
+        match certificate.get_status() {
+            proto::certificate::Certificate_Status::REVOKED
+            | proto::certificate::Certificate_Status::SUSPENDED => {
+                Err(ApplyError::InvalidTransaction(format!(
+                    "Certificate {} cannot be suspended. Status: {:?}",
+                    certificate.get_id(),
+                    certificate.get_status()
+                )))
+            }
+            _ => Ok(()),
+        }?;
+
+        if payload.get_suspension_date() < certificate.get_valid_from() {
+            return Err(ApplyError::InvalidTransaction(
+                "Invalid date. Suspension cannot take effect before the certificate is valid"
+                    .to_string(),
+            ));
         }
 
-        // Validate that the request is not in a finalized state
-        let status = request.get_status();
-        if status == proto::request::Request_Status::CLOSED
-            || status == proto::request::Request_Status::CERTIFIED
-        {
-            return Err(ApplyError::InvalidTransaction(format!(
-                "Once CLOSED or CERTIFIED, the request status can not be modified again.
-                Status: {:?}",
-                status
-            )));
+        if let Some(previous) = certificate.get_suspensions().last() {
+            if payload.get_suspension_date() < previous.get_reinstated_at() {
+                return Err(ApplyError::InvalidTransaction(format!(
+                    "Invalid date. Suspension cannot take effect before the previous suspension ended at {}",
+                    previous.get_reinstated_at()
+                )));
+            }
         }
 
-        // Update request status
-        request.set_status(payload.get_status());
+        let mut suspension = proto::certificate::Certificate_Suspension::new();
+        suspension.set_reason(payload.get_reason().to_string());
+        suspension.set_suspended_at(payload.get_suspension_date());
 
-        // Put updated request in state
-        state.set_request(&payload.get_request_id(), request)?;
+        certificate.suspensions.push(suspension);
+        certificate.set_status(proto::certificate::Certificate_Status::SUSPENDED);
+
+        add_certificate_event(state, events::CERTIFICATE_STATUS_CHANGED, &certificate)?;
+
+        // Put updated certificate in state
+        state.set_certificate(payload.get_certificate_id(), certificate)?;
 
         Ok(())
     }
 
-    /// Creates a new Standard and submits it to state
+    /// Reinstates a suspended Certificate and submits it to state
     ///
     /// ```
     /// # Errors
     /// Returns an error if
-    ///   - a standard with the standard id already exist
+    ///   - the certificate does not exist
     ///   - an Agent with the signer public key does not exist
-    ///   - the Agent submitting the transaction is not associated with the organization
-    ///   - the Agent submitting the transaction is not authorized as a TRANSACTOR of the organization
-    ///   - the Organization the Agent is associated with is not a StandardsBody
-    ///   - the standard does not exist
-    ///   - it fails to submit the new Standard to state.
+    ///   - the Agent submitting the transaction is not associated with an organization
+    ///   - the Organization the Agent is associated with did not issue the certificate
+    ///   - the Agent submitting the transaction lacks the MANAGE_CERTIFICATES permission
+    ///   - the certificate is not suspended
+    ///   - the reinstatement date is before the date the suspension took effect
+    ///   - it fails to submit the updated Certificate to state.
     /// ```
-    pub fn create_standard(
+    pub fn reinstate_certificate(
         &self,
-        payload: &proto::payload::CreateStandardAction,
-        mut state: CertState,
+        payload: &proto::payload::ReinstateCertificateAction,
+        state: &mut CertState,
         signer_public_key: &str,
     ) -> Result<(), ApplyError> {
-        // Verify that name is not already associated with a Standard object
-        match state.get_standard(&payload.standard_id) {
-            Ok(Some(_)) => Err(ApplyError::InvalidTransaction(format!(
-                "Standard already exists: {}",
-                payload.name
+        // Verify that the certificate exists
+        let mut certificate = match state.get_certificate(payload.get_certificate_id()) {
+            Ok(Some(certificate)) => Ok(certificate),
+            Ok(None) => Err(ApplyError::InvalidTransaction(format!(
+                "Certificate does not exist: {}",
+                payload.get_certificate_id()
             ))),
-            Ok(None) => Ok(()),
             Err(err) => Err(err),
         }?;
 
@@ -782,7 +1292,15 @@ impl CertTransactionHandler {
             )));
         }
 
-        // Validate org existence
+        // Validate the agent's organization issued the certificate
+        if agent.get_organization_id() != certificate.get_certifying_body_id() {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Organization {} did not issue certificate {}",
+                agent.get_organization_id(),
+                certificate.get_id()
+            )));
+        }
+
         let organization = match state.get_organization(agent.get_organization_id()) {
             Ok(Some(organization)) => Ok(organization),
             Ok(None) => Err(ApplyError::InvalidTransaction(format!(
@@ -792,96 +1310,113 @@ impl CertTransactionHandler {
             Err(err) => Err(err),
         }?;
 
-        match organization.get_organization_type() {
-            proto::organization::Organization_Type::STANDARDS_BODY => Ok(()),
-            _ => Err(ApplyError::InvalidTransaction(
-                "Organization associated with agent cannot create standards".to_string(),
-            )),
-        }?;
-
         // Validate agent is authorized
-        let transactor_authorization =
-            organization
-                .get_authorizations()
-                .iter()
-                .find(|authorization| {
-                    authorization.get_public_key() == signer_public_key
-                        && authorization.get_role()
-                            == proto::organization::Organization_Authorization_Role::TRANSACTOR
-                });
-        if transactor_authorization.is_none() {
-            return Err(ApplyError::InvalidTransaction(
-                "Agent is not authorized to create a certification standard".to_string(),
-            ));
+        let block_time = state.get_block_timestamp()?;
+        if !is_authorized(
+            signer_public_key,
+            &organization,
+            proto::organization::Organization_Permission::MANAGE_CERTIFICATES,
+            block_time,
+        ) {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Agent {} is not authorized to reinstate certificate {}",
+                signer_public_key,
+                certificate.get_id()
+            )));
         }
 
-        let mut new_standard_version = proto::standard::Standard_StandardVersion::new();
-        new_standard_version.set_version(payload.version.clone());
-        new_standard_version.set_description(payload.description.clone());
-        new_standard_version.set_link(payload.link.clone());
-        new_standard_version.set_approval_date(payload.approval_date.clone());
+        if certificate.get_status() != proto::certificate::Certificate_Status::SUSPENDED {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Certificate {} is not suspended. Status: {:?}",
+                certificate.get_id(),
+                certificate.get_status()
+            )));
+        }
 
-        let mut new_standard = proto::standard::Standard::new();
-        new_standard.set_id(payload.standard_id.clone());
-        new_standard.set_name(payload.name.clone());
-        new_standard.set_organization_id(organization.id.clone());
-        new_standard.set_versions(protobuf::RepeatedField::from_vec(vec![
-            new_standard_version,
-        ]));
+        {
+            let suspension = match certificate.suspensions.last_mut() {
+                Some(suspension) => Ok(suspension),
+                None => Err(ApplyError::InvalidTransaction(format!(
+                    "Certificate {} has no suspension to end",
+                    payload.get_certificate_id()
+                ))),
+            }?;
 
-        // Put new standard in state
-        state.set_standard(&payload.standard_id, new_standard)?;
+            if payload.get_reinstatement_date() < suspension.get_suspended_at() {
+                return Err(ApplyError::InvalidTransaction(
+                    "Invalid date. Reinstatement must be after the suspension took effect"
+                        .to_string(),
+                ));
+            }
+
+            suspension.set_reinstated_at(payload.get_reinstatement_date());
+        }
+
+        certificate.set_status(proto::certificate::Certificate_Status::VALID);
+
+        add_certificate_event(state, events::CERTIFICATE_STATUS_CHANGED, &certificate)?;
+
+        // Put updated certificate in state
+        state.set_certificate(payload.get_certificate_id(), certificate)?;
 
         Ok(())
     }
 
-    /// Adds a new version of an existing Standard and submits it to state
+    /// Issues a Certificate that renews an existing Certificate and submits both to state
     ///
     /// ```
     /// # Errors
     /// Returns an error if
-    ///   - an standard with the standard id does not exist
-    ///   - the same standard version already exists for this standard
+    ///   - a certificate with the certificate id already exist
+    ///   - the certificate being renewed does not exist
     ///   - an Agent with the signer public key does not exist
     ///   - the Agent submitting the transaction is not associated with the organization
-    ///   - the Agent submitting the transaction is not authorized as a TRANSACTOR of the organization
-    ///   - the Organization the Agent is associated with is not a StandardsBody
-    ///   - the standard being updated was not created by the organization of the Agent who signed the transaction
-    ///   - it fails to submit the new Standard to state.
+    ///   - the Organization the Agent is associated with is not a CertifyingBody
+    ///   - the Organization has not been approved by a registry operator
+    ///   - the Agent submitting the transaction lacks the ISSUE_CERTIFICATE permission
+    ///   - the Organization the Agent is associated with did not issue the certificate being renewed
+    ///   - the certificate being renewed has been revoked or already renewed
+    ///   - the standard has been retired
+    ///   - the standard version does not exist or is older than the renewed certificate's version
+    ///   - the certificate would remain valid after the standard version retires
+    ///   - the certificate has already expired at the block time
+    ///   - the Organization is not accredited for the standard version at the block time and
+    ///     when the certificate becomes valid
+    ///   - the certificate data does not conform to the data schema of the standard version
+    ///   - it fails to submit the Certificates to state.
     /// ```
-    pub fn update_standard(
+    pub fn renew_certificate(
         &self,
-        payload: &proto::payload::UpdateStandardAction,
-        mut state: CertState,
+        payload: &proto::payload::RenewCertificateAction,
+        state: &mut CertState,
         signer_public_key: &str,
     ) -> Result<(), ApplyError> {
-        // Verify that name is not already associated with a Standard object
-        let mut standard = match state.get_standard(&payload.standard_id)? {
-            Some(standard) => Ok(standard),
-            None => Err(ApplyError::InvalidTransaction(format!(
-                "Standard {} does not exist",
-                payload.standard_id
+        // Verify that certificate ID is not already associated with a Certificate object
+        match state.get_certificate(payload.get_id()) {
+            Ok(Some(_)) => Err(ApplyError::InvalidTransaction(format!(
+                "Certificate already exists: {}",
+                payload.get_id()
             ))),
+            Ok(None) => Ok(()),
+            Err(err) => Err(err),
         }?;
 
-        let mut versions = standard.get_versions().to_vec();
-
-        if versions
-            .iter()
-            .find(|version| version.version == payload.version)
-            .is_some()
-        {
-            return Err(ApplyError::InvalidTransaction(format!(
-                "Version already exists. Version  {}",
-                payload.version
-            )));
-        }
+        // Verify that the certificate being renewed exists
+        let mut previous_certificate =
+            match state.get_certificate(payload.get_previous_certificate_id()) {
+                Ok(Some(certificate)) => Ok(certificate),
+                Ok(None) => Err(ApplyError::InvalidTransaction(format!(
+                    "Certificate does not exist: {}",
+                    payload.get_previous_certificate_id()
+                ))),
+                Err(err) => Err(err),
+            }?;
 
         // Validate signer public key and agent
         let agent = match state.get_agent(signer_public_key) {
             Ok(Some(agent)) => Ok(agent),
             Ok(None) => Err(ApplyError::InvalidTransaction(format!(
-                "Agent does not exist: {}",
+                "No agent exists: {}",
                 signer_public_key
             ))),
             Err(err) => Err(err),
@@ -898,153 +1433,242 @@ impl CertTransactionHandler {
         let organization = match state.get_organization(agent.get_organization_id()) {
             Ok(Some(organization)) => Ok(organization),
             Ok(None) => Err(ApplyError::InvalidTransaction(format!(
-                "Organization does not exist: {}",
+                "No organization exists: {}",
                 agent.get_organization_id()
             ))),
             Err(err) => Err(err),
         }?;
 
-        match organization.get_organization_type() {
-            proto::organization::Organization_Type::STANDARDS_BODY => Ok(()),
-            _ => Err(ApplyError::InvalidTransaction(
-                "Organization associated with agent cannot create standards".to_string(),
-            )),
-        }?;
+        if organization.get_organization_type()
+            != proto::organization::Organization_Type::CERTIFYING_BODY
+        {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Organization {} is not a certifying body",
+                agent.get_organization_id()
+            )));
+        }
+
+        if organization.get_approval_status()
+            != proto::organization::Organization_ApprovalStatus::APPROVED
+        {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Organization {} has not been approved by a registry operator",
+                agent.get_organization_id()
+            )));
+        }
 
         // Validate agent is authorized
-        let transactor_authorization =
-            organization
-                .get_authorizations()
-                .iter()
-                .find(|authorization| {
-                    authorization.get_public_key() == signer_public_key
-                        && authorization.get_role()
-                            == proto::organization::Organization_Authorization_Role::TRANSACTOR
-                });
-        if transactor_authorization.is_none() {
-            return Err(ApplyError::InvalidTransaction(
-                "Agent is not authorized to create a certification standard".to_string(),
-            ));
+        let block_time = state.get_block_timestamp()?;
+        if !is_authorized(
+            signer_public_key,
+            &organization,
+            proto::organization::Organization_Permission::ISSUE_CERTIFICATE,
+            block_time,
+        ) {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Agent is not authorized to issue certificate: {}",
+                payload.get_id()
+            )));
         }
 
-        // Validade standard was created by agent's organizatio
-        if agent.get_organization_id() != standard.get_organization_id() {
+        // Validate the renewal is issued by the same certifying body
+        if previous_certificate.get_certifying_body_id() != organization.get_id() {
             return Err(ApplyError::InvalidTransaction(format!(
-                "Organization {} did not create the certification standard {}",
-                organization.get_name(),
-                standard.get_name()
+                "Organization {} did not issue certificate {}",
+                organization.get_id(),
+                previous_certificate.get_id()
             )));
         }
 
-        let mut new_standard_version = proto::standard::Standard_StandardVersion::new();
-        new_standard_version.set_version(payload.version.clone());
-        new_standard_version.set_description(payload.description.clone());
-        new_standard_version.set_link(payload.link.clone());
-        new_standard_version.set_approval_date(payload.approval_date.clone());
+        if previous_certificate.get_status() == proto::certificate::Certificate_Status::REVOKED {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Certificate {} has been revoked and cannot be renewed",
+                previous_certificate.get_id()
+            )));
+        }
 
-        versions.push(new_standard_version);
+        if !previous_certificate.get_next_certificate_id().is_empty() {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Certificate {} has already been renewed by certificate {}",
+                previous_certificate.get_id(),
+                previous_certificate.get_next_certificate_id()
+            )));
+        }
 
-        standard.set_versions(protobuf::RepeatedField::from_vec(versions));
+        // Validate current issue date
+        let valid_from = payload.get_valid_from();
+        let valid_to = payload.get_valid_to();
+        if valid_to < valid_from {
+            return Err(ApplyError::InvalidTransaction(
+                "Invalid dates. Valid to must be after valid from".to_string(),
+            ));
+        }
 
-        // Put updated standard in state
-        state.set_standard(&standard.id.clone(), standard)?;
+        if valid_to < block_time {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Invalid dates. Valid to {} is before the block time {}",
+                valid_to, block_time
+            )));
+        }
+
+        // Validate the standard version is not older than the renewed certificate's version
+        let standard = match state.get_standard(previous_certificate.get_standard_id()) {
+            Ok(Some(standard)) => Ok(standard),
+            Ok(None) => Err(ApplyError::InvalidTransaction(format!(
+                "No standard with ID {} exists",
+                previous_certificate.get_standard_id()
+            ))),
+            Err(err) => Err(err),
+        }?;
+        validate_standard_active(&standard)?;
+
+        let versions = standard.get_versions();
+        let new_version_index = match versions
+            .iter()
+            .position(|version| version.get_version() == payload.get_standard_version())
+        {
+            Some(index) => Ok(index),
+            None => Err(ApplyError::InvalidTransaction(format!(
+                "Version {} does not exist for Standard {}",
+                payload.get_standard_version(),
+                standard.get_id()
+            ))),
+        }?;
+        let previous_version_index = versions.iter().position(|version| {
+            version.get_version() == previous_certificate.get_standard_version()
+        });
+        if let Some(previous_version_index) = previous_version_index {
+            if new_version_index < previous_version_index {
+                return Err(ApplyError::InvalidTransaction(format!(
+                    "Version {} is older than version {} of the certificate being renewed",
+                    payload.get_standard_version(),
+                    previous_certificate.get_standard_version()
+                )));
+            }
+        }
+        validate_expires_by_retirement(standard.get_id(), &versions[new_version_index], valid_to)?;
+
+        // Validate the certifying body holds an accreditation for the standard version that
+        // is current when the certificate becomes valid and at the block time
+        if organization
+            .get_certifying_body_details()
+            .get_accreditations()
+            .iter()
+            .find(|accreditation| {
+                accreditation.get_standard_id() == standard.get_id()
+                    && accreditation.get_standard_version() == payload.get_standard_version()
+                    && covers_issuance(accreditation, valid_from, block_time)
+            })
+            .is_none()
+        {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Certifying body is not accredited for Standard {}, version {}",
+                standard.get_id(),
+                payload.get_standard_version()
+            )));
+        }
+
+        validate_certificate_data(&versions[new_version_index], payload.get_certificate_data())?;
+
+        // Create successor certificate
+        let mut new_certificate = proto::certificate::Certificate::new();
+        new_certificate.set_id(payload.get_id().to_string());
+        new_certificate.set_certifying_body_id(organization.get_id().to_string());
+        new_certificate.set_factory_id(previous_certificate.get_factory_id().to_string());
+        new_certificate.set_standard_id(previous_certificate.get_standard_id().to_string());
+        new_certificate.set_standard_version(payload.get_standard_version().to_string());
+        new_certificate.set_certificate_data(::protobuf::RepeatedField::from_vec(
+            payload.get_certificate_data().to_vec(),
+        ));
+        new_certificate.set_valid_from(valid_from);
+        new_certificate.set_valid_to(valid_to);
+        new_certificate.set_status(proto::certificate::Certificate_Status::VALID);
+        new_certificate.set_previous_certificate_id(previous_certificate.get_id().to_string());
+
+        // Link the renewed certificate to its successor
+        previous_certificate.set_next_certificate_id(payload.get_id().to_string());
+
+        add_certificate_event(state, events::CERTIFICATE_ISSUED, &new_certificate)?;
+
+        // Put certificates in state
+        state.set_certificate(payload.get_id(), new_certificate)?;
+        state.set_certificate(payload.get_previous_certificate_id(), previous_certificate)?;
 
         Ok(())
     }
 
-    /// Adds a new accreditation to an existing CertifyingBody organization and submits it to state
+    /// Creates a new Request and submits it to state
     ///
     /// ```
     /// # Errors
     /// Returns an error if
+    ///   - a request with the request id already exist
     ///   - an Agent with the signer public key does not exist
     ///   - the Agent submitting the transaction is not associated with the organization
-    ///   - the Agent submitting the transaction is not authorized as a TRANSACTOR of the organization
-    ///   - the Organization the Agent is associated with is not a StandardsBody
-    ///   - the certifying body id does provided in the payload does not identify an existing CertifyingBody organization
-    ///   - the standard provided in the payload does not exist
-    ///   - the standard was not created by the organization of the Agent who signed the transaction
-    ///   - the CertifyingBody is already accredited for the latest version of the standard
-    ///   - it fails to submit the new Standard to state.
+    ///   - the Agent submitting the transaction lacks the MANAGE_REQUESTS permission
+    ///   - the Organization the Agent is associated with is not a Factory
+    ///   - the standard does not exist or has been retired
+    ///   - a certifying body is given that does not exist, is not a Certifying Body or has not
+    ///     been approved by a registry operator
+    ///   - the request date drifts too far from the block time
+    ///   - the latest version of the standard has been retired at the request date
+    ///   - it fails to submit the new Request to state.
     /// ```
-    pub fn accredit_certifying_body(
+    pub fn open_request(
         &self,
-        payload: &proto::payload::AccreditCertifyingBodyAction,
-        mut state: CertState,
+        payload: &proto::payload::OpenRequestAction,
+        state: &mut CertState,
         signer_public_key: &str,
     ) -> Result<(), ApplyError> {
-        // Verify the signer
+        // Validate that the signer associated with a factory
         let agent = match state.get_agent(signer_public_key) {
             Ok(Some(agent)) => Ok(agent),
             Ok(None) => Err(ApplyError::InvalidTransaction(format!(
-                "Agent does not exist: {}",
+                "No agent exists: {}",
                 signer_public_key
             ))),
             Err(err) => Err(err),
         }?;
-
-        // Verify the signer is associated with a Standards Body
-        if agent.get_organization_id().is_empty() {
-            return Err(ApplyError::InvalidTransaction(format!(
-                "Agent is not associated with an organization: {}",
-                agent.get_organization_id(),
-            )));
-        }
-
-        let agent_organization = match state.get_organization(agent.get_organization_id()) {
-            Ok(Some(agent_organization)) => Ok(agent_organization),
+        let organization = match state.get_organization(agent.get_organization_id()) {
+            Ok(Some(organization)) => Ok(organization),
             Ok(None) => Err(ApplyError::InvalidTransaction(format!(
                 "No organization exists: {}",
                 agent.get_organization_id()
             ))),
             Err(err) => Err(err),
         }?;
-
-        match agent_organization.get_organization_type() {
-            proto::organization::Organization_Type::STANDARDS_BODY => Ok(()),
-            _ => Err(ApplyError::InvalidTransaction(
-                "Organization associated with agent cannot accredit Certifying Bodies".to_string(),
-            )),
-        }?;
-
-        // Verify the signer is an authorized transactor within their organization
-        let mut is_transactor = false;
-        let authorizations = agent_organization.get_authorizations();
-        for authorization in authorizations {
-            if authorization.get_public_key() == signer_public_key
-                && authorization.get_role()
-                    == proto::organization::Organization_Authorization_Role::TRANSACTOR
-            {
-                is_transactor = true;
-                break;
-            }
+        if organization.get_organization_type() != proto::organization::Organization_Type::FACTORY {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Organization {} is not a factory",
+                agent.get_organization_id()
+            )));
         }
-        if !is_transactor {
+
+        // Validate agent is authorized
+        let block_time = state.get_block_timestamp()?;
+        if !is_authorized(
+            signer_public_key,
+            &organization,
+            proto::organization::Organization_Permission::MANAGE_REQUESTS,
+            block_time,
+        ) {
             return Err(ApplyError::InvalidTransaction(format!(
-                "Agent {} is not authorized to accredit certifying body: {}",
-                signer_public_key,
-                payload.get_certifying_body_id(),
+                "Agent {} is not authorized to open a request",
+                agent.get_public_key(),
             )));
         }
 
-        // Verify the certifying_body_id is associated with a Certifying body
-        let mut certifying_body = match state.get_organization(payload.get_certifying_body_id()) {
-            Ok(Some(certifying_body)) => Ok(certifying_body),
-            Ok(None) => Err(ApplyError::InvalidTransaction(format!(
-                "No organization exists: {}",
-                payload.get_certifying_body_id(),
+        // Verify that the request does not already exist
+        match state.get_request(&payload.get_id()) {
+            Ok(Some(_)) => Err(ApplyError::InvalidTransaction(format!(
+                "Request already exists: {}",
+                payload.get_id()
             ))),
+            Ok(None) => Ok(()),
             Err(err) => Err(err),
         }?;
 
-        match certifying_body.get_organization_type() {
-            proto::organization::Organization_Type::CERTIFYING_BODY => Ok(()),
-            _ => Err(ApplyError::InvalidTransaction(
-                "Only Certifying Bodies may be accredited".to_string(),
-            )),
-        }?;
-
-        // Verify the name is associated with an existing standard
+        // Validate that the standard_id and version are associated with a valid standard
         let standard = match state.get_standard(&payload.get_standard_id()) {
             Ok(Some(standard)) => Ok(standard),
             Ok(None) => Err(ApplyError::InvalidTransaction(format!(
@@ -1053,165 +1677,8949 @@ impl CertTransactionHandler {
             ))),
             Err(err) => Err(err),
         }?;
+        validate_standard_active(&standard)?;
 
-        // Verify the agent's organization created the standard
-        if agent.get_organization_id() != standard.get_organization_id() {
-            return Err(ApplyError::InvalidTransaction(format!(
-                "Signer's associated organization did not create the certification standard {}",
-                standard.get_name()
-            )));
+        // Validate that the request is addressed to a certifying body. Payloads decoded from
+        // family version 0.1 do not address the request to a certifying body.
+        if !payload.get_certifying_body_id().is_empty() {
+            match state.get_organization(payload.get_certifying_body_id()) {
+                Ok(Some(certifying_body)) => {
+                    if certifying_body.get_organization_type()
+                        != proto::organization::Organization_Type::CERTIFYING_BODY
+                    {
+                        return Err(ApplyError::InvalidTransaction(format!(
+                            "Organization {} is not a certifying body",
+                            payload.get_certifying_body_id()
+                        )));
+                    }
+                    if certifying_body.get_approval_status()
+                        != proto::organization::Organization_ApprovalStatus::APPROVED
+                    {
+                        return Err(ApplyError::InvalidTransaction(format!(
+                            "Organization {} has not been approved by a registry operator",
+                            payload.get_certifying_body_id()
+                        )));
+                    }
+                    Ok(())
+                }
+                Ok(None) => Err(ApplyError::InvalidTransaction(format!(
+                    "No organization exists: {}",
+                    payload.get_certifying_body_id()
+                ))),
+                Err(err) => Err(err),
+            }?;
         }
 
-        let mut certifying_body_details = certifying_body.get_certifying_body_details().clone();
-
-        let mut accreditations = certifying_body_details.get_accreditations().to_vec();
-
-        let standard_versions = standard.get_versions().to_vec();
-        let latest_standard_version = match standard_versions.last() {
-            Some(valid_version) => valid_version,
-            None => {
-                return Err(ApplyError::InvalidTransaction(format!(
-                    "Invalid version for Standard {}",
-                    standard.get_id()
-                )));
-            }
-        };
+        self.validate_timestamp(state, payload.get_request_date(), "Request date")?;
 
-        if accreditations
-            .iter()
-            .find(|accreditation| {
-                accreditation.get_standard_id() == payload.get_standard_id()
-                    && accreditation.get_standard_version()
-                        == latest_standard_version.get_version().to_string()
-            })
-            .is_some()
-        {
+        // Versions are appended, so the standard is current while its latest version is
+        if standard.get_versions().last().map_or(false, |version| {
+            is_version_retired_at(version, payload.get_request_date())
+        }) {
             return Err(ApplyError::InvalidTransaction(format!(
-                "Accreditation for Standard {}, version {} already exists",
-                payload.get_standard_id(),
-                latest_standard_version.get_version().to_string(),
+                "Every version of Standard {} has been retired",
+                payload.get_standard_id()
             )));
         }
 
-        // Verify the date
-        let valid_from = payload.get_valid_from();
-        if valid_from < latest_standard_version.get_approval_date() {
-            return Err(ApplyError::InvalidTransaction(
-                "Invalid date, Standard is not valid from this date".to_string(),
-            ));
-        }
-
-        let valid_to = payload.get_valid_to();
-        if valid_to < valid_from {
-            return Err(ApplyError::InvalidTransaction(
-                "Invalid dates. Valid to must be after valid from".to_string(),
-            ));
-        }
-
-        let mut new_accreditation = proto::organization::CertifyingBody_Accreditation::new();
-        new_accreditation.set_standard_id(payload.get_standard_id().to_string());
-        new_accreditation.set_standard_version(latest_standard_version.get_version().to_string());
-        new_accreditation.set_accreditor_id(agent_organization.get_id().to_string());
-        new_accreditation.set_valid_to(payload.get_valid_to());
-        new_accreditation.set_valid_from(payload.get_valid_from());
-
-        accreditations.push(new_accreditation);
-        certifying_body_details
-            .set_accreditations(protobuf::RepeatedField::from_vec(accreditations));
+        // Create and open new certification request
+        let mut request = proto::request::Request::new();
+        request.set_id(payload.get_id().to_string());
+        request.set_status(proto::request::Request_Status::OPEN);
+        request.set_standard_id(payload.get_standard_id().to_string());
+        request.set_factory_id(agent.get_organization_id().to_string());
+        request.set_request_date(payload.get_request_date());
+        request.set_certifying_body_id(payload.get_certifying_body_id().to_string());
+        request.set_documents(::protobuf::RepeatedField::from_vec(
+            payload.get_documents().to_vec(),
+        ));
 
-        certifying_body.set_certifying_body_details(certifying_body_details);
+        add_request_status_changed_event(state, &request)?;
 
-        // Put updated CertifyingBody in state
-        state.set_organization(payload.get_certifying_body_id(), certifying_body)?;
+        // Put new request in state
+        state.set_request(&payload.get_id(), request)?;
 
         Ok(())
     }
-}
-
-impl TransactionHandler for CertTransactionHandler {
-    fn family_name(&self) -> String {
-        self.family_name.clone()
-    }
-
-    fn family_versions(&self) -> Vec<String> {
-        self.family_versions.clone()
-    }
-
-    fn namespaces(&self) -> Vec<String> {
-        self.namespaces.clone()
-    }
 
-    /// Applies the correct transaction logic depending on the payload action type.
-    /// It will use helper methods to perform all payload validation that requires
-    /// fetching data from state. If the payload is valid it will apply the changes
-    /// to state.
+    /// Updates an existing Request status and submits it to state
     ///
     /// ```
     /// # Errors
-    /// Returns an error if the transaction fails
+    /// Returns an error if
+    ///   - a request with the request id already exist
+    ///   - an Agent with the signer public key does not exist
+    ///   - the Agent submitting the transaction is not associated with the organization
+    ///   - the Agent submitting the transaction lacks the MANAGE_REQUESTS permission
+    ///   - the Organization the Agent is associated with is not a Factory
+    ///   - the new request status is not IN_PROGRESS or CLOSED.
+    ///   - the current request status is CLOSED, CERTIFIED or DECLINED.
+    ///   - the new request status is IN_PROGRESS and the current request status is not
+    ///     ACCEPTED.
+    ///   - it fails to submit the updated Request to state.
     /// ```
-    fn apply(
+    pub fn change_request_status(
         &self,
-        request: &TpProcessRequest,
-        context: &mut TransactionContext,
+        payload: &proto::payload::ChangeRequestStatusAction,
+        state: &mut CertState,
+        signer_public_key: &str,
     ) -> Result<(), ApplyError> {
-        let header = request.get_header();
-        let signer_public_key = header.get_signer_public_key();
-
-        // Return an action enum as the payload
-        let payload = CertPayload::new(request.get_payload())?;
-        let state = CertState::new(context);
+        // Verify that the request does exist
+        let mut request = match state.get_request(&payload.request_id) {
+            Ok(Some(request)) => Ok(request),
+            Ok(None) => Err(ApplyError::InvalidTransaction(format!(
+                "Request does not exists: {}",
+                payload.request_id
+            ))),
+            Err(err) => Err(err),
+        }?;
 
-        match payload.get_action() {
-            Action::CreateAgent(payload) => self.create_agent(&payload, state, signer_public_key),
+        // Validate that the signer associated with a factory
+        let agent = match state.get_agent(signer_public_key) {
+            Ok(Some(agent)) => Ok(agent),
+            Ok(None) => Err(ApplyError::InvalidTransaction(format!(
+                "No agent exists: {}",
+                signer_public_key
+            ))),
+            Err(err) => Err(err),
+        }?;
+        let organization = match state.get_organization(agent.get_organization_id()) {
+            Ok(Some(organization)) => Ok(organization),
+            Ok(None) => Err(ApplyError::InvalidTransaction(format!(
+                "No organization exists: {}",
+                agent.get_organization_id()
+            ))),
+            Err(err) => Err(err),
+        }?;
 
-            Action::CreateOrganization(payload) => {
-                self.create_organization(&payload, state, signer_public_key)
-            }
+        // Validate agent is authorized
+        let block_time = state.get_block_timestamp()?;
+        if !is_authorized(
+            signer_public_key,
+            &organization,
+            proto::organization::Organization_Permission::MANAGE_REQUESTS,
+            block_time,
+        ) {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Agent {} is not authorized to update request {}",
+                agent.get_public_key(),
+                request.get_id()
+            )));
+        }
 
-            Action::UpdateOrganization(payload) => {
-                self.update_organization(&payload, state, signer_public_key)
-            }
+        if request.get_factory_id() != agent.get_organization_id() {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Agent {} is not authorized to update request {}",
+                agent.get_organization_id(),
+                request.get_factory_id()
+            )));
+        }
 
-            Action::AuthorizeAgent(payload) => {
-                self.authorize_agent(&payload, state, signer_public_key)
-            }
+        // Validate that the request is not in a finalized state
+        let status = request.get_status();
+        if status == proto::request::Request_Status::CLOSED
+            || status == proto::request::Request_Status::CERTIFIED
+            || status == proto::request::Request_Status::DECLINED
+        {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Once CLOSED, CERTIFIED or DECLINED, the request status can not be modified again.
+                Status: {:?}",
+                status
+            )));
+        }
 
-            Action::IssueCertificate(payload) => {
-                self.issue_certificate(&payload, state, signer_public_key)
-            }
-            Action::CreateStandard(payload) => {
-                self.create_standard(&payload, state, signer_public_key)
-            }
-            Action::UpdateStandard(payload) => {
-                self.update_standard(&payload, state, signer_public_key)
-            }
-            Action::OpenRequest(payload) => self.open_request(&payload, state, signer_public_key),
-            Action::ChangeRequestStatus(payload) => {
-                self.change_request_status(&payload, state, signer_public_key)
+        // Work on a request may only start once its certifying body has accepted it
+        if payload.get_status() == proto::request::Request_Status::IN_PROGRESS
+            && status != proto::request::Request_Status::ACCEPTED
+        {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Only an ACCEPTED request may be set to IN_PROGRESS. Status: {:?}",
+                status
+            )));
+        }
+
+        // Update request status
+        request.set_status(payload.get_status());
+
+        add_request_status_changed_event(state, &request)?;
+
+        // Put updated request in state
+        state.set_request(&payload.get_request_id(), request)?;
+
+        Ok(())
+    }
+
+    /// Accepts a Request addressed to the signer's Certifying Body and submits it to state
+    ///
+    /// ```
+    /// # Errors
+    /// Returns an error if
+    ///   - the request does not exist
+    ///   - an Agent with the signer public key does not exist
+    ///   - the Agent submitting the transaction is not associated with the certifying body
+    ///     the request is addressed to, or with any certifying body if the request was opened
+    ///     under family version 0.1
+    ///   - the Agent submitting the transaction lacks the MANAGE_REQUESTS permission
+    ///   - the current request status is not OPEN.
+    ///   - it fails to submit the updated Request to state.
+    /// ```
+    pub fn accept_request(
+        &self,
+        payload: &proto::payload::AcceptRequestAction,
+        state: &mut CertState,
+        signer_public_key: &str,
+    ) -> Result<(), ApplyError> {
+        let mut request = self.get_request_for_certifying_body(
+            payload.get_request_id(),
+            state,
+            signer_public_key,
+            proto::organization::Organization_Permission::MANAGE_REQUESTS,
+        )?;
+
+        if request.get_status() != proto::request::Request_Status::OPEN {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Only OPEN requests can be accepted. Status: {:?}",
+                request.get_status()
+            )));
+        }
+
+        request.set_status(proto::request::Request_Status::ACCEPTED);
+
+        add_request_status_changed_event(state, &request)?;
+
+        // Put updated request in state
+        state.set_request(payload.get_request_id(), request)?;
+
+        Ok(())
+    }
+
+    /// Declines a Request addressed to the signer's Certifying Body and submits it to state
+    ///
+    /// ```
+    /// # Errors
+    /// Returns an error if
+    ///   - the request does not exist
+    ///   - an Agent with the signer public key does not exist
+    ///   - the Agent submitting the transaction is not associated with the certifying body
+    ///     the request is addressed to, or with any certifying body if the request was opened
+    ///     under family version 0.1
+    ///   - the Agent submitting the transaction lacks the MANAGE_REQUESTS permission
+    ///   - the current request status is CLOSED, CERTIFIED or DECLINED.
+    ///   - it fails to submit the updated Request to state.
+    /// ```
+    pub fn decline_request(
+        &self,
+        payload: &proto::payload::DeclineRequestAction,
+        state: &mut CertState,
+        signer_public_key: &str,
+    ) -> Result<(), ApplyError> {
+        let mut request = self.get_request_for_certifying_body(
+            payload.get_request_id(),
+            state,
+            signer_public_key,
+            proto::organization::Organization_Permission::MANAGE_REQUESTS,
+        )?;
+
+        // Validate that the request is not in a finalized state
+        let status = request.get_status();
+        if status == proto::request::Request_Status::CLOSED
+            || status == proto::request::Request_Status::CERTIFIED
+            || status == proto::request::Request_Status::DECLINED
+        {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Once CLOSED, CERTIFIED or DECLINED, the request status can not be modified again.
+                Status: {:?}",
+                status
+            )));
+        }
+
+        request.set_status(proto::request::Request_Status::DECLINED);
+        request.set_decline_reason(payload.get_reason().to_string());
+
+        add_request_status_changed_event(state, &request)?;
+
+        // Put updated request in state
+        state.set_request(payload.get_request_id(), request)?;
+
+        Ok(())
+    }
+
+    /// Records an Audit performed against a Request and submits it to state
+    ///
+    /// ```
+    /// # Errors
+    /// Returns an error if
+    ///   - an audit with the audit id already exist
+    ///   - the request does not exist
+    ///   - an Agent with the signer public key does not exist
+    ///   - the Agent submitting the transaction is not associated with the certifying body
+    ///     the request is addressed to, or with any certifying body if the request was opened
+    ///     under family version 0.1
+    ///   - the Agent submitting the transaction lacks the MANAGE_AUDITS permission
+    ///   - the request does not have status set to ACCEPTED or IN_PROGRESS
+    ///   - the audit date drifts too far from the block time
+    ///   - it fails to submit the new Audit to state.
+    /// ```
+    pub fn record_audit(
+        &self,
+        payload: &proto::payload::RecordAuditAction,
+        state: &mut CertState,
+        signer_public_key: &str,
+    ) -> Result<(), ApplyError> {
+        // Verify that audit ID is not already associated with an Audit object
+        match state.get_audit(payload.get_id()) {
+            Ok(Some(_)) => Err(ApplyError::InvalidTransaction(format!(
+                "Audit already exists: {}",
+                payload.get_id()
+            ))),
+            Ok(None) => Ok(()),
+            Err(err) => Err(err),
+        }?;
+
+        let request = self.get_request_for_certifying_body(
+            payload.get_request_id(),
+            state,
+            signer_public_key,
+            proto::organization::Organization_Permission::MANAGE_AUDITS,
+        )?;
+
+        if request.get_status() != proto::request::Request_Status::ACCEPTED
+            && request.get_status() != proto::request::Request_Status::IN_PROGRESS
+        {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "The request with id {} has its status set to {:?}. Only requests with status set to ACCEPTED or IN_PROGRESS can be audited.",
+                request.get_id(),
+                request.get_status()
+            )));
+        }
+
+        self.validate_timestamp(state, payload.get_audit_date(), "Audit date")?;
+
+        // Create audit
+        let mut new_audit = proto::audit::Audit::new();
+        new_audit.set_id(payload.get_id().to_string());
+        new_audit.set_request_id(request.get_id().to_string());
+        new_audit.set_factory_id(request.get_factory_id().to_string());
+        new_audit.set_certifying_body_id(request.get_certifying_body_id().to_string());
+        new_audit.set_auditor_public_key(signer_public_key.to_string());
+        new_audit.set_audit_date(payload.get_audit_date());
+        new_audit.set_outcome(payload.get_outcome());
+        new_audit.set_findings(::protobuf::RepeatedField::from_vec(
+            payload.get_findings().to_vec(),
+        ));
+
+        // Put audit in state
+        state.set_audit(payload.get_id(), new_audit)?;
+
+        Ok(())
+    }
+
+    /// Fetches a Request and validates that the signer holds the permission within the
+    /// Certifying Body the request is addressed to. Requests opened under family version 0.1
+    /// are not addressed to a certifying body. Any Certifying Body may act on them, and the
+    /// request is addressed to the first one that does.
+    fn get_request_for_certifying_body(
+        &self,
+        request_id: &str,
+        state: &mut CertState,
+        signer_public_key: &str,
+        permission: proto::organization::Organization_Permission,
+    ) -> Result<proto::request::Request, ApplyError> {
+        // Verify that the request does exist
+        let mut request = match state.get_request(request_id) {
+            Ok(Some(request)) => Ok(request),
+            Ok(None) => Err(ApplyError::InvalidTransaction(format!(
+                "Request does not exists: {}",
+                request_id
+            ))),
+            Err(err) => Err(err),
+        }?;
+        let unaddressed = request.get_certifying_body_id().is_empty();
+
+        // Validate that the signer is associated with the certifying body
+        let agent = match state.get_agent(signer_public_key) {
+            Ok(Some(agent)) => Ok(agent),
+            Ok(None) => Err(ApplyError::InvalidTransaction(format!(
+                "No agent exists: {}",
+                signer_public_key
+            ))),
+            Err(err) => Err(err),
+        }?;
+
+        if agent.get_organization_id().is_empty()
+            || (!unaddressed && agent.get_organization_id() != request.get_certifying_body_id())
+        {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Request {} is not addressed to the organization of agent {}",
+                request_id, signer_public_key
+            )));
+        }
+
+        let organization = match state.get_organization(agent.get_organization_id()) {
+            Ok(Some(organization)) => Ok(organization),
+            Ok(None) => Err(ApplyError::InvalidTransaction(format!(
+                "No organization exists: {}",
+                agent.get_organization_id()
+            ))),
+            Err(err) => Err(err),
+        }?;
+
+        if unaddressed
+            && organization.get_organization_type()
+                != proto::organization::Organization_Type::CERTIFYING_BODY
+        {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Organization {} is not a certifying body",
+                agent.get_organization_id()
+            )));
+        }
+
+        // Validate agent is authorized
+        let block_time = state.get_block_timestamp()?;
+        if !is_authorized(signer_public_key, &organization, permission, block_time) {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Agent {} is not authorized to update request {}",
+                signer_public_key, request_id
+            )));
+        }
+
+        if unaddressed {
+            request.set_certifying_body_id(agent.get_organization_id().to_string());
+            state.set_request(request_id, request.clone())?;
+        }
+
+        Ok(request)
+    }
+
+    /// Raises a NonConformance against a Factory and submits it to state
+    ///
+    /// ```
+    /// # Errors
+    /// Returns an error if
+    ///   - a non-conformance with the non-conformance id already exist
+    ///   - an Agent with the signer public key does not exist
+    ///   - the Agent submitting the transaction is not associated with an organization
+    ///   - the Agent submitting the transaction lacks the MANAGE_AUDITS permission
+    ///   - the Organization the Agent is associated with is not a CertifyingBody
+    ///   - the factory does not exist
+    ///   - an audit is referenced that does not exist or was not performed by the Agent's
+    ///     CertifyingBody on the factory
+    ///   - the raised at date drifts too far from the block time
+    ///   - it fails to submit the new NonConformance to state.
+    /// ```
+    pub fn raise_non_conformance(
+        &self,
+        payload: &proto::payload::RaiseNonConformanceAction,
+        state: &mut CertState,
+        signer_public_key: &str,
+    ) -> Result<(), ApplyError> {
+        // Verify that non-conformance ID is not already associated with a NonConformance object
+        match state.get_non_conformance(payload.get_id()) {
+            Ok(Some(_)) => Err(ApplyError::InvalidTransaction(format!(
+                "Non-conformance already exists: {}",
+                payload.get_id()
+            ))),
+            Ok(None) => Ok(()),
+            Err(err) => Err(err),
+        }?;
+
+        let organization = self.get_authorized_organization(
+            state,
+            signer_public_key,
+            proto::organization::Organization_Permission::MANAGE_AUDITS,
+        )?;
+
+        if organization.get_organization_type()
+            != proto::organization::Organization_Type::CERTIFYING_BODY
+        {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Organization {} is not a certifying body",
+                organization.get_id()
+            )));
+        }
+
+        // Validate the factory
+        match state.get_organization(payload.get_factory_id()) {
+            Ok(Some(ref factory))
+                if factory.get_organization_type()
+                    == proto::organization::Organization_Type::FACTORY =>
+            {
+                Ok(())
             }
-            Action::AccreditCertifyingBody(payload) => {
-                self.accredit_certifying_body(&payload, state, signer_public_key)
+            Ok(_) => Err(ApplyError::InvalidTransaction(format!(
+                "Factory does not exist: {}",
+                payload.get_factory_id()
+            ))),
+            Err(err) => Err(err),
+        }?;
+
+        if !payload.get_audit_id().is_empty() {
+            let audit = match state.get_audit(payload.get_audit_id()) {
+                Ok(Some(audit)) => Ok(audit),
+                Ok(None) => Err(ApplyError::InvalidTransaction(format!(
+                    "Audit does not exist: {}",
+                    payload.get_audit_id()
+                ))),
+                Err(err) => Err(err),
+            }?;
+
+            if audit.get_certifying_body_id() != organization.get_id()
+                || audit.get_factory_id() != payload.get_factory_id()
+            {
+                return Err(ApplyError::InvalidTransaction(format!(
+                    "Audit {} was not performed by certifying body {} on factory {}",
+                    audit.get_id(),
+                    organization.get_id(),
+                    payload.get_factory_id()
+                )));
             }
         }
-    }
-}
 
-#[cfg(target_arch = "wasm32")]
+        self.validate_timestamp(state, payload.get_raised_at(), "Raised at date")?;
 
-// If the TP will be compiled to WASM to be run as a smart contract in Sabre this apply method will be
-// used as wrapper for the handler apply method. For Sabre the apply must return a boolean
-fn apply(request: &TpProcessRequest, context: &mut TransactionContext) -> Result<bool, ApplyError> {
-    let handler = CertTransactionHandler::new();
-    match handler.apply(request, context) {
-        Ok(_) => Ok(true),
-        Err(err) => Err(err),
+        // Create non-conformance
+        let mut non_conformance = proto::non_conformance::NonConformance::new();
+        non_conformance.set_id(payload.get_id().to_string());
+        non_conformance.set_factory_id(payload.get_factory_id().to_string());
+        non_conformance.set_certifying_body_id(organization.get_id().to_string());
+        non_conformance.set_audit_id(payload.get_audit_id().to_string());
+        non_conformance.set_description(payload.get_description().to_string());
+        non_conformance.set_severity(payload.get_severity());
+        non_conformance.set_status(proto::non_conformance::NonConformance_Status::OPEN);
+        non_conformance.set_raised_at(payload.get_raised_at());
+
+        // Put non-conformance in state
+        state.set_non_conformance(payload.get_id(), non_conformance)?;
+
+        Ok(())
     }
-}
 
-#[allow(dead_code, private_no_mangle_fns)]
-#[cfg(target_arch = "wasm32")]
-#[no_mangle]
-pub unsafe fn entrypoint(payload: WasmPtr, signer: WasmPtr, signature: WasmPtr) -> i32 {
-    execute_entrypoint(payload, signer, signature, apply)
+    /// Submits a Factory's corrective action plan for a NonConformance to state
+    ///
+    /// ```
+    /// # Errors
+    /// Returns an error if
+    ///   - the non-conformance does not exist
+    ///   - an Agent with the signer public key does not exist
+    ///   - the Agent submitting the transaction is not associated with an organization
+    ///   - the Agent submitting the transaction lacks the MANAGE_AUDITS permission
+    ///   - the non-conformance was not raised against the Agent's organization
+    ///   - the non-conformance is CLOSED
+    ///   - the submitted at date drifts too far from the block time
+    ///   - it fails to submit the updated NonConformance to state.
+    /// ```
+    pub fn submit_corrective_action(
+        &self,
+        payload: &proto::payload::SubmitCorrectiveActionAction,
+        state: &mut CertState,
+        signer_public_key: &str,
+    ) -> Result<(), ApplyError> {
+        let mut non_conformance = match state.get_non_conformance(payload.get_non_conformance_id())
+        {
+            Ok(Some(non_conformance)) => Ok(non_conformance),
+            Ok(None) => Err(ApplyError::InvalidTransaction(format!(
+                "Non-conformance does not exist: {}",
+                payload.get_non_conformance_id()
+            ))),
+            Err(err) => Err(err),
+        }?;
+
+        let organization = self.get_authorized_organization(
+            state,
+            signer_public_key,
+            proto::organization::Organization_Permission::MANAGE_AUDITS,
+        )?;
+
+        if non_conformance.get_factory_id() != organization.get_id() {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Non-conformance {} was not raised against organization {}",
+                non_conformance.get_id(),
+                organization.get_id()
+            )));
+        }
+
+        if non_conformance.get_status() == proto::non_conformance::NonConformance_Status::CLOSED {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Non-conformance {} is already CLOSED",
+                non_conformance.get_id()
+            )));
+        }
+
+        self.validate_timestamp(state, payload.get_submitted_at(), "Submitted at date")?;
+
+        non_conformance
+            .set_corrective_action_plan(payload.get_corrective_action_plan().to_string());
+        non_conformance.set_corrective_action_submitted_at(payload.get_submitted_at());
+        non_conformance
+            .set_status(proto::non_conformance::NonConformance_Status::CORRECTIVE_ACTION_SUBMITTED);
+
+        // Put updated non-conformance in state
+        state.set_non_conformance(payload.get_non_conformance_id(), non_conformance)?;
+
+        Ok(())
+    }
+
+    /// Closes a NonConformance once its corrective action plan is accepted and submits it to state
+    ///
+    /// ```
+    /// # Errors
+    /// Returns an error if
+    ///   - the non-conformance does not exist
+    ///   - an Agent with the signer public key does not exist
+    ///   - the Agent submitting the transaction is not associated with an organization
+    ///   - the Agent submitting the transaction lacks the MANAGE_AUDITS permission
+    ///   - the non-conformance was not raised by the Agent's organization
+    ///   - the non-conformance status is not CORRECTIVE_ACTION_SUBMITTED
+    ///   - the closed at date drifts too far from the block time
+    ///   - it fails to submit the updated NonConformance to state.
+    /// ```
+    pub fn close_non_conformance(
+        &self,
+        payload: &proto::payload::CloseNonConformanceAction,
+        state: &mut CertState,
+        signer_public_key: &str,
+    ) -> Result<(), ApplyError> {
+        let mut non_conformance = match state.get_non_conformance(payload.get_non_conformance_id())
+        {
+            Ok(Some(non_conformance)) => Ok(non_conformance),
+            Ok(None) => Err(ApplyError::InvalidTransaction(format!(
+                "Non-conformance does not exist: {}",
+                payload.get_non_conformance_id()
+            ))),
+            Err(err) => Err(err),
+        }?;
+
+        let organization = self.get_authorized_organization(
+            state,
+            signer_public_key,
+            proto::organization::Organization_Permission::MANAGE_AUDITS,
+        )?;
+
+        if non_conformance.get_certifying_body_id() != organization.get_id() {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Non-conformance {} was not raised by organization {}",
+                non_conformance.get_id(),
+                organization.get_id()
+            )));
+        }
+
+        if non_conformance.get_status()
+            != proto::non_conformance::NonConformance_Status::CORRECTIVE_ACTION_SUBMITTED
+        {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Non-conformance {} has its status set to {:?}. Only non-conformances with a submitted corrective action can be closed.",
+                non_conformance.get_id(),
+                non_conformance.get_status()
+            )));
+        }
+
+        self.validate_timestamp(state, payload.get_closed_at(), "Closed at date")?;
+
+        non_conformance.set_status(proto::non_conformance::NonConformance_Status::CLOSED);
+        non_conformance.set_closed_at(payload.get_closed_at());
+
+        // Put updated non-conformance in state
+        state.set_non_conformance(payload.get_non_conformance_id(), non_conformance)?;
+
+        Ok(())
+    }
+
+    /// Fetches the Organization of the signer and validates that the signer holds the
+    /// permission within it
+    fn get_authorized_organization(
+        &self,
+        state: &mut CertState,
+        signer_public_key: &str,
+        permission: proto::organization::Organization_Permission,
+    ) -> Result<proto::organization::Organization, ApplyError> {
+        let agent = match state.get_agent(signer_public_key) {
+            Ok(Some(agent)) => Ok(agent),
+            Ok(None) => Err(ApplyError::InvalidTransaction(format!(
+                "No agent exists: {}",
+                signer_public_key
+            ))),
+            Err(err) => Err(err),
+        }?;
+
+        if agent.get_organization_id().is_empty() {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Agent is not associated with an organization: {}",
+                signer_public_key
+            )));
+        }
+
+        let organization = match state.get_organization(agent.get_organization_id()) {
+            Ok(Some(organization)) => Ok(organization),
+            Ok(None) => Err(ApplyError::InvalidTransaction(format!(
+                "No organization exists: {}",
+                agent.get_organization_id()
+            ))),
+            Err(err) => Err(err),
+        }?;
+
+        // Validate agent is authorized
+        let block_time = state.get_block_timestamp()?;
+        if !is_authorized(signer_public_key, &organization, permission, block_time) {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Agent {} lacks the {:?} permission within organization {}",
+                signer_public_key,
+                permission,
+                organization.get_id()
+            )));
+        }
+
+        Ok(organization)
+    }
+
+    /// Validates that a client supplied timestamp is not more than MAX_TIMESTAMP_DRIFT seconds
+    /// before, nor more than MAX_TIMESTAMP_LEAD seconds after, the block time
+    fn validate_timestamp(
+        &self,
+        state: &mut CertState,
+        timestamp: u64,
+        field: &str,
+    ) -> Result<(), ApplyError> {
+        let block_time = state.get_block_timestamp()?;
+        if timestamp.saturating_add(MAX_TIMESTAMP_DRIFT) < block_time {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "{} {} is more than {} seconds before the block time {}",
+                field, timestamp, MAX_TIMESTAMP_DRIFT, block_time
+            )));
+        }
+        if timestamp > block_time.saturating_add(MAX_TIMESTAMP_LEAD) {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "{} {} is more than {} seconds after the block time {}",
+                field, timestamp, MAX_TIMESTAMP_LEAD, block_time
+            )));
+        }
+        Ok(())
+    }
+
+    /// Creates a new Standard and submits it to state
+    ///
+    /// ```
+    /// # Errors
+    /// Returns an error if
+    ///   - a standard with the standard id already exist
+    ///   - an Agent with the signer public key does not exist
+    ///   - the Agent submitting the transaction is not associated with the organization
+    ///   - the Agent submitting the transaction lacks the MANAGE_STANDARDS permission
+    ///   - the Organization the Agent is associated with is not a StandardsBody
+    ///   - the Organization has not been approved by a registry operator
+    ///   - the standard does not exist
+    ///   - it fails to submit the new Standard to state.
+    /// ```
+    pub fn create_standard(
+        &self,
+        payload: &proto::payload::CreateStandardAction,
+        state: &mut CertState,
+        signer_public_key: &str,
+    ) -> Result<(), ApplyError> {
+        // Verify that name is not already associated with a Standard object
+        match state.get_standard(&payload.standard_id) {
+            Ok(Some(_)) => Err(ApplyError::InvalidTransaction(format!(
+                "Standard already exists: {}",
+                payload.name
+            ))),
+            Ok(None) => Ok(()),
+            Err(err) => Err(err),
+        }?;
+
+        // Validate signer public key and agent
+        let agent = match state.get_agent(signer_public_key) {
+            Ok(Some(agent)) => Ok(agent),
+            Ok(None) => Err(ApplyError::InvalidTransaction(format!(
+                "No agent exists: {}",
+                signer_public_key
+            ))),
+            Err(err) => Err(err),
+        }?;
+
+        if agent.get_organization_id().is_empty() {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Agent is not associated with an organization: {}",
+                agent.get_organization_id(),
+            )));
+        }
+
+        // Validate org existence
+        let organization = match state.get_organization(agent.get_organization_id()) {
+            Ok(Some(organization)) => Ok(organization),
+            Ok(None) => Err(ApplyError::InvalidTransaction(format!(
+                "No organization exists: {}",
+                agent.get_organization_id()
+            ))),
+            Err(err) => Err(err),
+        }?;
+
+        match organization.get_organization_type() {
+            proto::organization::Organization_Type::STANDARDS_BODY => Ok(()),
+            _ => Err(ApplyError::InvalidTransaction(
+                "Organization associated with agent cannot create standards".to_string(),
+            )),
+        }?;
+
+        if organization.get_approval_status()
+            != proto::organization::Organization_ApprovalStatus::APPROVED
+        {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Organization {} has not been approved by a registry operator",
+                agent.get_organization_id()
+            )));
+        }
+
+        // Validate agent is authorized
+        let block_time = state.get_block_timestamp()?;
+        if !is_authorized(
+            signer_public_key,
+            &organization,
+            proto::organization::Organization_Permission::MANAGE_STANDARDS,
+            block_time,
+        ) {
+            return Err(ApplyError::InvalidTransaction(
+                "Agent is not authorized to create a certification standard".to_string(),
+            ));
+        }
+
+        let mut new_standard_version = proto::standard::Standard_StandardVersion::new();
+        new_standard_version.set_version(payload.version.clone());
+        new_standard_version.set_description(payload.description.clone());
+        new_standard_version.set_link(payload.link.clone());
+        new_standard_version.set_approval_date(payload.approval_date.clone());
+        new_standard_version.set_data_schema(protobuf::RepeatedField::from_vec(
+            payload.get_data_schema().to_vec(),
+        ));
+
+        let mut new_standard = proto::standard::Standard::new();
+        new_standard.set_id(payload.standard_id.clone());
+        new_standard.set_name(payload.name.clone());
+        new_standard.set_organization_id(organization.id.clone());
+        new_standard.set_status(proto::standard::Standard_Status::ACTIVE);
+        new_standard.set_versions(protobuf::RepeatedField::from_vec(vec![
+            new_standard_version,
+        ]));
+
+        // Put new standard in state
+        state.set_standard(&payload.standard_id, new_standard)?;
+
+        Ok(())
+    }
+
+    /// Adds a new version of an existing Standard and submits it to state
+    ///
+    /// ```
+    /// # Errors
+    /// Returns an error if
+    ///   - an standard with the standard id does not exist
+    ///   - the same standard version already exists for this standard
+    ///   - an Agent with the signer public key does not exist
+    ///   - the Agent submitting the transaction is not associated with the organization
+    ///   - the Agent submitting the transaction lacks the MANAGE_STANDARDS permission
+    ///   - the Organization the Agent is associated with is not a StandardsBody
+    ///   - the Organization has not been approved by a registry operator
+    ///   - the standard being updated was not created by the organization of the Agent who signed the transaction
+    ///   - the standard has been retired
+    ///   - it fails to submit the new Standard to state.
+    /// ```
+    pub fn update_standard(
+        &self,
+        payload: &proto::payload::UpdateStandardAction,
+        state: &mut CertState,
+        signer_public_key: &str,
+    ) -> Result<(), ApplyError> {
+        // Verify that name is not already associated with a Standard object
+        let mut standard = match state.get_standard(&payload.standard_id)? {
+            Some(standard) => Ok(standard),
+            None => Err(ApplyError::InvalidTransaction(format!(
+                "Standard {} does not exist",
+                payload.standard_id
+            ))),
+        }?;
+
+        let mut versions = standard.get_versions().to_vec();
+
+        if versions
+            .iter()
+            .find(|version| version.version == payload.version)
+            .is_some()
+        {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Version already exists. Version  {}",
+                payload.version
+            )));
+        }
+
+        // Validate signer public key and agent
+        let agent = match state.get_agent(signer_public_key) {
+            Ok(Some(agent)) => Ok(agent),
+            Ok(None) => Err(ApplyError::InvalidTransaction(format!(
+                "Agent does not exist: {}",
+                signer_public_key
+            ))),
+            Err(err) => Err(err),
+        }?;
+
+        if agent.get_organization_id().is_empty() {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Agent is not associated with an organization: {}",
+                agent.get_organization_id(),
+            )));
+        }
+
+        // Validate org existence
+        let organization = match state.get_organization(agent.get_organization_id()) {
+            Ok(Some(organization)) => Ok(organization),
+            Ok(None) => Err(ApplyError::InvalidTransaction(format!(
+                "Organization does not exist: {}",
+                agent.get_organization_id()
+            ))),
+            Err(err) => Err(err),
+        }?;
+
+        match organization.get_organization_type() {
+            proto::organization::Organization_Type::STANDARDS_BODY => Ok(()),
+            _ => Err(ApplyError::InvalidTransaction(
+                "Organization associated with agent cannot create standards".to_string(),
+            )),
+        }?;
+
+        if organization.get_approval_status()
+            != proto::organization::Organization_ApprovalStatus::APPROVED
+        {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Organization {} has not been approved by a registry operator",
+                agent.get_organization_id()
+            )));
+        }
+
+        // Validate agent is authorized
+        let block_time = state.get_block_timestamp()?;
+        if !is_authorized(
+            signer_public_key,
+            &organization,
+            proto::organization::Organization_Permission::MANAGE_STANDARDS,
+            block_time,
+        ) {
+            return Err(ApplyError::InvalidTransaction(
+                "Agent is not authorized to create a certification standard".to_string(),
+            ));
+        }
+
+        // Validade standard was created by agent's organizatio
+        if agent.get_organization_id() != standard.get_organization_id() {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Organization {} did not create the certification standard {}",
+                organization.get_name(),
+                standard.get_name()
+            )));
+        }
+
+        // A retired standard takes no new versions
+        validate_standard_active(&standard)?;
+
+        let mut new_standard_version = proto::standard::Standard_StandardVersion::new();
+        new_standard_version.set_version(payload.version.clone());
+        new_standard_version.set_description(payload.description.clone());
+        new_standard_version.set_link(payload.link.clone());
+        new_standard_version.set_approval_date(payload.approval_date.clone());
+        new_standard_version.set_data_schema(protobuf::RepeatedField::from_vec(
+            payload.get_data_schema().to_vec(),
+        ));
+
+        versions.push(new_standard_version);
+
+        standard.set_versions(protobuf::RepeatedField::from_vec(versions));
+
+        // Put updated standard in state
+        state.set_standard(&standard.id.clone(), standard)?;
+
+        Ok(())
+    }
+
+    /// Sets the date a version of a Standard stops being valid and submits the Standard to state
+    ///
+    /// ```
+    /// # Errors
+    /// Returns an error if
+    ///   - the standard cannot be managed by the signer, see get_standard_for_standards_body
+    ///   - the version does not exist for the standard
+    ///   - the retirement date is before the version's approval date
+    ///   - the version has already been retired at the block time
+    ///   - it fails to submit the Standard to state.
+    /// ```
+    pub fn retire_standard_version(
+        &self,
+        payload: &proto::payload::RetireStandardVersionAction,
+        state: &mut CertState,
+        signer_public_key: &str,
+    ) -> Result<(), ApplyError> {
+        let mut standard = self.get_standard_for_standards_body(
+            payload.get_standard_id(),
+            state,
+            signer_public_key,
+        )?;
+
+        let block_time = state.get_block_timestamp()?;
+        {
+            let version = match standard
+                .mut_versions()
+                .iter_mut()
+                .find(|version| version.get_version() == payload.get_version())
+            {
+                Some(version) => Ok(version),
+                None => Err(ApplyError::InvalidTransaction(format!(
+                    "Version {} does not exist for Standard {}",
+                    payload.get_version(),
+                    payload.get_standard_id()
+                ))),
+            }?;
+
+            if payload.get_retirement_date() < version.get_approval_date() {
+                return Err(ApplyError::InvalidTransaction(format!(
+                    "Retirement date {} is before the approval date {} of version {}",
+                    payload.get_retirement_date(),
+                    version.get_approval_date(),
+                    payload.get_version()
+                )));
+            }
+
+            if is_version_retired_at(version, block_time) {
+                return Err(ApplyError::InvalidTransaction(format!(
+                    "Version {} of Standard {} has already been retired",
+                    payload.get_version(),
+                    payload.get_standard_id()
+                )));
+            }
+
+            version.set_retirement_date(payload.get_retirement_date());
+        }
+
+        state.set_standard(payload.get_standard_id(), standard)?;
+
+        Ok(())
+    }
+
+    /// Withdraws a Standard entirely and submits it to state. Requests, accreditations and
+    /// certificates can no longer be made for a retired Standard.
+    ///
+    /// ```
+    /// # Errors
+    /// Returns an error if
+    ///   - the standard cannot be managed by the signer, see get_standard_for_standards_body
+    ///   - it fails to submit the Standard to state.
+    /// ```
+    pub fn retire_standard(
+        &self,
+        payload: &proto::payload::RetireStandardAction,
+        state: &mut CertState,
+        signer_public_key: &str,
+    ) -> Result<(), ApplyError> {
+        let mut standard = self.get_standard_for_standards_body(
+            payload.get_standard_id(),
+            state,
+            signer_public_key,
+        )?;
+
+        standard.set_status(proto::standard::Standard_Status::RETIRED);
+
+        state.set_standard(payload.get_standard_id(), standard)?;
+
+        Ok(())
+    }
+
+    /// Fetches a Standard the signer's Standards Body created, validating that the signer holds
+    /// the MANAGE_STANDARDS permission within it
+    ///
+    /// ```
+    /// # Errors
+    /// Returns an error if
+    ///   - an Agent with the signer public key does not exist
+    ///   - the Agent submitting the transaction is not associated with an organization
+    ///   - the Organization the Agent is associated with is not a StandardsBody
+    ///   - the Organization has not been approved by a registry operator
+    ///   - the Agent submitting the transaction lacks the MANAGE_STANDARDS permission
+    ///   - the standard does not exist or has been retired
+    ///   - the standard was not created by the organization of the Agent who signed the transaction
+    /// ```
+    fn get_standard_for_standards_body(
+        &self,
+        standard_id: &str,
+        state: &mut CertState,
+        signer_public_key: &str,
+    ) -> Result<proto::standard::Standard, ApplyError> {
+        let agent = match state.get_agent(signer_public_key) {
+            Ok(Some(agent)) => Ok(agent),
+            Ok(None) => Err(ApplyError::InvalidTransaction(format!(
+                "Agent does not exist: {}",
+                signer_public_key
+            ))),
+            Err(err) => Err(err),
+        }?;
+
+        if agent.get_organization_id().is_empty() {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Agent is not associated with an organization: {}",
+                signer_public_key,
+            )));
+        }
+
+        let organization = match state.get_organization(agent.get_organization_id()) {
+            Ok(Some(organization)) => Ok(organization),
+            Ok(None) => Err(ApplyError::InvalidTransaction(format!(
+                "No organization exists: {}",
+                agent.get_organization_id()
+            ))),
+            Err(err) => Err(err),
+        }?;
+
+        match organization.get_organization_type() {
+            proto::organization::Organization_Type::STANDARDS_BODY => Ok(()),
+            _ => Err(ApplyError::InvalidTransaction(
+                "Organization associated with agent cannot retire standards".to_string(),
+            )),
+        }?;
+
+        if organization.get_approval_status()
+            != proto::organization::Organization_ApprovalStatus::APPROVED
+        {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Organization {} has not been approved by a registry operator",
+                agent.get_organization_id()
+            )));
+        }
+
+        let block_time = state.get_block_timestamp()?;
+        if !is_authorized(
+            signer_public_key,
+            &organization,
+            proto::organization::Organization_Permission::MANAGE_STANDARDS,
+            block_time,
+        ) {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Agent {} is not authorized to retire standard {}",
+                signer_public_key, standard_id
+            )));
+        }
+
+        let standard = match state.get_standard(standard_id) {
+            Ok(Some(standard)) => Ok(standard),
+            Ok(None) => Err(ApplyError::InvalidTransaction(format!(
+                "No standard with ID {} exists",
+                standard_id
+            ))),
+            Err(err) => Err(err),
+        }?;
+
+        if agent.get_organization_id() != standard.get_organization_id() {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Signer's associated organization did not create the certification standard {}",
+                standard.get_name()
+            )));
+        }
+        validate_standard_active(&standard)?;
+
+        Ok(standard)
+    }
+
+    /// Adds a new accreditation to an existing CertifyingBody organization and submits it to state
+    ///
+    /// ```
+    /// # Errors
+    /// Returns an error if
+    ///   - an Agent with the signer public key does not exist
+    ///   - the Agent submitting the transaction is not associated with the organization
+    ///   - the Agent submitting the transaction lacks the ACCREDIT permission
+    ///   - the Organization the Agent is associated with is not a StandardsBody
+    ///   - the certifying body id does provided in the payload does not identify an existing CertifyingBody organization
+    ///   - either Organization has not been approved by a registry operator
+    ///   - the standard provided in the payload does not exist
+    ///   - the standard was not created by the organization of the Agent who signed the transaction
+    ///   - the standard has been retired
+    ///   - the CertifyingBody is already accredited for the latest version of the standard
+    ///   - the accreditation expires after the retirement date of the latest version
+    ///   - it fails to submit the new Standard to state.
+    /// ```
+    pub fn accredit_certifying_body(
+        &self,
+        payload: &proto::payload::AccreditCertifyingBodyAction,
+        state: &mut CertState,
+        signer_public_key: &str,
+    ) -> Result<(), ApplyError> {
+        // Verify the signer
+        let agent = match state.get_agent(signer_public_key) {
+            Ok(Some(agent)) => Ok(agent),
+            Ok(None) => Err(ApplyError::InvalidTransaction(format!(
+                "Agent does not exist: {}",
+                signer_public_key
+            ))),
+            Err(err) => Err(err),
+        }?;
+
+        // Verify the signer is associated with a Standards Body
+        if agent.get_organization_id().is_empty() {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Agent is not associated with an organization: {}",
+                agent.get_organization_id(),
+            )));
+        }
+
+        let agent_organization = match state.get_organization(agent.get_organization_id()) {
+            Ok(Some(agent_organization)) => Ok(agent_organization),
+            Ok(None) => Err(ApplyError::InvalidTransaction(format!(
+                "No organization exists: {}",
+                agent.get_organization_id()
+            ))),
+            Err(err) => Err(err),
+        }?;
+
+        match agent_organization.get_organization_type() {
+            proto::organization::Organization_Type::STANDARDS_BODY => Ok(()),
+            _ => Err(ApplyError::InvalidTransaction(
+                "Organization associated with agent cannot accredit Certifying Bodies".to_string(),
+            )),
+        }?;
+
+        if agent_organization.get_approval_status()
+            != proto::organization::Organization_ApprovalStatus::APPROVED
+        {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Organization {} has not been approved by a registry operator",
+                agent.get_organization_id()
+            )));
+        }
+
+        // Verify the signer is authorized within their organization
+        let block_time = state.get_block_timestamp()?;
+        if !is_authorized(
+            signer_public_key,
+            &agent_organization,
+            proto::organization::Organization_Permission::ACCREDIT,
+            block_time,
+        ) {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Agent {} is not authorized to accredit certifying body: {}",
+                signer_public_key,
+                payload.get_certifying_body_id(),
+            )));
+        }
+
+        // Verify the certifying_body_id is associated with a Certifying body
+        let mut certifying_body = match state.get_organization(payload.get_certifying_body_id()) {
+            Ok(Some(certifying_body)) => Ok(certifying_body),
+            Ok(None) => Err(ApplyError::InvalidTransaction(format!(
+                "No organization exists: {}",
+                payload.get_certifying_body_id(),
+            ))),
+            Err(err) => Err(err),
+        }?;
+
+        match certifying_body.get_organization_type() {
+            proto::organization::Organization_Type::CERTIFYING_BODY => Ok(()),
+            _ => Err(ApplyError::InvalidTransaction(
+                "Only Certifying Bodies may be accredited".to_string(),
+            )),
+        }?;
+
+        if certifying_body.get_approval_status()
+            != proto::organization::Organization_ApprovalStatus::APPROVED
+        {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Organization {} has not been approved by a registry operator",
+                payload.get_certifying_body_id()
+            )));
+        }
+
+        // Verify the name is associated with an existing standard
+        let standard = match state.get_standard(&payload.get_standard_id()) {
+            Ok(Some(standard)) => Ok(standard),
+            Ok(None) => Err(ApplyError::InvalidTransaction(format!(
+                "No standard with ID {} exists",
+                payload.get_standard_id()
+            ))),
+            Err(err) => Err(err),
+        }?;
+
+        // Verify the agent's organization created the standard
+        if agent.get_organization_id() != standard.get_organization_id() {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Signer's associated organization did not create the certification standard {}",
+                standard.get_name()
+            )));
+        }
+        validate_standard_active(&standard)?;
+
+        let mut certifying_body_details = certifying_body.get_certifying_body_details().clone();
+
+        let mut accreditations = certifying_body_details.get_accreditations().to_vec();
+
+        let standard_versions = standard.get_versions().to_vec();
+        let latest_standard_version = match standard_versions.last() {
+            Some(valid_version) => valid_version,
+            None => {
+                return Err(ApplyError::InvalidTransaction(format!(
+                    "Invalid version for Standard {}",
+                    standard.get_id()
+                )));
+            }
+        };
+
+        if accreditations
+            .iter()
+            .find(|accreditation| {
+                accreditation.get_standard_id() == payload.get_standard_id()
+                    && accreditation.get_standard_version()
+                        == latest_standard_version.get_version().to_string()
+            })
+            .is_some()
+        {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Accreditation for Standard {}, version {} already exists",
+                payload.get_standard_id(),
+                latest_standard_version.get_version().to_string(),
+            )));
+        }
+
+        // Verify the date
+        let valid_from = payload.get_valid_from();
+        if valid_from < latest_standard_version.get_approval_date() {
+            return Err(ApplyError::InvalidTransaction(
+                "Invalid date, Standard is not valid from this date".to_string(),
+            ));
+        }
+
+        let valid_to = payload.get_valid_to();
+        if valid_to < valid_from {
+            return Err(ApplyError::InvalidTransaction(
+                "Invalid dates. Valid to must be after valid from".to_string(),
+            ));
+        }
+        validate_expires_by_retirement(
+            payload.get_standard_id(),
+            latest_standard_version,
+            valid_to,
+        )?;
+
+        let mut new_accreditation = proto::organization::CertifyingBody_Accreditation::new();
+        new_accreditation.set_standard_id(payload.get_standard_id().to_string());
+        new_accreditation.set_standard_version(latest_standard_version.get_version().to_string());
+        new_accreditation.set_accreditor_id(agent_organization.get_id().to_string());
+        new_accreditation.set_valid_to(payload.get_valid_to());
+        new_accreditation.set_valid_from(payload.get_valid_from());
+
+        add_accreditation_event(
+            state,
+            events::ACCREDITATION_GRANTED,
+            payload.get_certifying_body_id(),
+            &new_accreditation,
+        )?;
+
+        accreditations.push(new_accreditation);
+        certifying_body_details
+            .set_accreditations(protobuf::RepeatedField::from_vec(accreditations));
+
+        certifying_body.set_certifying_body_details(certifying_body_details);
+
+        // Put updated CertifyingBody in state
+        state.set_organization(payload.get_certifying_body_id(), certifying_body)?;
+
+        Ok(())
+    }
+
+    /// Removes accreditations from an existing CertifyingBody organization and submits it to state
+    ///
+    /// ```
+    /// # Errors
+    /// Returns an error if
+    ///   - an Agent with the signer public key does not exist
+    ///   - the Agent submitting the transaction is not associated with the organization
+    ///   - the Organization the Agent is associated with is not a StandardsBody
+    ///   - the Agent submitting the transaction lacks the ACCREDIT permission
+    ///   - the certifying body id provided in the payload does not identify an existing CertifyingBody organization
+    ///   - the standard provided in the payload does not exist
+    ///   - the standard was not created by the organization of the Agent who signed the transaction
+    ///   - the CertifyingBody holds no matching accreditation for the standard
+    ///   - it fails to submit the updated CertifyingBody to state.
+    /// ```
+    pub fn revoke_accreditation(
+        &self,
+        payload: &proto::payload::RevokeAccreditationAction,
+        state: &mut CertState,
+        signer_public_key: &str,
+    ) -> Result<(), ApplyError> {
+        // Verify the signer
+        let agent = match state.get_agent(signer_public_key) {
+            Ok(Some(agent)) => Ok(agent),
+            Ok(None) => Err(ApplyError::InvalidTransaction(format!(
+                "Agent does not exist: {}",
+                signer_public_key
+            ))),
+            Err(err) => Err(err),
+        }?;
+
+        // Verify the signer is associated with a Standards Body
+        if agent.get_organization_id().is_empty() {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Agent is not associated with an organization: {}",
+                agent.get_organization_id(),
+            )));
+        }
+
+        let agent_organization = match state.get_organization(agent.get_organization_id()) {
+            Ok(Some(agent_organization)) => Ok(agent_organization),
+            Ok(None) => Err(ApplyError::InvalidTransaction(format!(
+                "No organization exists: {}",
+                agent.get_organization_id()
+            ))),
+            Err(err) => Err(err),
+        }?;
+
+        match agent_organization.get_organization_type() {
+            proto::organization::Organization_Type::STANDARDS_BODY => Ok(()),
+            _ => Err(ApplyError::InvalidTransaction(
+                "Organization associated with agent cannot revoke accreditations".to_string(),
+            )),
+        }?;
+
+        // Verify the signer is authorized within their organization
+        let block_time = state.get_block_timestamp()?;
+        if !is_authorized(
+            signer_public_key,
+            &agent_organization,
+            proto::organization::Organization_Permission::ACCREDIT,
+            block_time,
+        ) {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Agent {} is not authorized to revoke accreditations of certifying body: {}",
+                signer_public_key,
+                payload.get_certifying_body_id(),
+            )));
+        }
+
+        // Verify the certifying_body_id is associated with a Certifying body
+        let mut certifying_body = match state.get_organization(payload.get_certifying_body_id()) {
+            Ok(Some(certifying_body)) => Ok(certifying_body),
+            Ok(None) => Err(ApplyError::InvalidTransaction(format!(
+                "No organization exists: {}",
+                payload.get_certifying_body_id(),
+            ))),
+            Err(err) => Err(err),
+        }?;
+
+        match certifying_body.get_organization_type() {
+            proto::organization::Organization_Type::CERTIFYING_BODY => Ok(()),
+            _ => Err(ApplyError::InvalidTransaction(
+                "Only Certifying Bodies hold accreditations".to_string(),
+            )),
+        }?;
+
+        // Verify the standard exists and was created by the agent's organization
+        let standard = match state.get_standard(&payload.get_standard_id()) {
+            Ok(Some(standard)) => Ok(standard),
+            Ok(None) => Err(ApplyError::InvalidTransaction(format!(
+                "No standard with ID {} exists",
+                payload.get_standard_id()
+            ))),
+            Err(err) => Err(err),
+        }?;
+
+        if agent.get_organization_id() != standard.get_organization_id() {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Signer's associated organization did not create the certification standard {}",
+                standard.get_name()
+            )));
+        }
+
+        let mut certifying_body_details = certifying_body.get_certifying_body_details().clone();
+
+        let (revoked, accreditations): (Vec<_>, Vec<_>) = certifying_body_details
+            .get_accreditations()
+            .to_vec()
+            .into_iter()
+            .partition(|accreditation| {
+                accreditation.get_standard_id() == payload.get_standard_id()
+                    && (payload.get_standard_version().is_empty()
+                        || accreditation.get_standard_version() == payload.get_standard_version())
+            });
+
+        if revoked.is_empty() {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Certifying body {} holds no accreditation for Standard {} {}",
+                payload.get_certifying_body_id(),
+                payload.get_standard_id(),
+                payload.get_standard_version()
+            )));
+        }
+
+        for accreditation in &revoked {
+            add_accreditation_event(
+                state,
+                events::ACCREDITATION_REVOKED,
+                payload.get_certifying_body_id(),
+                accreditation,
+            )?;
+        }
+
+        certifying_body_details
+            .set_accreditations(protobuf::RepeatedField::from_vec(accreditations));
+
+        certifying_body.set_certifying_body_details(certifying_body_details);
+
+        // Put updated CertifyingBody in state
+        state.set_organization(payload.get_certifying_body_id(), certifying_body)?;
+
+        Ok(())
+    }
+
+    /// Records that a version of a Standard is equivalent to a version of another Standard and
+    /// submits the Standard to state. The equivalence is mutual once the standards body that
+    /// created the other Standard declares it as well. When both Standards were created by the
+    /// same standards body, both sides are recorded at once.
+    ///
+    /// ```
+    /// # Errors
+    /// Returns an error if
+    ///   - an Agent with the signer public key does not exist
+    ///   - the Agent submitting the transaction is not associated with an organization
+    ///   - the Organization the Agent is associated with is not a StandardsBody
+    ///   - the Organization has not been approved by a registry operator
+    ///   - the Agent submitting the transaction lacks the MANAGE_STANDARDS permission
+    ///   - either standard or standard version does not exist
+    ///   - the standard was not created by the organization of the Agent who signed the transaction
+    ///   - the equivalence has already been declared for the standard
+    ///   - it fails to submit the Standard to state.
+    /// ```
+    pub fn declare_equivalence(
+        &self,
+        payload: &proto::payload::DeclareEquivalenceAction,
+        state: &mut CertState,
+        signer_public_key: &str,
+    ) -> Result<(), ApplyError> {
+        // Verify the signer
+        let agent = match state.get_agent(signer_public_key) {
+            Ok(Some(agent)) => Ok(agent),
+            Ok(None) => Err(ApplyError::InvalidTransaction(format!(
+                "Agent does not exist: {}",
+                signer_public_key
+            ))),
+            Err(err) => Err(err),
+        }?;
+
+        if agent.get_organization_id().is_empty() {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Agent is not associated with an organization: {}",
+                agent.get_organization_id(),
+            )));
+        }
+
+        let organization = match state.get_organization(agent.get_organization_id()) {
+            Ok(Some(organization)) => Ok(organization),
+            Ok(None) => Err(ApplyError::InvalidTransaction(format!(
+                "No organization exists: {}",
+                agent.get_organization_id()
+            ))),
+            Err(err) => Err(err),
+        }?;
+
+        match organization.get_organization_type() {
+            proto::organization::Organization_Type::STANDARDS_BODY => Ok(()),
+            _ => Err(ApplyError::InvalidTransaction(
+                "Organization associated with agent cannot declare equivalences".to_string(),
+            )),
+        }?;
+
+        if organization.get_approval_status()
+            != proto::organization::Organization_ApprovalStatus::APPROVED
+        {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Organization {} has not been approved by a registry operator",
+                agent.get_organization_id()
+            )));
+        }
+
+        // Verify the signer is authorized within their organization
+        let block_time = state.get_block_timestamp()?;
+        if !is_authorized(
+            signer_public_key,
+            &organization,
+            proto::organization::Organization_Permission::MANAGE_STANDARDS,
+            block_time,
+        ) {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Agent {} is not authorized to declare equivalences for standard {}",
+                signer_public_key,
+                payload.get_standard_id()
+            )));
+        }
+
+        let mut standard = get_standard_with_version(
+            state,
+            payload.get_standard_id(),
+            payload.get_standard_version(),
+        )?;
+        let mut equivalent_standard = get_standard_with_version(
+            state,
+            payload.get_equivalent_standard_id(),
+            payload.get_equivalent_standard_version(),
+        )?;
+
+        if agent.get_organization_id() != standard.get_organization_id() {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Signer's associated organization did not create the certification standard {}",
+                standard.get_name()
+            )));
+        }
+
+        if !add_equivalence(
+            &mut standard,
+            payload.get_standard_version(),
+            payload.get_equivalent_standard_id(),
+            payload.get_equivalent_standard_version(),
+        ) {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Standard {} version {} is already declared equivalent to Standard {} version {}",
+                payload.get_standard_id(),
+                payload.get_standard_version(),
+                payload.get_equivalent_standard_id(),
+                payload.get_equivalent_standard_version()
+            )));
+        }
+        state.set_standard(payload.get_standard_id(), standard)?;
+
+        // A standards body that created both standards declares both sides at once
+        if agent.get_organization_id() == equivalent_standard.get_organization_id() {
+            add_equivalence(
+                &mut equivalent_standard,
+                payload.get_equivalent_standard_version(),
+                payload.get_standard_id(),
+                payload.get_standard_version(),
+            );
+            state.set_standard(payload.get_equivalent_standard_id(), equivalent_standard)?;
+        }
+
+        Ok(())
+    }
+
+    /// Adds a factory to the supplier list of the signer's Retailer and submits it to state
+    ///
+    /// ```
+    /// # Errors
+    /// Returns an error if
+    ///   - an Agent with the signer public key does not exist
+    ///   - the Agent submitting the transaction is not associated with an organization
+    ///   - the Organization the Agent is associated with is not a Retailer
+    ///   - the Agent submitting the transaction lacks the MANAGE_SUPPLIERS permission
+    ///   - the factory does not exist
+    ///   - the factory is already a supplier of the Retailer
+    ///   - it fails to submit the Retailer to state.
+    /// ```
+    pub fn add_supplier(
+        &self,
+        payload: &proto::payload::AddSupplierAction,
+        state: &mut CertState,
+        signer_public_key: &str,
+    ) -> Result<(), ApplyError> {
+        let mut retailer = get_signer_retailer(state, signer_public_key)?;
+
+        match state.get_organization(payload.get_factory_id()) {
+            Ok(Some(ref factory))
+                if factory.get_organization_type()
+                    == proto::organization::Organization_Type::FACTORY =>
+            {
+                Ok(())
+            }
+            Ok(_) => Err(ApplyError::InvalidTransaction(format!(
+                "Factory does not exist: {}",
+                payload.get_factory_id()
+            ))),
+            Err(err) => Err(err),
+        }?;
+
+        let mut retailer_details = retailer.get_retailer_details().clone();
+        if retailer_details
+            .get_supplier_ids()
+            .iter()
+            .any(|supplier_id| supplier_id == payload.get_factory_id())
+        {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Factory {} is already a supplier of retailer {}",
+                payload.get_factory_id(),
+                retailer.get_id()
+            )));
+        }
+        retailer_details
+            .mut_supplier_ids()
+            .push(payload.get_factory_id().to_string());
+        retailer.set_retailer_details(retailer_details);
+
+        let retailer_id = retailer.get_id().to_string();
+        state.set_organization(&retailer_id, retailer)?;
+
+        Ok(())
+    }
+
+    /// Removes a factory from the supplier list of the signer's Retailer and submits it to state
+    ///
+    /// ```
+    /// # Errors
+    /// Returns an error if
+    ///   - an Agent with the signer public key does not exist
+    ///   - the Agent submitting the transaction is not associated with an organization
+    ///   - the Organization the Agent is associated with is not a Retailer
+    ///   - the Agent submitting the transaction lacks the MANAGE_SUPPLIERS permission
+    ///   - the factory is not a supplier of the Retailer
+    ///   - it fails to submit the Retailer to state.
+    /// ```
+    pub fn remove_supplier(
+        &self,
+        payload: &proto::payload::RemoveSupplierAction,
+        state: &mut CertState,
+        signer_public_key: &str,
+    ) -> Result<(), ApplyError> {
+        let mut retailer = get_signer_retailer(state, signer_public_key)?;
+
+        let mut retailer_details = retailer.get_retailer_details().clone();
+        let supplier_ids: Vec<String> = retailer_details
+            .get_supplier_ids()
+            .iter()
+            .filter(|supplier_id| *supplier_id != payload.get_factory_id())
+            .cloned()
+            .collect();
+        if supplier_ids.len() == retailer_details.get_supplier_ids().len() {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Factory {} is not a supplier of retailer {}",
+                payload.get_factory_id(),
+                retailer.get_id()
+            )));
+        }
+        retailer_details.set_supplier_ids(protobuf::RepeatedField::from_vec(supplier_ids));
+        retailer.set_retailer_details(retailer_details);
+
+        let retailer_id = retailer.get_id().to_string();
+        state.set_organization(&retailer_id, retailer)?;
+
+        Ok(())
+    }
+
+    /// Defines a role of the signer's Organization, replacing the permissions of an existing
+    /// role with the same name, and submits it to state
+    ///
+    /// ```
+    /// # Errors
+    /// Returns an error if
+    ///   - an Agent with the signer public key does not exist
+    ///   - the Agent submitting the transaction is not associated with an organization
+    ///   - the Organization the Agent is associated with does not exist
+    ///   - the Agent submitting the transaction lacks the MANAGE_ORGANIZATION permission
+    ///   - it fails to submit the Organization to state.
+    /// ```
+    pub fn define_role(
+        &self,
+        payload: &proto::payload::DefineRoleAction,
+        state: &mut CertState,
+        signer_public_key: &str,
+    ) -> Result<(), ApplyError> {
+        let agent = match state.get_agent(signer_public_key) {
+            Ok(Some(agent)) => Ok(agent),
+            Ok(None) => Err(ApplyError::InvalidTransaction(format!(
+                "Agent does not exist: {}",
+                signer_public_key
+            ))),
+            Err(err) => Err(err),
+        }?;
+
+        if agent.get_organization_id().is_empty() {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Agent is not associated with an organization: {}",
+                signer_public_key
+            )));
+        }
+
+        let mut organization = match state.get_organization(agent.get_organization_id()) {
+            Ok(Some(organization)) => Ok(organization),
+            Ok(None) => Err(ApplyError::InvalidTransaction(format!(
+                "No organization exists: {}",
+                agent.get_organization_id()
+            ))),
+            Err(err) => Err(err),
+        }?;
+
+        // Validate agent is authorized
+        let block_time = state.get_block_timestamp()?;
+        if !is_authorized(
+            signer_public_key,
+            &organization,
+            proto::organization::Organization_Permission::MANAGE_ORGANIZATION,
+            block_time,
+        ) {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Agent {} is not authorized to define roles of organization {}",
+                signer_public_key,
+                organization.get_id()
+            )));
+        }
+
+        let mut role = proto::organization::Organization_RoleDefinition::new();
+        role.set_name(payload.get_name().to_string());
+        role.set_permissions(payload.get_permissions().to_vec());
+
+        let existing_index = organization
+            .get_roles()
+            .iter()
+            .position(|existing| existing.get_name() == payload.get_name());
+        match existing_index {
+            Some(index) => organization.mut_roles()[index] = role,
+            None => organization.mut_roles().push(role),
+        }
+
+        let organization_id = organization.get_id().to_string();
+        state.set_organization(&organization_id, organization)?;
+
+        Ok(())
+    }
+
+    /// Sets the number of ADMIN approvals needed for sensitive changes to the signer's
+    /// Organization and submits it to state
+    ///
+    /// ```
+    /// # Errors
+    /// Returns an error if
+    ///   - the signer is not an Agent or is not associated with an Organization
+    ///   - the Organization the Agent is associated with does not exist
+    ///   - the Agent submitting the transaction lacks the MANAGE_ORGANIZATION permission
+    ///   - the quorum exceeds the number of ADMINs of the Organization
+    ///   - it fails to submit the Organization to state.
+    /// ```
+    pub fn set_admin_quorum(
+        &self,
+        payload: &proto::payload::SetAdminQuorumAction,
+        state: &mut CertState,
+        signer_public_key: &str,
+    ) -> Result<(), ApplyError> {
+        let agent = match state.get_agent(signer_public_key) {
+            Ok(Some(agent)) => Ok(agent),
+            Ok(None) => Err(ApplyError::InvalidTransaction(format!(
+                "Agent does not exist: {}",
+                signer_public_key
+            ))),
+            Err(err) => Err(err),
+        }?;
+
+        if agent.get_organization_id().is_empty() {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Agent is not associated with an organization: {}",
+                signer_public_key
+            )));
+        }
+
+        let mut organization = match state.get_organization(agent.get_organization_id()) {
+            Ok(Some(organization)) => Ok(organization),
+            Ok(None) => Err(ApplyError::InvalidTransaction(format!(
+                "No organization exists: {}",
+                agent.get_organization_id()
+            ))),
+            Err(err) => Err(err),
+        }?;
+
+        // Validate agent is authorized
+        let block_time = state.get_block_timestamp()?;
+        if !is_authorized(
+            signer_public_key,
+            &organization,
+            proto::organization::Organization_Permission::MANAGE_ORGANIZATION,
+            block_time,
+        ) {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Agent {} is not authorized to set the admin quorum of organization {}",
+                signer_public_key,
+                organization.get_id()
+            )));
+        }
+
+        // Validate the quorum can be reached
+        let admin_count = get_admin_keys(&organization, block_time).len();
+        if payload.get_admin_quorum() as usize > admin_count {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Admin quorum {} exceeds the {} ADMINs of organization {}",
+                payload.get_admin_quorum(),
+                admin_count,
+                organization.get_id()
+            )));
+        }
+
+        organization.set_admin_quorum(payload.get_admin_quorum());
+
+        let organization_id = organization.get_id().to_string();
+        state.set_organization(&organization_id, organization)?;
+
+        Ok(())
+    }
+
+    /// Creates a Proposal to apply a sensitive action to the signer's Organization and submits
+    /// it to state. The proposer's vote counts towards accepting the Proposal, and the
+    /// Proposal is recorded among the Organization's open proposals.
+    ///
+    /// ```
+    /// # Errors
+    /// Returns an error if
+    ///   - a Proposal with the same id already exists
+    ///   - the signer is not an Agent or is not associated with an Organization
+    ///   - the Organization the Agent is associated with does not exist
+    ///   - the Agent submitting the transaction lacks the MANAGE_ORGANIZATION permission
+    ///   - the Organization's admin quorum lets a single ADMIN apply the action
+    ///   - it fails to submit the Proposal or Organization to state.
+    /// ```
+    pub fn propose_change(
+        &self,
+        payload: &proto::payload::ProposeChangeAction,
+        state: &mut CertState,
+        signer_public_key: &str,
+    ) -> Result<(), ApplyError> {
+        if state.get_proposal(payload.get_proposal_id())?.is_some() {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Proposal already exists: {}",
+                payload.get_proposal_id()
+            )));
+        }
+
+        let agent = match state.get_agent(signer_public_key) {
+            Ok(Some(agent)) => Ok(agent),
+            Ok(None) => Err(ApplyError::InvalidTransaction(format!(
+                "Agent does not exist: {}",
+                signer_public_key
+            ))),
+            Err(err) => Err(err),
+        }?;
+
+        if agent.get_organization_id().is_empty() {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Agent is not associated with an organization: {}",
+                signer_public_key
+            )));
+        }
+
+        let mut organization = match state.get_organization(agent.get_organization_id()) {
+            Ok(Some(organization)) => Ok(organization),
+            Ok(None) => Err(ApplyError::InvalidTransaction(format!(
+                "No organization exists: {}",
+                agent.get_organization_id()
+            ))),
+            Err(err) => Err(err),
+        }?;
+
+        // Validate agent is authorized
+        let block_time = state.get_block_timestamp()?;
+        if !is_authorized(
+            signer_public_key,
+            &organization,
+            proto::organization::Organization_Permission::MANAGE_ORGANIZATION,
+            block_time,
+        ) {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Agent {} is not authorized to propose changes to organization {}",
+                signer_public_key,
+                organization.get_id()
+            )));
+        }
+
+        if organization.get_admin_quorum() < 2 {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Organization {} does not require proposals",
+                organization.get_id()
+            )));
+        }
+
+        let mut vote = proto::proposal::Proposal_Vote::new();
+        vote.set_public_key(signer_public_key.to_string());
+        vote.set_accept(true);
+
+        let mut proposal = proto::proposal::Proposal::new();
+        proposal.set_id(payload.get_proposal_id().to_string());
+        proposal.set_organization_id(organization.get_id().to_string());
+        proposal.set_proposer(signer_public_key.to_string());
+        proposal.set_payload(payload.get_payload().clone());
+        proposal.set_required_approvals(organization.get_admin_quorum());
+        proposal.set_status(proto::proposal::Proposal_Status::OPEN);
+        proposal.set_votes(protobuf::RepeatedField::from_vec(vec![vote]));
+        proposal.set_proposed_at(block_time);
+
+        organization
+            .mut_open_proposal_ids()
+            .push(payload.get_proposal_id().to_string());
+
+        let organization_id = organization.get_id().to_string();
+        state.set_organization(&organization_id, organization)?;
+        state.set_proposal(payload.get_proposal_id(), proposal)?;
+
+        Ok(())
+    }
+
+    /// Records the signer's vote on a Proposal and submits it to state. Once enough ADMINs
+    /// have accepted the Proposal its action is applied on behalf of the proposer, and once
+    /// too many have rejected it for the quorum to be reached it is closed. Votes cast by
+    /// agents that have since lost the MANAGE_ORGANIZATION permission are not counted.
+    ///
+    /// ```
+    /// # Errors
+    /// Returns an error if
+    ///   - the Proposal does not exist or is no longer open
+    ///   - the Organization of the Proposal does not exist
+    ///   - the Agent submitting the transaction lacks the MANAGE_ORGANIZATION permission
+    ///     within the Organization of the Proposal
+    ///   - the Agent has already voted on the Proposal
+    ///   - the Proposal is accepted but the proposer has lost the MANAGE_ORGANIZATION permission
+    ///   - the accepted action fails
+    ///   - it fails to submit the Proposal or Organization to state.
+    /// ```
+    pub fn vote_on_proposal(
+        &self,
+        payload: &proto::payload::VoteOnProposalAction,
+        state: &mut CertState,
+        signer_public_key: &str,
+    ) -> Result<(), ApplyError> {
+        let mut proposal = match state.get_proposal(payload.get_proposal_id()) {
+            Ok(Some(proposal)) => Ok(proposal),
+            Ok(None) => Err(ApplyError::InvalidTransaction(format!(
+                "Proposal does not exist: {}",
+                payload.get_proposal_id()
+            ))),
+            Err(err) => Err(err),
+        }?;
+
+        if proposal.get_status() != proto::proposal::Proposal_Status::OPEN {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Proposal {} is not open",
+                payload.get_proposal_id()
+            )));
+        }
+
+        let organization = match state.get_organization(proposal.get_organization_id()) {
+            Ok(Some(organization)) => Ok(organization),
+            Ok(None) => Err(ApplyError::InvalidTransaction(format!(
+                "No organization exists: {}",
+                proposal.get_organization_id()
+            ))),
+            Err(err) => Err(err),
+        }?;
+
+        // Validate agent is authorized
+        let block_time = state.get_block_timestamp()?;
+        if !is_authorized(
+            signer_public_key,
+            &organization,
+            proto::organization::Organization_Permission::MANAGE_ORGANIZATION,
+            block_time,
+        ) {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Agent {} is not authorized to vote on proposals of organization {}",
+                signer_public_key,
+                organization.get_id()
+            )));
+        }
+
+        if proposal
+            .get_votes()
+            .iter()
+            .any(|vote| vote.get_public_key() == signer_public_key)
+        {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Agent {} has already voted on proposal {}",
+                signer_public_key,
+                payload.get_proposal_id()
+            )));
+        }
+
+        let mut vote = proto::proposal::Proposal_Vote::new();
+        vote.set_public_key(signer_public_key.to_string());
+        vote.set_accept(payload.get_accept());
+        proposal.mut_votes().push(vote);
+
+        // Only the votes of agents that are still ADMINs are counted
+        let admin_keys = get_admin_keys(&organization, block_time);
+        let required_approvals = proposal.get_required_approvals() as usize;
+        let (approvals, rejections) = proposal
+            .get_votes()
+            .iter()
+            .filter(|vote| admin_keys.contains(vote.get_public_key()))
+            .fold((0, 0), |(approvals, rejections), vote| {
+                if vote.get_accept() {
+                    (approvals + 1, rejections)
+                } else {
+                    (approvals, rejections + 1)
+                }
+            });
+
+        if approvals >= required_approvals {
+            if !admin_keys.contains(proposal.get_proposer()) {
+                return Err(ApplyError::InvalidTransaction(format!(
+                    "Proposer {} is no longer authorized to change organization {}",
+                    proposal.get_proposer(),
+                    organization.get_id()
+                )));
+            }
+            let action = validate_proposed_payload(proposal.get_payload())?;
+            let proposer = proposal.get_proposer().to_string();
+
+            // Close the proposal first, so the action no longer sees it as open
+            proposal.set_status(proto::proposal::Proposal_Status::ACCEPTED);
+            close_proposal(state, proposal)?;
+            return self.apply_approved_action(action, state, &proposer);
+        } else if admin_keys.len().saturating_sub(rejections) < required_approvals {
+            proposal.set_status(proto::proposal::Proposal_Status::REJECTED);
+            return close_proposal(state, proposal);
+        }
+
+        state.set_proposal(payload.get_proposal_id(), proposal)?;
+
+        Ok(())
+    }
+
+    /// Withdraws an open Proposal and submits it to state, removing it from its Organization's
+    /// open proposals. This closes a Proposal whose action can no longer be applied. The
+    /// proposer may withdraw their Proposal, and any ADMIN may once the proposer has lost the
+    /// MANAGE_ORGANIZATION permission.
+    ///
+    /// ```
+    /// # Errors
+    /// Returns an error if
+    ///   - the Proposal does not exist or is no longer open
+    ///   - the Organization of the Proposal does not exist
+    ///   - the signer is not the proposer, and either lacks the MANAGE_ORGANIZATION permission
+    ///     within the Organization of the Proposal or the proposer still holds it
+    ///   - it fails to submit the Proposal or Organization to state.
+    /// ```
+    pub fn withdraw_proposal(
+        &self,
+        payload: &proto::payload::WithdrawProposalAction,
+        state: &mut CertState,
+        signer_public_key: &str,
+    ) -> Result<(), ApplyError> {
+        let mut proposal = match state.get_proposal(payload.get_proposal_id()) {
+            Ok(Some(proposal)) => Ok(proposal),
+            Ok(None) => Err(ApplyError::InvalidTransaction(format!(
+                "Proposal does not exist: {}",
+                payload.get_proposal_id()
+            ))),
+            Err(err) => Err(err),
+        }?;
+
+        if proposal.get_status() != proto::proposal::Proposal_Status::OPEN {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Proposal {} is not open",
+                payload.get_proposal_id()
+            )));
+        }
+
+        let organization = match state.get_organization(proposal.get_organization_id()) {
+            Ok(Some(organization)) => Ok(organization),
+            Ok(None) => Err(ApplyError::InvalidTransaction(format!(
+                "No organization exists: {}",
+                proposal.get_organization_id()
+            ))),
+            Err(err) => Err(err),
+        }?;
+
+        if proposal.get_proposer() != signer_public_key {
+            let block_time = state.get_block_timestamp()?;
+            let admin_keys = get_admin_keys(&organization, block_time);
+            if !admin_keys.contains(signer_public_key)
+                || admin_keys.contains(proposal.get_proposer())
+            {
+                return Err(ApplyError::InvalidTransaction(format!(
+                    "Agent {} is not authorized to withdraw proposal {}",
+                    signer_public_key,
+                    payload.get_proposal_id()
+                )));
+            }
+        }
+
+        proposal.set_status(proto::proposal::Proposal_Status::WITHDRAWN);
+        close_proposal(state, proposal)
+    }
+
+    /// Replaces the set of registry operators and submits it to state
+    ///
+    /// The first submission creates the registry. It must be signed by a key listed in the
+    /// `consensource.registry.operators` setting, which is expected to be set in the genesis
+    /// block.
+    ///
+    /// ```
+    /// # Errors
+    /// Returns an error if
+    ///   - the registry exists and the signer is not one of its operators
+    ///   - the registry does not exist and the signer is not listed in the
+    ///     `consensource.registry.operators` setting
+    ///   - it fails to submit the Registry to state.
+    /// ```
+    pub fn update_registry_operators(
+        &self,
+        payload: &proto::payload::UpdateRegistryOperatorsAction,
+        state: &mut CertState,
+        signer_public_key: &str,
+    ) -> Result<(), ApplyError> {
+        let mut registry = match state.get_registry()? {
+            Some(registry) => {
+                if !registry
+                    .get_operators()
+                    .iter()
+                    .any(|operator| operator == signer_public_key)
+                {
+                    return Err(ApplyError::InvalidTransaction(format!(
+                        "Signer {} is not a registry operator",
+                        signer_public_key
+                    )));
+                }
+                registry
+            }
+            None => {
+                let allowed_keys = state
+                    .get_setting(addressing::REGISTRY_OPERATORS_SETTING)?
+                    .unwrap_or_default();
+                if !allowed_keys
+                    .split(',')
+                    .any(|key| key.trim() == signer_public_key)
+                {
+                    return Err(ApplyError::InvalidTransaction(format!(
+                        "Signer {} is not permitted to create the registry by setting {}",
+                        signer_public_key,
+                        addressing::REGISTRY_OPERATORS_SETTING
+                    )));
+                }
+                proto::registry::Registry::new()
+            }
+        };
+
+        registry.set_operators(protobuf::RepeatedField::from_vec(
+            payload.get_operators().to_vec(),
+        ));
+
+        state.set_registry(registry)?;
+
+        Ok(())
+    }
+
+    /// Approves or rejects a Standards Body or Certifying Body and submits it to state
+    ///
+    /// ```
+    /// # Errors
+    /// Returns an error if
+    ///   - the registry has not been created
+    ///   - the signer is not a registry operator
+    ///   - the Organization does not exist
+    ///   - the Organization is not a Standards Body or Certifying Body
+    ///   - it fails to submit the Organization to state.
+    /// ```
+    pub fn review_organization(
+        &self,
+        payload: &proto::payload::ReviewOrganizationAction,
+        state: &mut CertState,
+        signer_public_key: &str,
+    ) -> Result<(), ApplyError> {
+        let registry = match state.get_registry() {
+            Ok(Some(registry)) => Ok(registry),
+            Ok(None) => Err(ApplyError::InvalidTransaction(
+                "No registry operators have been set".to_string(),
+            )),
+            Err(err) => Err(err),
+        }?;
+
+        if !registry
+            .get_operators()
+            .iter()
+            .any(|operator| operator == signer_public_key)
+        {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Signer {} is not a registry operator",
+                signer_public_key
+            )));
+        }
+
+        let mut organization = match state.get_organization(payload.get_organization_id()) {
+            Ok(Some(organization)) => Ok(organization),
+            Ok(None) => Err(ApplyError::InvalidTransaction(format!(
+                "No organization exists: {}",
+                payload.get_organization_id()
+            ))),
+            Err(err) => Err(err),
+        }?;
+
+        match organization.get_organization_type() {
+            proto::organization::Organization_Type::STANDARDS_BODY
+            | proto::organization::Organization_Type::CERTIFYING_BODY => Ok(()),
+            _ => Err(ApplyError::InvalidTransaction(format!(
+                "Organization {} does not require approval",
+                payload.get_organization_id()
+            ))),
+        }?;
+
+        organization.set_approval_status(payload.get_approval_status());
+
+        state.set_organization(payload.get_organization_id(), organization)?;
+
+        Ok(())
+    }
+
+    /// Creates an AccreditationRequest from the signer's Certifying Body for a Standard and
+    /// submits it to state
+    ///
+    /// ```
+    /// # Errors
+    /// Returns an error if
+    ///   - an accreditation request with the request id already exists
+    ///   - an Agent with the signer public key does not exist
+    ///   - the Organization the Agent is associated with is not a CertifyingBody
+    ///   - the CertifyingBody has not been approved by a registry operator
+    ///   - the Agent submitting the transaction lacks the MANAGE_REQUESTS permission
+    ///   - the standard provided in the payload does not exist
+    ///   - the standard has been retired
+    ///   - the request date drifts too far from the block time
+    ///   - it fails to submit the new AccreditationRequest to state.
+    /// ```
+    pub fn open_accreditation_request(
+        &self,
+        payload: &proto::payload::OpenAccreditationRequestAction,
+        state: &mut CertState,
+        signer_public_key: &str,
+    ) -> Result<(), ApplyError> {
+        // Validate that the signer is associated with a certifying body
+        let agent = match state.get_agent(signer_public_key) {
+            Ok(Some(agent)) => Ok(agent),
+            Ok(None) => Err(ApplyError::InvalidTransaction(format!(
+                "No agent exists: {}",
+                signer_public_key
+            ))),
+            Err(err) => Err(err),
+        }?;
+        let organization = match state.get_organization(agent.get_organization_id()) {
+            Ok(Some(organization)) => Ok(organization),
+            Ok(None) => Err(ApplyError::InvalidTransaction(format!(
+                "No organization exists: {}",
+                agent.get_organization_id()
+            ))),
+            Err(err) => Err(err),
+        }?;
+        if organization.get_organization_type()
+            != proto::organization::Organization_Type::CERTIFYING_BODY
+        {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Organization {} is not a certifying body",
+                agent.get_organization_id()
+            )));
+        }
+
+        if organization.get_approval_status()
+            != proto::organization::Organization_ApprovalStatus::APPROVED
+        {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Organization {} has not been approved by a registry operator",
+                agent.get_organization_id()
+            )));
+        }
+
+        // Validate agent is authorized
+        let block_time = state.get_block_timestamp()?;
+        if !is_authorized(
+            signer_public_key,
+            &organization,
+            proto::organization::Organization_Permission::MANAGE_REQUESTS,
+            block_time,
+        ) {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Agent {} is not authorized to open an accreditation request",
+                agent.get_public_key(),
+            )));
+        }
+
+        // Verify that the accreditation request does not already exist
+        match state.get_accreditation_request(payload.get_id()) {
+            Ok(Some(_)) => Err(ApplyError::InvalidTransaction(format!(
+                "Accreditation request already exists: {}",
+                payload.get_id()
+            ))),
+            Ok(None) => Ok(()),
+            Err(err) => Err(err),
+        }?;
+
+        // Validate that the standard exists, its creator reviews the request
+        let standard = match state.get_standard(payload.get_standard_id()) {
+            Ok(Some(standard)) => Ok(standard),
+            Ok(None) => Err(ApplyError::InvalidTransaction(format!(
+                "No standard with ID {} exists",
+                payload.get_standard_id()
+            ))),
+            Err(err) => Err(err),
+        }?;
+        validate_standard_active(&standard)?;
+
+        self.validate_timestamp(state, payload.get_request_date(), "Request date")?;
+
+        let mut request = proto::accreditation_request::AccreditationRequest::new();
+        request.set_id(payload.get_id().to_string());
+        request.set_status(proto::accreditation_request::AccreditationRequest_Status::OPEN);
+        request.set_certifying_body_id(agent.get_organization_id().to_string());
+        request.set_standard_id(payload.get_standard_id().to_string());
+        request.set_standards_body_id(standard.get_organization_id().to_string());
+        request.set_request_date(payload.get_request_date());
+
+        add_accreditation_request_status_changed_event(state, &request)?;
+
+        state.set_accreditation_request(payload.get_id(), request)?;
+
+        Ok(())
+    }
+
+    /// Moves an AccreditationRequest addressed to the signer's Standards Body into review, or
+    /// rejects it, and submits it to state
+    ///
+    /// ```
+    /// # Errors
+    /// Returns an error if
+    ///   - the accreditation request does not exist
+    ///   - an Agent with the signer public key does not exist
+    ///   - the Agent submitting the transaction is not associated with the standards body
+    ///     that reviews the request
+    ///   - the Agent submitting the transaction lacks the ACCREDIT permission
+    ///   - the current request status is APPROVED or REJECTED.
+    ///   - the request already has the requested status
+    ///   - it fails to submit the updated AccreditationRequest to state.
+    /// ```
+    pub fn change_accreditation_request_status(
+        &self,
+        payload: &proto::payload::ChangeAccreditationRequestStatusAction,
+        state: &mut CertState,
+        signer_public_key: &str,
+    ) -> Result<(), ApplyError> {
+        let mut request = self.get_accreditation_request_for_standards_body(
+            payload.get_request_id(),
+            state,
+            signer_public_key,
+        )?;
+
+        if request.get_status() == payload.get_status() {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Accreditation request {} is already {:?}",
+                payload.get_request_id(),
+                request.get_status()
+            )));
+        }
+
+        request.set_status(payload.get_status());
+        request.set_rejection_reason(payload.get_reason().to_string());
+
+        add_accreditation_request_status_changed_event(state, &request)?;
+
+        state.set_accreditation_request(payload.get_request_id(), request)?;
+
+        Ok(())
+    }
+
+    /// Approves an AccreditationRequest addressed to the signer's Standards Body that is in
+    /// review, accrediting the Certifying Body for the latest version of the Standard, and
+    /// submits it to state
+    ///
+    /// ```
+    /// # Errors
+    /// Returns an error if
+    ///   - the accreditation request does not exist
+    ///   - an Agent with the signer public key does not exist
+    ///   - the Agent submitting the transaction is not associated with the standards body
+    ///     that reviews the request
+    ///   - the Agent submitting the transaction lacks the ACCREDIT permission
+    ///   - the current request status is APPROVED or REJECTED.
+    ///   - the request has not been moved IN_REVIEW
+    ///   - the Certifying Body cannot be accredited for the Standard
+    ///   - it fails to submit the updated AccreditationRequest to state.
+    /// ```
+    pub fn approve_accreditation_request(
+        &self,
+        payload: &proto::payload::ApproveAccreditationRequestAction,
+        state: &mut CertState,
+        signer_public_key: &str,
+    ) -> Result<(), ApplyError> {
+        let mut request = self.get_accreditation_request_for_standards_body(
+            payload.get_request_id(),
+            state,
+            signer_public_key,
+        )?;
+
+        if request.get_status()
+            != proto::accreditation_request::AccreditationRequest_Status::IN_REVIEW
+        {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Only an IN_REVIEW accreditation request can be approved. Status: {:?}",
+                request.get_status()
+            )));
+        }
+
+        let mut accredit = proto::payload::AccreditCertifyingBodyAction::new();
+        accredit.set_certifying_body_id(request.get_certifying_body_id().to_string());
+        accredit.set_standard_id(request.get_standard_id().to_string());
+        accredit.set_valid_from(payload.get_valid_from());
+        accredit.set_valid_to(payload.get_valid_to());
+        self.accredit_certifying_body(&accredit, state, signer_public_key)?;
+
+        // The accreditation was granted for the latest version of the standard
+        let standard_version = match state.get_standard(request.get_standard_id())? {
+            Some(standard) => standard
+                .get_versions()
+                .last()
+                .map(|version| version.get_version().to_string())
+                .unwrap_or_default(),
+            None => String::new(),
+        };
+
+        request.set_status(proto::accreditation_request::AccreditationRequest_Status::APPROVED);
+        request.set_standard_version(standard_version);
+
+        add_accreditation_request_status_changed_event(state, &request)?;
+
+        state.set_accreditation_request(payload.get_request_id(), request)?;
+
+        Ok(())
+    }
+
+    /// Fetches an AccreditationRequest that the signer's Standards Body may still review,
+    /// validating that the signer holds the ACCREDIT permission within it
+    fn get_accreditation_request_for_standards_body(
+        &self,
+        request_id: &str,
+        state: &mut CertState,
+        signer_public_key: &str,
+    ) -> Result<proto::accreditation_request::AccreditationRequest, ApplyError> {
+        // Verify that the accreditation request does exist
+        let request = match state.get_accreditation_request(request_id) {
+            Ok(Some(request)) => Ok(request),
+            Ok(None) => Err(ApplyError::InvalidTransaction(format!(
+                "Accreditation request does not exist: {}",
+                request_id
+            ))),
+            Err(err) => Err(err),
+        }?;
+
+        // Validate that the signer is associated with the reviewing standards body
+        let agent = match state.get_agent(signer_public_key) {
+            Ok(Some(agent)) => Ok(agent),
+            Ok(None) => Err(ApplyError::InvalidTransaction(format!(
+                "No agent exists: {}",
+                signer_public_key
+            ))),
+            Err(err) => Err(err),
+        }?;
+
+        if agent.get_organization_id().is_empty()
+            || agent.get_organization_id() != request.get_standards_body_id()
+        {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Accreditation request {} is not addressed to the organization of agent {}",
+                request_id, signer_public_key
+            )));
+        }
+
+        let organization = match state.get_organization(agent.get_organization_id()) {
+            Ok(Some(organization)) => Ok(organization),
+            Ok(None) => Err(ApplyError::InvalidTransaction(format!(
+                "No organization exists: {}",
+                agent.get_organization_id()
+            ))),
+            Err(err) => Err(err),
+        }?;
+
+        // Validate agent is authorized
+        let block_time = state.get_block_timestamp()?;
+        if !is_authorized(
+            signer_public_key,
+            &organization,
+            proto::organization::Organization_Permission::ACCREDIT,
+            block_time,
+        ) {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Agent {} is not authorized to review accreditation request {}",
+                signer_public_key, request_id
+            )));
+        }
+
+        // Validate that the request is not in a finalized state
+        let status = request.get_status();
+        if status == proto::accreditation_request::AccreditationRequest_Status::APPROVED
+            || status == proto::accreditation_request::AccreditationRequest_Status::REJECTED
+        {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Once APPROVED or REJECTED, the accreditation request status can not be modified again. Status: {:?}",
+                status
+            )));
+        }
+
+        Ok(request)
+    }
+
+    /// Applies the transaction logic of a single action to state. Actions that require the
+    /// approval of the signer organization's ADMINs are rejected unless its admin quorum
+    /// lets a single ADMIN apply them.
+    ///
+    /// ```
+    /// # Errors
+    /// Returns an error if
+    ///   - the action must be proposed to the signer's organization
+    ///   - the action fails
+    /// ```
+    fn apply_action(
+        &self,
+        action: Action,
+        state: &mut CertState,
+        signer_public_key: &str,
+    ) -> Result<(), ApplyError> {
+        if requires_quorum(&action, signer_public_key) {
+            if let Some(organization) = get_signer_organization(state, signer_public_key)? {
+                if organization.get_admin_quorum() > 1 {
+                    return Err(ApplyError::InvalidTransaction(format!(
+                        "Organization {} requires {} ADMIN approvals for this action, it must be proposed",
+                        organization.get_id(),
+                        organization.get_admin_quorum()
+                    )));
+                }
+            }
+        }
+        self.apply_approved_action(action, state, signer_public_key)
+    }
+
+    /// Applies the transaction logic of a single action to state, without checking whether it
+    /// requires the approval of the signer organization's ADMINs
+    ///
+    /// ```
+    /// # Errors
+    /// Returns an error if the action fails
+    /// ```
+    fn apply_approved_action(
+        &self,
+        action: Action,
+        state: &mut CertState,
+        signer_public_key: &str,
+    ) -> Result<(), ApplyError> {
+        match action {
+            Action::CreateAgent(payload) => self.create_agent(&payload, state, signer_public_key),
+
+            Action::CreateOrganization(payload) => {
+                self.create_organization(&payload, state, signer_public_key)
+            }
+
+            Action::UpdateOrganization(payload) => {
+                self.update_organization(&payload, state, signer_public_key)
+            }
+
+            Action::AuthorizeAgent(payload) => {
+                self.authorize_agent(&payload, state, signer_public_key)
+            }
+
+            Action::RevokeAgentAuthorization(payload) => {
+                self.revoke_agent_authorization(&payload, state, signer_public_key)
+            }
+
+            Action::RotateAgentKey(payload) => {
+                self.rotate_agent_key(&payload, state, signer_public_key)
+            }
+
+            Action::UpdateRegistryOperators(payload) => {
+                self.update_registry_operators(&payload, state, signer_public_key)
+            }
+
+            Action::ReviewOrganization(payload) => {
+                self.review_organization(&payload, state, signer_public_key)
+            }
+
+            Action::AcceptRequest(payload) => {
+                self.accept_request(&payload, state, signer_public_key)
+            }
+
+            Action::DeclineRequest(payload) => {
+                self.decline_request(&payload, state, signer_public_key)
+            }
+            Action::RecordAudit(payload) => self.record_audit(&payload, state, signer_public_key),
+            Action::RaiseNonConformance(payload) => {
+                self.raise_non_conformance(&payload, state, signer_public_key)
+            }
+            Action::SubmitCorrectiveAction(payload) => {
+                self.submit_corrective_action(&payload, state, signer_public_key)
+            }
+            Action::CloseNonConformance(payload) => {
+                self.close_non_conformance(&payload, state, signer_public_key)
+            }
+
+            Action::IssueCertificate(payload) => {
+                self.issue_certificate(&payload, state, signer_public_key)
+            }
+            Action::CreateStandard(payload) => {
+                self.create_standard(&payload, state, signer_public_key)
+            }
+            Action::UpdateStandard(payload) => {
+                self.update_standard(&payload, state, signer_public_key)
+            }
+            Action::OpenRequest(payload) => self.open_request(&payload, state, signer_public_key),
+            Action::ChangeRequestStatus(payload) => {
+                self.change_request_status(&payload, state, signer_public_key)
+            }
+            Action::AccreditCertifyingBody(payload) => {
+                self.accredit_certifying_body(&payload, state, signer_public_key)
+            }
+            Action::RevokeCertificate(payload) => {
+                self.revoke_certificate(&payload, state, signer_public_key)
+            }
+            Action::SuspendCertificate(payload) => {
+                self.suspend_certificate(&payload, state, signer_public_key)
+            }
+            Action::ReinstateCertificate(payload) => {
+                self.reinstate_certificate(&payload, state, signer_public_key)
+            }
+            Action::RenewCertificate(payload) => {
+                self.renew_certificate(&payload, state, signer_public_key)
+            }
+            Action::RevokeAccreditation(payload) => {
+                self.revoke_accreditation(&payload, state, signer_public_key)
+            }
+            Action::DeclareEquivalence(payload) => {
+                self.declare_equivalence(&payload, state, signer_public_key)
+            }
+            Action::AddSupplier(payload) => self.add_supplier(&payload, state, signer_public_key),
+            Action::RemoveSupplier(payload) => {
+                self.remove_supplier(&payload, state, signer_public_key)
+            }
+            Action::DefineRole(payload) => self.define_role(&payload, state, signer_public_key),
+            Action::SetAdminQuorum(payload) => {
+                self.set_admin_quorum(&payload, state, signer_public_key)
+            }
+            Action::ProposeChange(payload) => {
+                self.propose_change(&payload, state, signer_public_key)
+            }
+            Action::VoteOnProposal(payload) => {
+                self.vote_on_proposal(&payload, state, signer_public_key)
+            }
+            Action::WithdrawProposal(payload) => {
+                self.withdraw_proposal(&payload, state, signer_public_key)
+            }
+            Action::OpenAccreditationRequest(payload) => {
+                self.open_accreditation_request(&payload, state, signer_public_key)
+            }
+            Action::ChangeAccreditationRequestStatus(payload) => {
+                self.change_accreditation_request_status(&payload, state, signer_public_key)
+            }
+            Action::ApproveAccreditationRequest(payload) => {
+                self.approve_accreditation_request(&payload, state, signer_public_key)
+            }
+            Action::RetireStandardVersion(payload) => {
+                self.retire_standard_version(&payload, state, signer_public_key)
+            }
+            Action::RetireStandard(payload) => {
+                self.retire_standard(&payload, state, signer_public_key)
+            }
+            Action::BatchActions(actions) => self.batch_actions(actions, state, signer_public_key),
+        }
+    }
+
+    /// Applies the actions of a BATCH_ACTIONS payload in order against the same state, so
+    /// later actions see the changes of earlier ones. The transaction is rejected, and none
+    /// of the changes are kept, if any of the actions fails.
+    ///
+    /// ```
+    /// # Errors
+    /// Returns an error if any of the actions fails
+    /// ```
+    fn batch_actions(
+        &self,
+        actions: Vec<Action>,
+        state: &mut CertState,
+        signer_public_key: &str,
+    ) -> Result<(), ApplyError> {
+        for (index, action) in actions.into_iter().enumerate() {
+            self.apply_action(action, state, signer_public_key)
+                .map_err(|err| match err {
+                    ApplyError::InvalidTransaction(msg) => ApplyError::InvalidTransaction(format!(
+                        "Batch action {} failed: {}",
+                        index, msg
+                    )),
+                    err => err,
+                })?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns whether the accreditation is current at the given time
+fn is_current_at(
+    accreditation: &proto::organization::CertifyingBody_Accreditation,
+    time: u64,
+) -> bool {
+    accreditation.get_valid_from() <= time && time <= accreditation.get_valid_to()
+}
+
+/// Returns whether the accreditation is current both when a certificate becomes valid and
+/// at the block time
+fn covers_issuance(
+    accreditation: &proto::organization::CertifyingBody_Accreditation,
+    valid_from: u64,
+    block_time: u64,
+) -> bool {
+    is_current_at(accreditation, valid_from) && is_current_at(accreditation, block_time)
+}
+
+/// Returns whether the authorization is current at the block time. An unset valid_from or
+/// valid_to leaves that end of the window open.
+fn is_authorization_current_at(
+    authorization: &proto::organization::Organization_Authorization,
+    block_time: u64,
+) -> bool {
+    authorization.get_valid_from() <= block_time
+        && (authorization.get_valid_to() == 0 || block_time <= authorization.get_valid_to())
+}
+
+/// Returns whether the agent holds the permission within the organization through one of its
+/// current authorizations. ADMIN grants MANAGE_ORGANIZATION, TRANSACTOR grants every other
+/// permission and CUSTOM grants the permissions of the organization's role definition.
+fn is_authorized(
+    public_key: &str,
+    organization: &proto::organization::Organization,
+    permission: proto::organization::Organization_Permission,
+    block_time: u64,
+) -> bool {
+    organization
+        .get_authorizations()
+        .iter()
+        .filter(|authorization| {
+            authorization.get_public_key() == public_key
+                && is_authorization_current_at(authorization, block_time)
+        })
+        .any(|authorization| match authorization.get_role() {
+            proto::organization::Organization_Authorization_Role::ADMIN => {
+                permission == proto::organization::Organization_Permission::MANAGE_ORGANIZATION
+            }
+            proto::organization::Organization_Authorization_Role::TRANSACTOR => {
+                permission != proto::organization::Organization_Permission::MANAGE_ORGANIZATION
+                    && permission != proto::organization::Organization_Permission::UNSET_PERMISSION
+            }
+            proto::organization::Organization_Authorization_Role::CUSTOM => organization
+                .get_roles()
+                .iter()
+                .find(|role| role.get_name() == authorization.get_role_name())
+                .map_or(false, |role| role.get_permissions().contains(&permission)),
+            proto::organization::Organization_Authorization_Role::UNSET_ROLE => false,
+        })
+}
+
+/// Returns the public keys holding the MANAGE_ORGANIZATION permission within the organization
+/// at the block time. These are the ADMINs that vote on the organization's proposals.
+fn get_admin_keys(
+    organization: &proto::organization::Organization,
+    block_time: u64,
+) -> HashSet<String> {
+    organization
+        .get_authorizations()
+        .iter()
+        .map(|authorization| authorization.get_public_key())
+        .filter(|public_key| {
+            is_authorized(
+                public_key,
+                organization,
+                proto::organization::Organization_Permission::MANAGE_ORGANIZATION,
+                block_time,
+            )
+        })
+        .map(|public_key| public_key.to_string())
+        .collect()
+}
+
+/// Returns whether the action may need the approval of the signer organization's ADMINs.
+/// Authorizing ADMIN or CUSTOM roles is sensitive as either can grant MANAGE_ORGANIZATION,
+/// as is rotating the key of an Agent other than the signer.
+fn requires_quorum(action: &Action, signer_public_key: &str) -> bool {
+    match action {
+        Action::UpdateOrganization(_)
+        | Action::RevokeAgentAuthorization(_)
+        | Action::DefineRole(_)
+        | Action::SetAdminQuorum(_) => true,
+        Action::AuthorizeAgent(payload) => {
+            payload.get_role() != proto::organization::Organization_Authorization_Role::TRANSACTOR
+        }
+        Action::RotateAgentKey(payload) => payload.get_public_key() != signer_public_key,
+        _ => false,
+    }
+}
+
+/// Submits a Proposal that has been accepted, rejected or withdrawn to state and removes it
+/// from its Organization's open proposals
+fn close_proposal(
+    state: &mut CertState,
+    proposal: proto::proposal::Proposal,
+) -> Result<(), ApplyError> {
+    let mut organization = match state.get_organization(proposal.get_organization_id()) {
+        Ok(Some(organization)) => Ok(organization),
+        Ok(None) => Err(ApplyError::InvalidTransaction(format!(
+            "No organization exists: {}",
+            proposal.get_organization_id()
+        ))),
+        Err(err) => Err(err),
+    }?;
+    organization
+        .mut_open_proposal_ids()
+        .retain(|proposal_id| proposal_id != proposal.get_id());
+
+    let organization_id = organization.get_id().to_string();
+    state.set_organization(&organization_id, organization)?;
+    let proposal_id = proposal.get_id().to_string();
+    state.set_proposal(&proposal_id, proposal)
+}
+
+/// Fetches the Organization the signer is associated with, if any
+fn get_signer_organization(
+    state: &mut CertState,
+    signer_public_key: &str,
+) -> Result<Option<proto::organization::Organization>, ApplyError> {
+    match state.get_agent(signer_public_key)? {
+        Some(ref agent) if !agent.get_organization_id().is_empty() => {
+            state.get_organization(agent.get_organization_id())
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Fetches the Retailer the signer holds the MANAGE_SUPPLIERS permission within
+fn get_signer_retailer(
+    state: &mut CertState,
+    signer_public_key: &str,
+) -> Result<proto::organization::Organization, ApplyError> {
+    let agent = match state.get_agent(signer_public_key) {
+        Ok(Some(agent)) => Ok(agent),
+        Ok(None) => Err(ApplyError::InvalidTransaction(format!(
+            "Agent does not exist: {}",
+            signer_public_key
+        ))),
+        Err(err) => Err(err),
+    }?;
+
+    if agent.get_organization_id().is_empty() {
+        return Err(ApplyError::InvalidTransaction(format!(
+            "Agent is not associated with an organization: {}",
+            signer_public_key
+        )));
+    }
+
+    let retailer = match state.get_organization(agent.get_organization_id()) {
+        Ok(Some(organization)) => Ok(organization),
+        Ok(None) => Err(ApplyError::InvalidTransaction(format!(
+            "No organization exists: {}",
+            agent.get_organization_id()
+        ))),
+        Err(err) => Err(err),
+    }?;
+
+    if retailer.get_organization_type() != proto::organization::Organization_Type::RETAILER {
+        return Err(ApplyError::InvalidTransaction(format!(
+            "Organization {} is not a retailer",
+            agent.get_organization_id()
+        )));
+    }
+
+    let block_time = state.get_block_timestamp()?;
+    if !is_authorized(
+        signer_public_key,
+        &retailer,
+        proto::organization::Organization_Permission::MANAGE_SUPPLIERS,
+        block_time,
+    ) {
+        return Err(ApplyError::InvalidTransaction(format!(
+            "Agent {} is not authorized to manage the suppliers of retailer {}",
+            signer_public_key,
+            agent.get_organization_id()
+        )));
+    }
+
+    Ok(retailer)
+}
+
+/// Rejects a Standard that has been withdrawn. Standards created before standards had a
+/// status are UNSET and treated as active.
+fn validate_standard_active(standard: &proto::standard::Standard) -> Result<(), ApplyError> {
+    if standard.get_status() == proto::standard::Standard_Status::RETIRED {
+        return Err(ApplyError::InvalidTransaction(format!(
+            "Standard {} has been retired",
+            standard.get_id()
+        )));
+    }
+    Ok(())
+}
+
+/// Returns whether a version of a Standard has been retired at the given time
+fn is_version_retired_at(version: &proto::standard::Standard_StandardVersion, time: u64) -> bool {
+    version.get_retirement_date() != 0 && time >= version.get_retirement_date()
+}
+
+/// Validates that a certificate or accreditation for a version of a Standard expires by the
+/// version's retirement date, if it has one
+fn validate_expires_by_retirement(
+    standard_id: &str,
+    version: &proto::standard::Standard_StandardVersion,
+    valid_to: u64,
+) -> Result<(), ApplyError> {
+    let retirement_date = version.get_retirement_date();
+    if retirement_date != 0 && valid_to > retirement_date {
+        return Err(ApplyError::InvalidTransaction(format!(
+            "Version {} of Standard {} retires at {}. Valid to {} must not be after it",
+            version.get_version(),
+            standard_id,
+            retirement_date,
+            valid_to
+        )));
+    }
+    Ok(())
+}
+
+/// Fetches a Standard from state, checking that it has the given version
+fn get_standard_with_version(
+    state: &mut CertState,
+    standard_id: &str,
+    standard_version: &str,
+) -> Result<proto::standard::Standard, ApplyError> {
+    let standard = match state.get_standard(standard_id) {
+        Ok(Some(standard)) => Ok(standard),
+        Ok(None) => Err(ApplyError::InvalidTransaction(format!(
+            "No standard with ID {} exists",
+            standard_id
+        ))),
+        Err(err) => Err(err),
+    }?;
+
+    if standard
+        .get_versions()
+        .iter()
+        .find(|version| version.get_version() == standard_version)
+        .is_none()
+    {
+        return Err(ApplyError::InvalidTransaction(format!(
+            "Version {} does not exist for Standard {}",
+            standard_version, standard_id
+        )));
+    }
+
+    Ok(standard)
+}
+
+/// Adds an equivalence to a Standard. Returns false if the Standard already records it.
+fn add_equivalence(
+    standard: &mut proto::standard::Standard,
+    version: &str,
+    equivalent_standard_id: &str,
+    equivalent_standard_version: &str,
+) -> bool {
+    if standard.get_equivalences().iter().any(|equivalence| {
+        equivalence.get_version() == version
+            && equivalence.get_equivalent_standard_id() == equivalent_standard_id
+            && equivalence.get_equivalent_standard_version() == equivalent_standard_version
+    }) {
+        return false;
+    }
+
+    let mut equivalence = proto::standard::Standard_Equivalence::new();
+    equivalence.set_version(version.to_string());
+    equivalence.set_equivalent_standard_id(equivalent_standard_id.to_string());
+    equivalence.set_equivalent_standard_version(equivalent_standard_version.to_string());
+    standard.mut_equivalences().push(equivalence);
+    true
+}
+
+/// Validates certificate data against the data schema of the standard version the certificate
+/// is issued against. Versions without a schema accept free-form certificate data.
+fn validate_certificate_data(
+    standard_version: &proto::standard::Standard_StandardVersion,
+    certificate_data: &[proto::certificate::Certificate_CertificateData],
+) -> Result<(), ApplyError> {
+    let data_schema = standard_version.get_data_schema();
+    if data_schema.is_empty() {
+        return Ok(());
+    }
+
+    let mut provided_fields = HashSet::new();
+    for data in certificate_data {
+        let field = match data_schema
+            .iter()
+            .find(|field| field.get_name() == data.get_field())
+        {
+            Some(field) => Ok(field),
+            None => Err(ApplyError::InvalidTransaction(format!(
+                "Field {} is not part of the data schema of version {}",
+                data.get_field(),
+                standard_version.get_version()
+            ))),
+        }?;
+
+        if !provided_fields.insert(data.get_field()) {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Field {} is set more than once",
+                data.get_field()
+            )));
+        }
+
+        let value = data.get_data();
+        let is_valid_type = match field.get_data_type() {
+            proto::standard::Standard_DataField_Type::INTEGER => value.parse::<i64>().is_ok(),
+            proto::standard::Standard_DataField_Type::DECIMAL => value
+                .parse::<f64>()
+                .map(|value| value.is_finite())
+                .unwrap_or(false),
+            proto::standard::Standard_DataField_Type::BOOLEAN => {
+                value == "true" || value == "false"
+            }
+            proto::standard::Standard_DataField_Type::STRING
+            | proto::standard::Standard_DataField_Type::ENUMERATION
+            | proto::standard::Standard_DataField_Type::UNSET_TYPE => true,
+        };
+        if !is_valid_type {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Field {} must be of type {:?}: {}",
+                field.get_name(),
+                field.get_data_type(),
+                value
+            )));
+        }
+
+        if !field.get_allowed_values().is_empty()
+            && !field
+                .get_allowed_values()
+                .iter()
+                .any(|allowed_value| allowed_value == value)
+        {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Field {} must be one of {}: {}",
+                field.get_name(),
+                field.get_allowed_values().join(", "),
+                value
+            )));
+        }
+
+        if !field.get_pattern().is_empty()
+            && !compile_data_pattern(field.get_pattern())?.is_match(value)
+        {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Field {} does not match pattern {}: {}",
+                field.get_name(),
+                field.get_pattern(),
+                value
+            )));
+        }
+    }
+
+    if let Some(field) = data_schema
+        .iter()
+        .find(|field| field.get_required() && !provided_fields.contains(field.get_name()))
+    {
+        return Err(ApplyError::InvalidTransaction(format!(
+            "Required field {} is missing from the certificate data",
+            field.get_name()
+        )));
+    }
+
+    Ok(())
+}
+
+/// Emits an event carrying the identifiers and status of a Certificate
+fn add_certificate_event(
+    state: &mut CertState,
+    event_type: &str,
+    certificate: &proto::certificate::Certificate,
+) -> Result<(), ApplyError> {
+    let status = format!("{:?}", certificate.get_status());
+    state.add_event(
+        event_type,
+        &[
+            ("certificate_id", certificate.get_id()),
+            ("certifying_body_id", certificate.get_certifying_body_id()),
+            ("factory_id", certificate.get_factory_id()),
+            ("standard_id", certificate.get_standard_id()),
+            ("standard_version", certificate.get_standard_version()),
+            ("status", status.as_str()),
+        ],
+    )
+}
+
+/// Emits a request-status-changed event carrying the identifiers and new status of a Request
+fn add_request_status_changed_event(
+    state: &mut CertState,
+    request: &proto::request::Request,
+) -> Result<(), ApplyError> {
+    let status = format!("{:?}", request.get_status());
+    state.add_event(
+        events::REQUEST_STATUS_CHANGED,
+        &[
+            ("request_id", request.get_id()),
+            ("factory_id", request.get_factory_id()),
+            ("certifying_body_id", request.get_certifying_body_id()),
+            ("standard_id", request.get_standard_id()),
+            ("status", status.as_str()),
+        ],
+    )
+}
+
+/// Emits an accreditation-request-status-changed event carrying the identifiers and new
+/// status of an AccreditationRequest
+fn add_accreditation_request_status_changed_event(
+    state: &mut CertState,
+    request: &proto::accreditation_request::AccreditationRequest,
+) -> Result<(), ApplyError> {
+    let status = format!("{:?}", request.get_status());
+    state.add_event(
+        events::ACCREDITATION_REQUEST_STATUS_CHANGED,
+        &[
+            ("request_id", request.get_id()),
+            ("certifying_body_id", request.get_certifying_body_id()),
+            ("standards_body_id", request.get_standards_body_id()),
+            ("standard_id", request.get_standard_id()),
+            ("status", status.as_str()),
+        ],
+    )
+}
+
+/// Emits an event carrying the identifiers of a Certifying Body's accreditation
+fn add_accreditation_event(
+    state: &mut CertState,
+    event_type: &str,
+    certifying_body_id: &str,
+    accreditation: &proto::organization::CertifyingBody_Accreditation,
+) -> Result<(), ApplyError> {
+    state.add_event(
+        event_type,
+        &[
+            ("certifying_body_id", certifying_body_id),
+            ("standards_body_id", accreditation.get_accreditor_id()),
+            ("standard_id", accreditation.get_standard_id()),
+            ("standard_version", accreditation.get_standard_version()),
+        ],
+    )
+}
+
+impl TransactionHandler for CertTransactionHandler {
+    fn family_name(&self) -> String {
+        self.family_name.clone()
+    }
+
+    fn family_versions(&self) -> Vec<String> {
+        self.family_versions.clone()
+    }
+
+    fn namespaces(&self) -> Vec<String> {
+        self.namespaces.clone()
+    }
+
+    /// Applies the correct transaction logic depending on the payload action type.
+    /// It will use helper methods to perform all payload validation that requires
+    /// fetching data from state. If the payload is valid it will apply the changes
+    /// to state.
+    ///
+    /// ```
+    /// # Errors
+    /// Returns an error if the transaction fails
+    /// ```
+    fn apply(
+        &self,
+        request: &TpProcessRequest,
+        context: &mut TransactionContext,
+    ) -> Result<(), ApplyError> {
+        let header = request.get_header();
+        let signer_public_key = header.get_signer_public_key();
+
+        // Return an action enum as the payload
+        let payload = CertPayload::new(get_family_version(request), request.get_payload())?;
+        let mut state = CertState::new(context);
+
+        self.apply_action(payload.get_action(), &mut state, signer_public_key)
+    }
+}
+
+/// Returns the family version the transaction was submitted under
+#[cfg(not(target_arch = "wasm32"))]
+fn get_family_version(request: &TpProcessRequest) -> &str {
+    request.get_header().get_family_version()
+}
+
+/// Sabre does not expose the family version of the transaction header to smart contracts. A
+/// contract is registered under a single version, see cert_registry.yaml, so every payload is
+/// decoded as the current family version.
+#[cfg(target_arch = "wasm32")]
+fn get_family_version(_request: &TpProcessRequest) -> &str {
+    addressing::FAMILY_VERSION
+}
+
+#[cfg(target_arch = "wasm32")]
+
+// If the TP will be compiled to WASM to be run as a smart contract in Sabre this apply method will be
+// used as wrapper for the handler apply method. For Sabre the apply must return a boolean
+fn apply(request: &TpProcessRequest, context: &mut TransactionContext) -> Result<bool, ApplyError> {
+    let handler = CertTransactionHandler::new();
+    match handler.apply(request, context) {
+        Ok(_) => Ok(true),
+        Err(err) => Err(err),
+    }
+}
+
+#[allow(dead_code, private_no_mangle_fns)]
+#[cfg(target_arch = "wasm32")]
+#[no_mangle]
+pub unsafe fn entrypoint(payload: WasmPtr, signer: WasmPtr, signature: WasmPtr) -> i32 {
+    execute_entrypoint(payload, signer, signature, apply)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use common::proto::organization::Organization_Authorization_Role::{ADMIN, CUSTOM, TRANSACTOR};
+    use common::proto::organization::Organization_Permission::{
+        ISSUE_CERTIFICATE, MANAGE_ORGANIZATION, MANAGE_REQUESTS,
+    };
+    use common::proto::organization::Organization_Type::{
+        CERTIFYING_BODY, FACTORY, RETAILER, STANDARDS_BODY,
+    };
+    use common::proto::standard::Standard_DataField_Type;
+    use testing::*;
+
+    const OPERATOR_KEY: &str = "operator_agent";
+    const STANDARDS_BODY_KEY: &str = "standards_body_agent";
+    const OTHER_STANDARDS_BODY_KEY: &str = "other_standards_body_agent";
+    const CERTIFYING_BODY_KEY: &str = "certifying_body_agent";
+    const FACTORY_KEY: &str = "factory_agent";
+    const FACTORY_TRANSACTOR_KEY: &str = "factory_transactor_agent";
+    const RETAILER_KEY: &str = "retailer_agent";
+    const SECOND_FACTORY_ADMIN_KEY: &str = "second_factory_admin_agent";
+    const THIRD_FACTORY_ADMIN_KEY: &str = "third_factory_admin_agent";
+    const UNAFFILIATED_KEY: &str = "unaffiliated_agent";
+    const ORPHAN_KEY: &str = "orphan_agent";
+    const UNKNOWN_KEY: &str = "unknown_agent";
+
+    const STANDARDS_BODY_ID: &str = "standards_body";
+    const OTHER_STANDARDS_BODY_ID: &str = "other_standards_body";
+    const CERTIFYING_BODY_ID: &str = "certifying_body";
+    const FACTORY_ID: &str = "factory";
+    const RETAILER_ID: &str = "retailer";
+    const MISSING_ORGANIZATION_ID: &str = "missing_organization";
+    const STANDARD_ID: &str = "standard";
+    const OTHER_STANDARD_ID: &str = "other_standard";
+    const CERTIFICATE_ID: &str = "certificate";
+    const REQUEST_ID: &str = "request";
+    const AUDIT_ID: &str = "audit";
+    const NON_CONFORMANCE_ID: &str = "non_conformance";
+    const PROPOSAL_ID: &str = "proposal";
+    const ACCREDITATION_REQUEST_ID: &str = "accreditation_request";
+
+    const STANDARD_APPROVAL_DATE: u64 = 100;
+    const ACCREDITED_FROM: u64 = 100;
+    const ACCREDITED_TO: u64 = 10_000;
+    const VALID_FROM: u64 = 1_000;
+    const VALID_TO: u64 = 5_000;
+
+    /*
+     * Fixtures
+     */
+
+    /// State with a registry operator, an approved standards body that created version 1.0
+    /// of a standard, an approved certifying body accredited for that version and a factory.
+    /// The orphan agent references an organization that does not exist. BlockInfo records
+    /// VALID_FROM as the block time.
+    fn base_state() -> StateBuilder {
+        base_state_without_block_info().with_block_time(VALID_FROM)
+    }
+
+    /// The base state before BlockInfo has recorded any block
+    fn base_state_without_block_info() -> StateBuilder {
+        StateBuilder::new()
+            .with_registry(&[OPERATOR_KEY])
+            .with_agent(agent(STANDARDS_BODY_KEY, STANDARDS_BODY_ID))
+            .with_agent(agent(CERTIFYING_BODY_KEY, CERTIFYING_BODY_ID))
+            .with_agent(agent(FACTORY_KEY, FACTORY_ID))
+            .with_agent(agent(FACTORY_TRANSACTOR_KEY, FACTORY_ID))
+            .with_agent(agent(UNAFFILIATED_KEY, ""))
+            .with_agent(agent(ORPHAN_KEY, MISSING_ORGANIZATION_ID))
+            .with_organization(organization(
+                STANDARDS_BODY_ID,
+                STANDARDS_BODY,
+                &[
+                    (STANDARDS_BODY_KEY, ADMIN),
+                    (STANDARDS_BODY_KEY, TRANSACTOR),
+                ],
+            ))
+            .with_organization(certifying_body(&[
+                (CERTIFYING_BODY_KEY, ADMIN),
+                (CERTIFYING_BODY_KEY, TRANSACTOR),
+            ]))
+            .with_organization(organization(
+                FACTORY_ID,
+                FACTORY,
+                &[
+                    (FACTORY_KEY, ADMIN),
+                    (FACTORY_KEY, TRANSACTOR),
+                    (FACTORY_TRANSACTOR_KEY, TRANSACTOR),
+                ],
+            ))
+            .with_standard(standard(
+                STANDARD_ID,
+                STANDARDS_BODY_ID,
+                &[("1.0", STANDARD_APPROVAL_DATE)],
+            ))
+    }
+
+    /// The certifying body of the base state, accredited for version 1.0 of the standard
+    fn certifying_body(
+        authorizations: &[(&str, proto::organization::Organization_Authorization_Role)],
+    ) -> proto::organization::Organization {
+        let mut certifying_body = organization(CERTIFYING_BODY_ID, CERTIFYING_BODY, authorizations);
+        certifying_body
+            .mut_certifying_body_details()
+            .mut_accreditations()
+            .push(accreditation(
+                STANDARD_ID,
+                "1.0",
+                STANDARDS_BODY_ID,
+                ACCREDITED_FROM,
+                ACCREDITED_TO,
+            ));
+        certifying_body
+    }
+
+    /// The standard of the base state, withdrawn by its standards body
+    fn retired_standard() -> proto::standard::Standard {
+        let mut standard = standard(
+            STANDARD_ID,
+            STANDARDS_BODY_ID,
+            &[("1.0", STANDARD_APPROVAL_DATE)],
+        );
+        standard.set_status(proto::standard::Standard_Status::RETIRED);
+        standard
+    }
+
+    /// The standard of the base state, with version 1.0 retiring at `retirement_date`
+    fn retiring_standard(retirement_date: u64) -> proto::standard::Standard {
+        let mut standard = standard(
+            STANDARD_ID,
+            STANDARDS_BODY_ID,
+            &[("1.0", STANDARD_APPROVAL_DATE)],
+        );
+        standard.mut_versions()[0].set_retirement_date(retirement_date);
+        standard
+    }
+
+    fn pending(
+        mut organization: proto::organization::Organization,
+    ) -> proto::organization::Organization {
+        organization.set_approval_status(proto::organization::Organization_ApprovalStatus::PENDING);
+        organization
+    }
+
+    fn limited(
+        mut organization: proto::organization::Organization,
+        valid_from: u64,
+        valid_to: u64,
+    ) -> proto::organization::Organization {
+        for authorization in organization.mut_authorizations().iter_mut() {
+            authorization.set_valid_from(valid_from);
+            authorization.set_valid_to(valid_to);
+        }
+        organization
+    }
+
+    /// Defines the role within the organization and grants it to `public_key`
+    fn with_custom_role(
+        mut organization: proto::organization::Organization,
+        public_key: &str,
+        role: proto::organization::Organization_RoleDefinition,
+    ) -> proto::organization::Organization {
+        let mut authorization = proto::organization::Organization_Authorization::new();
+        authorization.set_public_key(public_key.to_string());
+        authorization.set_role(CUSTOM);
+        authorization.set_role_name(role.get_name().to_string());
+        organization.mut_authorizations().push(authorization);
+        organization.mut_roles().push(role);
+        organization
+    }
+
+    /// Base state where the factory has three ADMINs, two of which must approve sensitive
+    /// changes to the factory
+    fn quorum_state() -> StateBuilder {
+        let mut factory = organization(
+            FACTORY_ID,
+            FACTORY,
+            &[
+                (FACTORY_KEY, ADMIN),
+                (FACTORY_KEY, TRANSACTOR),
+                (FACTORY_TRANSACTOR_KEY, TRANSACTOR),
+                (SECOND_FACTORY_ADMIN_KEY, ADMIN),
+                (THIRD_FACTORY_ADMIN_KEY, ADMIN),
+            ],
+        );
+        factory.set_admin_quorum(2);
+        base_state()
+            .with_agent(agent(SECOND_FACTORY_ADMIN_KEY, FACTORY_ID))
+            .with_agent(agent(THIRD_FACTORY_ADMIN_KEY, FACTORY_ID))
+            .with_organization(factory)
+    }
+
+    /// An open proposal by the factory ADMIN, who accepted it
+    fn open_proposal(
+        payload: proto::payload::CertificateRegistryPayload,
+    ) -> proto::proposal::Proposal {
+        let mut vote = proto::proposal::Proposal_Vote::new();
+        vote.set_public_key(FACTORY_KEY.to_string());
+        vote.set_accept(true);
+
+        let mut proposal = proto::proposal::Proposal::new();
+        proposal.set_id(PROPOSAL_ID.to_string());
+        proposal.set_organization_id(FACTORY_ID.to_string());
+        proposal.set_proposer(FACTORY_KEY.to_string());
+        proposal.set_payload(payload);
+        proposal.set_required_approvals(2);
+        proposal.set_status(proto::proposal::Proposal_Status::OPEN);
+        proposal.mut_votes().push(vote);
+        proposal
+    }
+
+    /// Quorum state with the proposal recorded among the factory's open proposals
+    fn proposal_state(proposal: proto::proposal::Proposal) -> StateBuilder {
+        let mut factory = get_organization(&mut quorum_state().build(), FACTORY_ID);
+        factory
+            .mut_open_proposal_ids()
+            .push(proposal.get_id().to_string());
+        quorum_state()
+            .with_organization(factory)
+            .with_proposal(proposal)
+    }
+
+    fn rotated(mut agent: proto::agent::Agent) -> proto::agent::Agent {
+        agent.set_successor_public_key("successor_agent".to_string());
+        agent
+    }
+
+    fn issued_certificate() -> proto::certificate::Certificate {
+        certificate(
+            CERTIFICATE_ID,
+            CERTIFYING_BODY_ID,
+            FACTORY_ID,
+            STANDARD_ID,
+            "1.0",
+            VALID_FROM,
+            VALID_TO,
+        )
+    }
+
+    fn suspended_certificate() -> proto::certificate::Certificate {
+        let mut certificate = issued_certificate();
+        let mut suspension = proto::certificate::Certificate_Suspension::new();
+        suspension.set_suspended_at(2_000);
+        certificate.mut_suspensions().push(suspension);
+        certificate.set_status(proto::certificate::Certificate_Status::SUSPENDED);
+        certificate
+    }
+
+    fn factory_request(status: proto::request::Request_Status) -> proto::request::Request {
+        request(
+            REQUEST_ID,
+            FACTORY_ID,
+            CERTIFYING_BODY_ID,
+            STANDARD_ID,
+            status,
+        )
+    }
+
+    /// A request opened under family version 0.1, which is not addressed to a certifying body
+    fn legacy_request(status: proto::request::Request_Status) -> proto::request::Request {
+        let mut request = factory_request(status);
+        request.clear_certifying_body_id();
+        request
+    }
+
+    fn certifying_body_accreditation_request(
+        status: proto::accreditation_request::AccreditationRequest_Status,
+    ) -> proto::accreditation_request::AccreditationRequest {
+        accreditation_request(
+            ACCREDITATION_REQUEST_ID,
+            CERTIFYING_BODY_ID,
+            STANDARDS_BODY_ID,
+            STANDARD_ID,
+            status,
+        )
+    }
+
+    fn raised_non_conformance(
+        status: proto::non_conformance::NonConformance_Status,
+    ) -> proto::non_conformance::NonConformance {
+        non_conformance(NON_CONFORMANCE_ID, FACTORY_ID, CERTIFYING_BODY_ID, status)
+    }
+
+    fn document_reference() -> proto::document::DocumentReference {
+        let mut document = proto::document::DocumentReference::new();
+        document.set_sha256(
+            "9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08".to_string(),
+        );
+        document.set_media_type("application/pdf".to_string());
+        document.set_title("Audit report".to_string());
+        document
+    }
+
+    /// Base state with a retailer that has no suppliers yet
+    fn retailer_state() -> StateBuilder {
+        base_state()
+            .with_agent(agent(RETAILER_KEY, RETAILER_ID))
+            .with_organization(organization(
+                RETAILER_ID,
+                RETAILER,
+                &[(RETAILER_KEY, ADMIN), (RETAILER_KEY, TRANSACTOR)],
+            ))
+    }
+
+    fn retailer_with_supplier() -> proto::organization::Organization {
+        let mut retailer = organization(
+            RETAILER_ID,
+            RETAILER,
+            &[(RETAILER_KEY, ADMIN), (RETAILER_KEY, TRANSACTOR)],
+        );
+        retailer
+            .mut_retailer_details()
+            .mut_supplier_ids()
+            .push(FACTORY_ID.to_string());
+        retailer
+    }
+
+    /// Base state with a second standards body that created version 1.0 of another standard
+    fn other_standard_state() -> StateBuilder {
+        base_state()
+            .with_agent(agent(OTHER_STANDARDS_BODY_KEY, OTHER_STANDARDS_BODY_ID))
+            .with_organization(organization(
+                OTHER_STANDARDS_BODY_ID,
+                STANDARDS_BODY,
+                &[(OTHER_STANDARDS_BODY_KEY, TRANSACTOR)],
+            ))
+            .with_standard(standard(
+                OTHER_STANDARD_ID,
+                OTHER_STANDARDS_BODY_ID,
+                &[("1.0", STANDARD_APPROVAL_DATE)],
+            ))
+    }
+
+    fn data_field(
+        name: &str,
+        data_type: proto::standard::Standard_DataField_Type,
+        required: bool,
+    ) -> proto::standard::Standard_DataField {
+        let mut field = proto::standard::Standard_DataField::new();
+        field.set_name(name.to_string());
+        field.set_data_type(data_type);
+        field.set_required(required);
+        field
+    }
+
+    /// A data schema with a required integer, an optional enumeration and an optional
+    /// patterned string
+    fn data_schema() -> Vec<proto::standard::Standard_DataField> {
+        let mut grade = data_field("grade", Standard_DataField_Type::ENUMERATION, false);
+        grade.set_allowed_values(protobuf::RepeatedField::from_vec(vec![
+            "A".to_string(),
+            "B".to_string(),
+        ]));
+        let mut lot = data_field("lot", Standard_DataField_Type::STRING, false);
+        lot.set_pattern("[A-Z]{2}-[0-9]+".to_string());
+        vec![
+            data_field("workers", Standard_DataField_Type::INTEGER, true),
+            grade,
+            lot,
+        ]
+    }
+
+    /// Base state where version 1.0 of the standard carries `data_schema`
+    fn schema_state() -> StateBuilder {
+        let mut standard = standard(
+            STANDARD_ID,
+            STANDARDS_BODY_ID,
+            &[("1.0", STANDARD_APPROVAL_DATE)],
+        );
+        standard.mut_versions()[0]
+            .set_data_schema(protobuf::RepeatedField::from_vec(data_schema()));
+        base_state().with_standard(standard)
+    }
+
+    fn certificate_data(
+        data: &[(&str, &str)],
+    ) -> protobuf::RepeatedField<proto::certificate::Certificate_CertificateData> {
+        data.iter()
+            .map(|&(field, value)| {
+                let mut certificate_data = proto::certificate::Certificate_CertificateData::new();
+                certificate_data.set_field(field.to_string());
+                certificate_data.set_data(value.to_string());
+                certificate_data
+            })
+            .collect()
+    }
+
+    /*
+     * Payloads that are valid against the base state
+     */
+
+    fn create_agent_payload() -> proto::payload::CreateAgentAction {
+        let mut payload = proto::payload::CreateAgentAction::new();
+        payload.set_name("New agent".to_string());
+        payload.set_timestamp(VALID_FROM);
+        payload
+    }
+
+    fn create_organization_payload() -> proto::payload::CreateOrganizationAction {
+        let mut payload = proto::payload::CreateOrganizationAction::new();
+        payload.set_id("new_organization".to_string());
+        payload.set_name("New organization".to_string());
+        payload.set_organization_type(CERTIFYING_BODY);
+        payload
+    }
+
+    fn update_organization_payload() -> proto::payload::UpdateOrganizationAction {
+        let mut address = proto::organization::Factory_Address::new();
+        address.set_city("Springfield".to_string());
+        let mut payload = proto::payload::UpdateOrganizationAction::new();
+        payload.set_address(address);
+        payload
+    }
+
+    fn authorize_agent_payload(
+        public_key: &str,
+        role: proto::organization::Organization_Authorization_Role,
+    ) -> proto::payload::AuthorizeAgentAction {
+        let mut payload = proto::payload::AuthorizeAgentAction::new();
+        payload.set_public_key(public_key.to_string());
+        payload.set_role(role);
+        payload
+    }
+
+    fn revoke_agent_authorization_payload(
+        public_key: &str,
+        role: proto::organization::Organization_Authorization_Role,
+    ) -> proto::payload::RevokeAgentAuthorizationAction {
+        let mut payload = proto::payload::RevokeAgentAuthorizationAction::new();
+        payload.set_public_key(public_key.to_string());
+        payload.set_role(role);
+        payload
+    }
+
+    fn rotate_agent_key_payload(public_key: &str) -> proto::payload::RotateAgentKeyAction {
+        let mut payload = proto::payload::RotateAgentKeyAction::new();
+        payload.set_public_key(public_key.to_string());
+        payload.set_new_public_key("new_agent".to_string());
+        payload
+    }
+
+    fn issue_certificate_payload() -> proto::payload::IssueCertificateAction {
+        let mut payload = proto::payload::IssueCertificateAction::new();
+        payload.set_id("new_certificate".to_string());
+        payload.set_source(proto::payload::IssueCertificateAction_Source::INDEPENDENT);
+        payload.set_factory_id(FACTORY_ID.to_string());
+        payload.set_standard_id(STANDARD_ID.to_string());
+        payload.set_standard_version("1.0".to_string());
+        payload.set_valid_from(VALID_FROM);
+        payload.set_valid_to(VALID_TO);
+        payload
+    }
+
+    fn issue_certificate_from_request_payload() -> proto::payload::IssueCertificateAction {
+        let mut payload = issue_certificate_payload();
+        payload.set_source(proto::payload::IssueCertificateAction_Source::FROM_REQUEST);
+        payload.set_request_id(REQUEST_ID.to_string());
+        payload.clear_factory_id();
+        payload.clear_standard_id();
+        payload
+    }
+
+    fn revoke_certificate_payload() -> proto::payload::RevokeCertificateAction {
+        let mut payload = proto::payload::RevokeCertificateAction::new();
+        payload.set_certificate_id(CERTIFICATE_ID.to_string());
+        payload.set_reason(proto::certificate::Certificate_RevocationReason::ISSUED_IN_ERROR);
+        payload.set_effective_date(2_000);
+        payload
+    }
+
+    fn suspend_certificate_payload() -> proto::payload::SuspendCertificateAction {
+        let mut payload = proto::payload::SuspendCertificateAction::new();
+        payload.set_certificate_id(CERTIFICATE_ID.to_string());
+        payload.set_reason("Pending investigation".to_string());
+        payload.set_suspension_date(2_000);
+        payload
+    }
+
+    fn reinstate_certificate_payload() -> proto::payload::ReinstateCertificateAction {
+        let mut payload = proto::payload::ReinstateCertificateAction::new();
+        payload.set_certificate_id(CERTIFICATE_ID.to_string());
+        payload.set_reinstatement_date(3_000);
+        payload
+    }
+
+    fn renew_certificate_payload() -> proto::payload::RenewCertificateAction {
+        let mut payload = proto::payload::RenewCertificateAction::new();
+        payload.set_id("renewed_certificate".to_string());
+        payload.set_previous_certificate_id(CERTIFICATE_ID.to_string());
+        payload.set_standard_version("1.0".to_string());
+        payload.set_valid_from(VALID_TO);
+        payload.set_valid_to(ACCREDITED_TO);
+        payload
+    }
+
+    fn open_request_payload() -> proto::payload::OpenRequestAction {
+        let mut payload = proto::payload::OpenRequestAction::new();
+        payload.set_id("new_request".to_string());
+        payload.set_standard_id(STANDARD_ID.to_string());
+        payload.set_certifying_body_id(CERTIFYING_BODY_ID.to_string());
+        payload.set_request_date(VALID_FROM);
+        payload
+    }
+
+    /// An OpenRequestAction as submitted by a family version 0.1 client, which does not
+    /// address the request to a certifying body
+    fn open_request_payload_v0_1() -> proto::payload::CertificateRegistryPayload {
+        let mut open_request = open_request_payload();
+        open_request.clear_certifying_body_id();
+        let mut payload = proto::payload::CertificateRegistryPayload::new();
+        payload.set_action(proto::payload::CertificateRegistryPayload_Action::OPEN_REQUEST_ACTION);
+        payload.set_open_request_action(open_request);
+        payload
+    }
+
+    fn change_request_status_payload() -> proto::payload::ChangeRequestStatusAction {
+        let mut payload = proto::payload::ChangeRequestStatusAction::new();
+        payload.set_request_id(REQUEST_ID.to_string());
+        payload.set_status(proto::request::Request_Status::CLOSED);
+        payload
+    }
+
+    fn accept_request_payload() -> proto::payload::AcceptRequestAction {
+        let mut payload = proto::payload::AcceptRequestAction::new();
+        payload.set_request_id(REQUEST_ID.to_string());
+        payload
+    }
+
+    fn decline_request_payload() -> proto::payload::DeclineRequestAction {
+        let mut payload = proto::payload::DeclineRequestAction::new();
+        payload.set_request_id(REQUEST_ID.to_string());
+        payload.set_reason("Outside of our scope".to_string());
+        payload
+    }
+
+    fn record_audit_payload() -> proto::payload::RecordAuditAction {
+        let mut payload = proto::payload::RecordAuditAction::new();
+        payload.set_id("new_audit".to_string());
+        payload.set_request_id(REQUEST_ID.to_string());
+        payload.set_audit_date(VALID_FROM);
+        payload.set_outcome(proto::audit::Audit_Outcome::PASSED);
+        payload
+    }
+
+    fn raise_non_conformance_payload() -> proto::payload::RaiseNonConformanceAction {
+        let mut payload = proto::payload::RaiseNonConformanceAction::new();
+        payload.set_id("new_non_conformance".to_string());
+        payload.set_factory_id(FACTORY_ID.to_string());
+        payload.set_description("Fire exits are blocked".to_string());
+        payload.set_severity(proto::audit::Audit_Finding_Severity::MAJOR);
+        payload.set_raised_at(VALID_FROM);
+        payload
+    }
+
+    fn submit_corrective_action_payload() -> proto::payload::SubmitCorrectiveActionAction {
+        let mut payload = proto::payload::SubmitCorrectiveActionAction::new();
+        payload.set_non_conformance_id(NON_CONFORMANCE_ID.to_string());
+        payload.set_corrective_action_plan("Clear the fire exits".to_string());
+        payload.set_submitted_at(VALID_FROM);
+        payload
+    }
+
+    fn close_non_conformance_payload() -> proto::payload::CloseNonConformanceAction {
+        let mut payload = proto::payload::CloseNonConformanceAction::new();
+        payload.set_non_conformance_id(NON_CONFORMANCE_ID.to_string());
+        payload.set_closed_at(VALID_FROM);
+        payload
+    }
+
+    fn create_standard_payload() -> proto::payload::CreateStandardAction {
+        let mut payload = proto::payload::CreateStandardAction::new();
+        payload.set_standard_id("new_standard".to_string());
+        payload.set_name("New standard".to_string());
+        payload.set_version("1.0".to_string());
+        payload.set_approval_date(STANDARD_APPROVAL_DATE);
+        payload
+    }
+
+    fn update_standard_payload() -> proto::payload::UpdateStandardAction {
+        let mut payload = proto::payload::UpdateStandardAction::new();
+        payload.set_standard_id(STANDARD_ID.to_string());
+        payload.set_version("2.0".to_string());
+        payload.set_approval_date(2_000);
+        payload
+    }
+
+    fn retire_standard_version_payload(
+        retirement_date: u64,
+    ) -> proto::payload::RetireStandardVersionAction {
+        let mut payload = proto::payload::RetireStandardVersionAction::new();
+        payload.set_standard_id(STANDARD_ID.to_string());
+        payload.set_version("1.0".to_string());
+        payload.set_retirement_date(retirement_date);
+        payload
+    }
+
+    fn retire_standard_payload() -> proto::payload::RetireStandardAction {
+        let mut payload = proto::payload::RetireStandardAction::new();
+        payload.set_standard_id(STANDARD_ID.to_string());
+        payload
+    }
+
+    fn accredit_certifying_body_payload() -> proto::payload::AccreditCertifyingBodyAction {
+        let mut payload = proto::payload::AccreditCertifyingBodyAction::new();
+        payload.set_certifying_body_id(CERTIFYING_BODY_ID.to_string());
+        payload.set_standard_id(STANDARD_ID.to_string());
+        payload.set_valid_from(2_000);
+        payload.set_valid_to(ACCREDITED_TO);
+        payload
+    }
+
+    fn revoke_accreditation_payload() -> proto::payload::RevokeAccreditationAction {
+        let mut payload = proto::payload::RevokeAccreditationAction::new();
+        payload.set_certifying_body_id(CERTIFYING_BODY_ID.to_string());
+        payload.set_standard_id(STANDARD_ID.to_string());
+        payload
+    }
+
+    fn declare_equivalence_payload() -> proto::payload::DeclareEquivalenceAction {
+        let mut payload = proto::payload::DeclareEquivalenceAction::new();
+        payload.set_standard_id(STANDARD_ID.to_string());
+        payload.set_standard_version("1.0".to_string());
+        payload.set_equivalent_standard_id(OTHER_STANDARD_ID.to_string());
+        payload.set_equivalent_standard_version("1.0".to_string());
+        payload
+    }
+
+    fn add_supplier_payload() -> proto::payload::AddSupplierAction {
+        let mut payload = proto::payload::AddSupplierAction::new();
+        payload.set_factory_id(FACTORY_ID.to_string());
+        payload
+    }
+
+    fn remove_supplier_payload() -> proto::payload::RemoveSupplierAction {
+        let mut payload = proto::payload::RemoveSupplierAction::new();
+        payload.set_factory_id(FACTORY_ID.to_string());
+        payload
+    }
+
+    fn define_role_payload(
+        name: &str,
+        permissions: &[proto::organization::Organization_Permission],
+    ) -> proto::payload::DefineRoleAction {
+        let mut payload = proto::payload::DefineRoleAction::new();
+        payload.set_name(name.to_string());
+        payload.set_permissions(permissions.to_vec());
+        payload
+    }
+
+    fn set_admin_quorum_payload(admin_quorum: u32) -> proto::payload::SetAdminQuorumAction {
+        let mut payload = proto::payload::SetAdminQuorumAction::new();
+        payload.set_admin_quorum(admin_quorum);
+        payload
+    }
+
+    fn authorize_admin_proposal() -> proto::payload::CertificateRegistryPayload {
+        let mut payload = proto::payload::CertificateRegistryPayload::new();
+        payload.set_action(proto::payload::CertificateRegistryPayload_Action::AUTHORIZE_AGENT);
+        payload.set_authorize_agent(authorize_agent_payload(FACTORY_TRANSACTOR_KEY, ADMIN));
+        payload
+    }
+
+    fn revoke_admin_proposal(public_key: &str) -> proto::payload::CertificateRegistryPayload {
+        let mut payload = proto::payload::CertificateRegistryPayload::new();
+        payload.set_action(
+            proto::payload::CertificateRegistryPayload_Action::REVOKE_AGENT_AUTHORIZATION,
+        );
+        payload
+            .set_revoke_agent_authorization(revoke_agent_authorization_payload(public_key, ADMIN));
+        payload
+    }
+
+    fn rotate_agent_key_proposal(public_key: &str) -> proto::payload::CertificateRegistryPayload {
+        let mut payload = proto::payload::CertificateRegistryPayload::new();
+        payload.set_action(proto::payload::CertificateRegistryPayload_Action::ROTATE_AGENT_KEY);
+        payload.set_rotate_agent_key(rotate_agent_key_payload(public_key));
+        payload
+    }
+
+    fn propose_change_payload(
+        payload: proto::payload::CertificateRegistryPayload,
+    ) -> proto::payload::ProposeChangeAction {
+        let mut propose_change = proto::payload::ProposeChangeAction::new();
+        propose_change.set_proposal_id(PROPOSAL_ID.to_string());
+        propose_change.set_payload(payload);
+        propose_change
+    }
+
+    fn vote_on_proposal_payload(accept: bool) -> proto::payload::VoteOnProposalAction {
+        let mut payload = proto::payload::VoteOnProposalAction::new();
+        payload.set_proposal_id(PROPOSAL_ID.to_string());
+        payload.set_accept(accept);
+        payload
+    }
+
+    fn withdraw_proposal_payload() -> proto::payload::WithdrawProposalAction {
+        let mut payload = proto::payload::WithdrawProposalAction::new();
+        payload.set_proposal_id(PROPOSAL_ID.to_string());
+        payload
+    }
+
+    fn open_accreditation_request_payload() -> proto::payload::OpenAccreditationRequestAction {
+        let mut payload = proto::payload::OpenAccreditationRequestAction::new();
+        payload.set_id("new_accreditation_request".to_string());
+        payload.set_standard_id(STANDARD_ID.to_string());
+        payload.set_request_date(VALID_FROM);
+        payload
+    }
+
+    fn change_accreditation_request_status_payload(
+        status: proto::accreditation_request::AccreditationRequest_Status,
+        reason: &str,
+    ) -> proto::payload::ChangeAccreditationRequestStatusAction {
+        let mut payload = proto::payload::ChangeAccreditationRequestStatusAction::new();
+        payload.set_request_id(ACCREDITATION_REQUEST_ID.to_string());
+        payload.set_status(status);
+        payload.set_reason(reason.to_string());
+        payload
+    }
+
+    fn approve_accreditation_request_payload() -> proto::payload::ApproveAccreditationRequestAction
+    {
+        let mut payload = proto::payload::ApproveAccreditationRequestAction::new();
+        payload.set_request_id(ACCREDITATION_REQUEST_ID.to_string());
+        payload.set_valid_from(2_000);
+        payload.set_valid_to(ACCREDITED_TO);
+        payload
+    }
+
+    fn update_registry_operators_payload() -> proto::payload::UpdateRegistryOperatorsAction {
+        let mut payload = proto::payload::UpdateRegistryOperatorsAction::new();
+        payload.set_operators(protobuf::RepeatedField::from_vec(vec![
+            OPERATOR_KEY.to_string(),
+            "second_operator".to_string(),
+        ]));
+        payload
+    }
+
+    fn review_organization_payload(
+        organization_id: &str,
+    ) -> proto::payload::ReviewOrganizationAction {
+        let mut payload = proto::payload::ReviewOrganizationAction::new();
+        payload.set_organization_id(organization_id.to_string());
+        payload.set_approval_status(proto::organization::Organization_ApprovalStatus::APPROVED);
+        payload
+    }
+
+    /*
+     * Assertions
+     */
+
+    /// Decodes the payload as it is decoded when submitted under the family version
+    fn decode(
+        family_version: &str,
+        payload: &proto::payload::CertificateRegistryPayload,
+    ) -> Result<Action, ApplyError> {
+        let payload_data =
+            protobuf::Message::write_to_bytes(payload).expect("Failed to serialize payload");
+        CertPayload::new(family_version, &payload_data).map(|payload| payload.get_action())
+    }
+
+    fn apply(
+        context: &mut MockTransactionContext,
+        signer_public_key: &str,
+        action: Action,
+    ) -> Result<(), ApplyError> {
+        let mut state = CertState::new(context);
+        CertTransactionHandler::new().apply_action(action, &mut state, signer_public_key)
+    }
+
+    /// Applies the action to a fresh context built from `state` and checks that it is rejected
+    /// with a message containing `expected`
+    fn assert_invalid(
+        state: StateBuilder,
+        signer_public_key: &str,
+        action: Action,
+        expected: &str,
+    ) {
+        let mut context = state.build();
+        match apply(&mut context, signer_public_key, action) {
+            Err(ApplyError::InvalidTransaction(msg)) => assert!(
+                msg.contains(expected),
+                "Expected an error containing \"{}\", got \"{}\"",
+                expected,
+                msg
+            ),
+            Err(err) => panic!("Expected an invalid transaction, got {:?}", err),
+            Ok(()) => panic!("Expected an invalid transaction: {}", expected),
+        }
+    }
+
+    fn assert_valid(context: &mut MockTransactionContext, signer_public_key: &str, action: Action) {
+        if let Err(err) = apply(context, signer_public_key, action) {
+            panic!("Expected a valid transaction, got {:?}", err);
+        }
+    }
+
+    fn get_agent(context: &mut MockTransactionContext, public_key: &str) -> proto::agent::Agent {
+        CertState::new(context)
+            .get_agent(public_key)
+            .unwrap()
+            .expect("Agent not found")
+    }
+
+    fn get_organization(
+        context: &mut MockTransactionContext,
+        organization_id: &str,
+    ) -> proto::organization::Organization {
+        CertState::new(context)
+            .get_organization(organization_id)
+            .unwrap()
+            .expect("Organization not found")
+    }
+
+    fn get_proposal(
+        context: &mut MockTransactionContext,
+        proposal_id: &str,
+    ) -> proto::proposal::Proposal {
+        CertState::new(context)
+            .get_proposal(proposal_id)
+            .unwrap()
+            .expect("Proposal not found")
+    }
+
+    fn get_accreditation_request(
+        context: &mut MockTransactionContext,
+        request_id: &str,
+    ) -> proto::accreditation_request::AccreditationRequest {
+        CertState::new(context)
+            .get_accreditation_request(request_id)
+            .unwrap()
+            .expect("Accreditation request not found")
+    }
+
+    fn get_certificate(
+        context: &mut MockTransactionContext,
+        certificate_id: &str,
+    ) -> proto::certificate::Certificate {
+        CertState::new(context)
+            .get_certificate(certificate_id)
+            .unwrap()
+            .expect("Certificate not found")
+    }
+
+    fn get_request(
+        context: &mut MockTransactionContext,
+        request_id: &str,
+    ) -> proto::request::Request {
+        CertState::new(context)
+            .get_request(request_id)
+            .unwrap()
+            .expect("Request not found")
+    }
+
+    fn get_standard(
+        context: &mut MockTransactionContext,
+        standard_id: &str,
+    ) -> proto::standard::Standard {
+        CertState::new(context)
+            .get_standard(standard_id)
+            .unwrap()
+            .expect("Standard not found")
+    }
+
+    /*
+     * CreateAgentAction
+     */
+
+    #[test]
+    fn test_create_agent() {
+        let mut context = base_state().build();
+        assert_valid(
+            &mut context,
+            "new_agent",
+            Action::CreateAgent(create_agent_payload()),
+        );
+        assert_eq!(get_agent(&mut context, "new_agent").get_name(), "New agent");
+    }
+
+    #[test]
+    fn test_create_agent_already_exists() {
+        assert_invalid(
+            base_state(),
+            FACTORY_KEY,
+            Action::CreateAgent(create_agent_payload()),
+            "Agent already exists",
+        );
+    }
+
+    #[test]
+    fn test_create_agent_timestamp_before_block_time() {
+        assert_invalid(
+            base_state().with_block_time(VALID_FROM + MAX_TIMESTAMP_DRIFT + 1),
+            "new_agent",
+            Action::CreateAgent(create_agent_payload()),
+            "Timestamp 1000 is more than 300 seconds before the block time",
+        );
+    }
+
+    #[test]
+    fn test_create_agent_timestamp_after_block_time() {
+        assert_invalid(
+            base_state().with_block_time(VALID_FROM - MAX_TIMESTAMP_LEAD - 1),
+            "new_agent",
+            Action::CreateAgent(create_agent_payload()),
+            "Timestamp 1000 is more than 600 seconds after the block time",
+        );
+    }
+
+    #[test]
+    fn test_create_agent_timestamp_within_drift() {
+        for block_time in &[
+            VALID_FROM + MAX_TIMESTAMP_DRIFT,
+            VALID_FROM - MAX_TIMESTAMP_LEAD,
+        ] {
+            let mut context = base_state().with_block_time(*block_time).build();
+            assert_valid(
+                &mut context,
+                "new_agent",
+                Action::CreateAgent(create_agent_payload()),
+            );
+        }
+    }
+
+    #[test]
+    fn test_create_agent_without_block_info() {
+        assert_invalid(
+            base_state_without_block_info(),
+            "new_agent",
+            Action::CreateAgent(create_agent_payload()),
+            "No block time is available",
+        );
+    }
+
+    /*
+     * CreateOrganizationAction
+     */
+
+    #[test]
+    fn test_create_organization() {
+        let mut context = base_state().build();
+        assert_valid(
+            &mut context,
+            UNAFFILIATED_KEY,
+            Action::CreateOrganization(create_organization_payload()),
+        );
+        assert_eq!(
+            get_organization(&mut context, "new_organization").get_approval_status(),
+            proto::organization::Organization_ApprovalStatus::PENDING
+        );
+        assert_eq!(
+            get_agent(&mut context, UNAFFILIATED_KEY).get_organization_id(),
+            "new_organization"
+        );
+    }
+
+    #[test]
+    fn test_create_organization_retailer() {
+        let mut payload = create_organization_payload();
+        payload.set_organization_type(RETAILER);
+        let mut context = base_state().build();
+        assert_valid(
+            &mut context,
+            UNAFFILIATED_KEY,
+            Action::CreateOrganization(payload),
+        );
+        let retailer = get_organization(&mut context, "new_organization");
+        assert_eq!(
+            retailer.get_approval_status(),
+            proto::organization::Organization_ApprovalStatus::UNSET_APPROVAL
+        );
+        assert!(retailer.has_retailer_details());
+    }
+
+    #[test]
+    fn test_create_organization_already_exists() {
+        let mut payload = create_organization_payload();
+        payload.set_id(FACTORY_ID.to_string());
+        assert_invalid(
+            base_state(),
+            UNAFFILIATED_KEY,
+            Action::CreateOrganization(payload),
+            "Organization already exists",
+        );
+    }
+
+    #[test]
+    fn test_create_organization_no_agent() {
+        assert_invalid(
+            base_state(),
+            UNKNOWN_KEY,
+            Action::CreateOrganization(create_organization_payload()),
+            "No agent exists",
+        );
+    }
+
+    #[test]
+    fn test_create_organization_rotated_agent() {
+        assert_invalid(
+            base_state().with_agent(rotated(agent(UNAFFILIATED_KEY, ""))),
+            UNAFFILIATED_KEY,
+            Action::CreateOrganization(create_organization_payload()),
+            "has been rotated to",
+        );
+    }
+
+    #[test]
+    fn test_create_organization_agent_already_associated() {
+        assert_invalid(
+            base_state(),
+            FACTORY_KEY,
+            Action::CreateOrganization(create_organization_payload()),
+            "Agent is already associated with an organization",
+        );
+    }
+
+    /*
+     * UpdateOrganizationAction
+     */
+
+    #[test]
+    fn test_update_organization() {
+        let mut context = base_state().build();
+        assert_valid(
+            &mut context,
+            FACTORY_KEY,
+            Action::UpdateOrganization(update_organization_payload()),
+        );
+        assert_eq!(
+            get_organization(&mut context, FACTORY_ID)
+                .get_factory_details()
+                .get_address()
+                .get_city(),
+            "Springfield"
+        );
+    }
+
+    #[test]
+    fn test_update_organization_no_agent() {
+        assert_invalid(
+            base_state(),
+            UNKNOWN_KEY,
+            Action::UpdateOrganization(update_organization_payload()),
+            "No agent exists",
+        );
+    }
+
+    #[test]
+    fn test_update_organization_agent_not_associated() {
+        assert_invalid(
+            base_state(),
+            UNAFFILIATED_KEY,
+            Action::UpdateOrganization(update_organization_payload()),
+            "Agent is not associated with an organization",
+        );
+    }
+
+    #[test]
+    fn test_update_organization_no_organization() {
+        assert_invalid(
+            base_state(),
+            ORPHAN_KEY,
+            Action::UpdateOrganization(update_organization_payload()),
+            "No organization exists",
+        );
+    }
+
+    #[test]
+    fn test_update_organization_not_admin() {
+        assert_invalid(
+            base_state(),
+            FACTORY_TRANSACTOR_KEY,
+            Action::UpdateOrganization(update_organization_payload()),
+            "Agent is not authorized to update organization",
+        );
+    }
+
+    #[test]
+    fn test_update_organization_address_of_non_factory() {
+        assert_invalid(
+            base_state(),
+            CERTIFYING_BODY_KEY,
+            Action::UpdateOrganization(update_organization_payload()),
+            "Organization is not a factory",
+        );
+    }
+
+    /*
+     * AuthorizeAgentAction
+     */
+
+    #[test]
+    fn test_authorize_agent() {
+        let mut context = base_state().build();
+        assert_valid(
+            &mut context,
+            FACTORY_KEY,
+            Action::AuthorizeAgent(authorize_agent_payload(UNAFFILIATED_KEY, TRANSACTOR)),
+        );
+        assert_eq!(
+            get_agent(&mut context, UNAFFILIATED_KEY).get_organization_id(),
+            FACTORY_ID
+        );
+    }
+
+    #[test]
+    fn test_authorize_agent_no_signing_agent() {
+        assert_invalid(
+            base_state(),
+            UNKNOWN_KEY,
+            Action::AuthorizeAgent(authorize_agent_payload(UNAFFILIATED_KEY, TRANSACTOR)),
+            "Signing agent does not exist",
+        );
+    }
+
+    #[test]
+    fn test_authorize_agent_signer_not_associated() {
+        assert_invalid(
+            base_state(),
+            UNAFFILIATED_KEY,
+            Action::AuthorizeAgent(authorize_agent_payload(UNAFFILIATED_KEY, TRANSACTOR)),
+            "Transaction signer is not associated with an organization",
+        );
+    }
+
+    #[test]
+    fn test_authorize_agent_no_organization() {
+        assert_invalid(
+            base_state(),
+            ORPHAN_KEY,
+            Action::AuthorizeAgent(authorize_agent_payload(UNAFFILIATED_KEY, TRANSACTOR)),
+            "Organization does not exist",
+        );
+    }
+
+    #[test]
+    fn test_authorize_agent_signer_not_admin() {
+        assert_invalid(
+            base_state(),
+            FACTORY_TRANSACTOR_KEY,
+            Action::AuthorizeAgent(authorize_agent_payload(UNAFFILIATED_KEY, TRANSACTOR)),
+            "is not an authorized ADMIN",
+        );
+    }
+
+    #[test]
+    fn test_authorize_agent_no_agent() {
+        assert_invalid(
+            base_state(),
+            FACTORY_KEY,
+            Action::AuthorizeAgent(authorize_agent_payload(UNKNOWN_KEY, TRANSACTOR)),
+            "No agent exists",
+        );
+    }
+
+    #[test]
+    fn test_authorize_agent_rotated_agent() {
+        assert_invalid(
+            base_state().with_agent(rotated(agent(UNAFFILIATED_KEY, ""))),
+            FACTORY_KEY,
+            Action::AuthorizeAgent(authorize_agent_payload(UNAFFILIATED_KEY, TRANSACTOR)),
+            "has been rotated to",
+        );
+    }
+
+    #[test]
+    fn test_authorize_agent_of_different_organization() {
+        assert_invalid(
+            base_state(),
+            FACTORY_KEY,
+            Action::AuthorizeAgent(authorize_agent_payload(CERTIFYING_BODY_KEY, TRANSACTOR)),
+            "Agent is already associated with a different organization",
+        );
+    }
+
+    #[test]
+    fn test_authorize_agent_already_authorized() {
+        assert_invalid(
+            base_state(),
+            FACTORY_KEY,
+            Action::AuthorizeAgent(authorize_agent_payload(FACTORY_TRANSACTOR_KEY, TRANSACTOR)),
+            "is already authorized as TRANSACTOR",
+        );
+    }
+
+    #[test]
+    fn test_authorize_agent_with_validity_window() {
+        let mut context = base_state().build();
+        let mut payload = authorize_agent_payload(UNAFFILIATED_KEY, TRANSACTOR);
+        payload.set_valid_from(VALID_FROM);
+        payload.set_valid_to(VALID_TO);
+        assert_valid(&mut context, FACTORY_KEY, Action::AuthorizeAgent(payload));
+
+        let factory = get_organization(&mut context, FACTORY_ID);
+        let authorization = factory
+            .get_authorizations()
+            .iter()
+            .find(|auth| auth.get_public_key() == UNAFFILIATED_KEY)
+            .expect("Authorization not found");
+        assert_eq!(authorization.get_valid_from(), VALID_FROM);
+        assert_eq!(authorization.get_valid_to(), VALID_TO);
+    }
+
+    #[test]
+    fn test_authorize_agent_replaces_expired_authorization() {
+        let mut factory = organization(
+            FACTORY_ID,
+            FACTORY,
+            &[(FACTORY_KEY, ADMIN), (FACTORY_TRANSACTOR_KEY, TRANSACTOR)],
+        );
+        factory.mut_authorizations()[1].set_valid_to(VALID_FROM - 1);
+        let mut context = base_state()
+            .with_organization(factory)
+            .with_block_time(VALID_FROM)
+            .build();
+
+        let mut payload = authorize_agent_payload(FACTORY_TRANSACTOR_KEY, TRANSACTOR);
+        payload.set_valid_to(VALID_TO);
+        assert_valid(&mut context, FACTORY_KEY, Action::AuthorizeAgent(payload));
+
+        let transactor_authorizations: Vec<_> = get_organization(&mut context, FACTORY_ID)
+            .get_authorizations()
+            .iter()
+            .filter(|auth| auth.get_public_key() == FACTORY_TRANSACTOR_KEY)
+            .cloned()
+            .collect();
+        assert_eq!(transactor_authorizations.len(), 1);
+        assert_eq!(transactor_authorizations[0].get_valid_to(), VALID_TO);
+    }
+
+    #[test]
+    fn test_authorize_agent_without_block_info() {
+        assert_invalid(
+            base_state_without_block_info(),
+            FACTORY_KEY,
+            Action::AuthorizeAgent(authorize_agent_payload(UNAFFILIATED_KEY, TRANSACTOR)),
+            "No block time is available",
+        );
+    }
+
+    #[test]
+    fn test_authorize_agent_expired_admin() {
+        assert_invalid(
+            base_state()
+                .with_organization(limited(
+                    organization(FACTORY_ID, FACTORY, &[(FACTORY_KEY, ADMIN)]),
+                    ACCREDITED_FROM,
+                    VALID_FROM - 1,
+                ))
+                .with_block_time(VALID_FROM),
+            FACTORY_KEY,
+            Action::AuthorizeAgent(authorize_agent_payload(UNAFFILIATED_KEY, TRANSACTOR)),
+            "is not an authorized ADMIN",
+        );
+    }
+
+    /*
+     * RevokeAgentAuthorizationAction
+     */
+
+    #[test]
+    fn test_revoke_agent_authorization() {
+        let mut context = base_state().build();
+        assert_valid(
+            &mut context,
+            FACTORY_KEY,
+            Action::RevokeAgentAuthorization(revoke_agent_authorization_payload(
+                FACTORY_TRANSACTOR_KEY,
+                TRANSACTOR,
+            )),
+        );
+        assert_eq!(
+            get_agent(&mut context, FACTORY_TRANSACTOR_KEY).get_organization_id(),
+            ""
+        );
+    }
+
+    #[test]
+    fn test_revoke_agent_authorization_no_signing_agent() {
+        assert_invalid(
+            base_state(),
+            UNKNOWN_KEY,
+            Action::RevokeAgentAuthorization(revoke_agent_authorization_payload(
+                FACTORY_TRANSACTOR_KEY,
+                TRANSACTOR,
+            )),
+            "Signing agent does not exist",
+        );
+    }
+
+    #[test]
+    fn test_revoke_agent_authorization_signer_not_associated() {
+        assert_invalid(
+            base_state(),
+            UNAFFILIATED_KEY,
+            Action::RevokeAgentAuthorization(revoke_agent_authorization_payload(
+                FACTORY_TRANSACTOR_KEY,
+                TRANSACTOR,
+            )),
+            "Transaction signer is not associated with an organization",
+        );
+    }
+
+    #[test]
+    fn test_revoke_agent_authorization_no_organization() {
+        assert_invalid(
+            base_state(),
+            ORPHAN_KEY,
+            Action::RevokeAgentAuthorization(revoke_agent_authorization_payload(
+                FACTORY_TRANSACTOR_KEY,
+                TRANSACTOR,
+            )),
+            "Organization does not exist",
+        );
+    }
+
+    #[test]
+    fn test_revoke_agent_authorization_signer_not_admin() {
+        assert_invalid(
+            base_state(),
+            FACTORY_TRANSACTOR_KEY,
+            Action::RevokeAgentAuthorization(revoke_agent_authorization_payload(
+                FACTORY_KEY,
+                TRANSACTOR,
+            )),
+            "is not an authorized ADMIN",
+        );
+    }
+
+    #[test]
+    fn test_revoke_agent_authorization_no_agent() {
+        assert_invalid(
+            base_state(),
+            FACTORY_KEY,
+            Action::RevokeAgentAuthorization(revoke_agent_authorization_payload(
+                UNKNOWN_KEY,
+                TRANSACTOR,
+            )),
+            "No agent exists",
+        );
+    }
+
+    #[test]
+    fn test_revoke_agent_authorization_of_different_organization() {
+        assert_invalid(
+            base_state(),
+            FACTORY_KEY,
+            Action::RevokeAgentAuthorization(revoke_agent_authorization_payload(
+                CERTIFYING_BODY_KEY,
+                TRANSACTOR,
+            )),
+            "is not associated with the organization",
+        );
+    }
+
+    #[test]
+    fn test_revoke_agent_authorization_role_not_held() {
+        assert_invalid(
+            base_state(),
+            FACTORY_KEY,
+            Action::RevokeAgentAuthorization(revoke_agent_authorization_payload(
+                FACTORY_TRANSACTOR_KEY,
+                ADMIN,
+            )),
+            "is not authorized as ADMIN",
+        );
+    }
+
+    #[test]
+    fn test_revoke_agent_authorization_last_admin() {
+        assert_invalid(
+            base_state(),
+            FACTORY_KEY,
+            Action::RevokeAgentAuthorization(revoke_agent_authorization_payload(
+                FACTORY_KEY,
+                ADMIN,
+            )),
+            "Cannot revoke the last ADMIN",
+        );
+    }
+
+    #[test]
+    fn test_revoke_agent_authorization_last_current_admin() {
+        let mut factory = organization(
+            FACTORY_ID,
+            FACTORY,
+            &[(FACTORY_KEY, ADMIN), (FACTORY_TRANSACTOR_KEY, ADMIN)],
+        );
+        factory.mut_authorizations()[1].set_valid_to(VALID_FROM - 1);
+        assert_invalid(
+            base_state()
+                .with_organization(factory)
+                .with_block_time(VALID_FROM),
+            FACTORY_KEY,
+            Action::RevokeAgentAuthorization(revoke_agent_authorization_payload(
+                FACTORY_KEY,
+                ADMIN,
+            )),
+            "Cannot revoke the last ADMIN",
+        );
+    }
+
+    #[test]
+    fn test_revoke_agent_authorization_last_custom_admin() {
+        let mut payload = revoke_agent_authorization_payload(FACTORY_KEY, CUSTOM);
+        payload.set_role_name("manager".to_string());
+        assert_invalid(
+            base_state().with_organization(with_custom_role(
+                organization(FACTORY_ID, FACTORY, &[(FACTORY_TRANSACTOR_KEY, TRANSACTOR)]),
+                FACTORY_KEY,
+                role_definition("manager", &[MANAGE_ORGANIZATION]),
+            )),
+            FACTORY_KEY,
+            Action::RevokeAgentAuthorization(payload),
+            "Cannot revoke the last ADMIN",
+        );
+    }
+
+    #[test]
+    fn test_revoke_agent_authorization_admin_with_custom_admin_remaining() {
+        let mut context = base_state()
+            .with_organization(with_custom_role(
+                organization(FACTORY_ID, FACTORY, &[(FACTORY_KEY, ADMIN)]),
+                FACTORY_TRANSACTOR_KEY,
+                role_definition("manager", &[MANAGE_ORGANIZATION]),
+            ))
+            .build();
+        assert_valid(
+            &mut context,
+            FACTORY_KEY,
+            Action::RevokeAgentAuthorization(revoke_agent_authorization_payload(
+                FACTORY_KEY,
+                ADMIN,
+            )),
+        );
+    }
+
+    /*
+     * RotateAgentKeyAction
+     */
+
+    #[test]
+    fn test_rotate_agent_key() {
+        let mut context = base_state().build();
+        assert_valid(
+            &mut context,
+            FACTORY_KEY,
+            Action::RotateAgentKey(rotate_agent_key_payload(FACTORY_KEY)),
+        );
+        assert_eq!(
+            get_agent(&mut context, FACTORY_KEY).get_successor_public_key(),
+            "new_agent"
+        );
+        assert_eq!(
+            get_agent(&mut context, "new_agent").get_organization_id(),
+            FACTORY_ID
+        );
+        assert!(get_organization(&mut context, FACTORY_ID)
+            .get_authorizations()
+            .iter()
+            .all(|authorization| authorization.get_public_key() != FACTORY_KEY));
+    }
+
+    #[test]
+    fn test_rotate_agent_key_no_agent() {
+        assert_invalid(
+            base_state(),
+            UNKNOWN_KEY,
+            Action::RotateAgentKey(rotate_agent_key_payload(UNKNOWN_KEY)),
+            "No agent exists",
+        );
+    }
+
+    #[test]
+    fn test_rotate_agent_key_already_rotated() {
+        assert_invalid(
+            base_state().with_agent(rotated(agent(FACTORY_KEY, FACTORY_ID))),
+            FACTORY_KEY,
+            Action::RotateAgentKey(rotate_agent_key_payload(FACTORY_KEY)),
+            "has already been rotated to",
+        );
+    }
+
+    #[test]
+    fn test_rotate_agent_key_new_key_exists() {
+        let mut payload = rotate_agent_key_payload(FACTORY_KEY);
+        payload.set_new_public_key(UNAFFILIATED_KEY.to_string());
+        assert_invalid(
+            base_state(),
+            FACTORY_KEY,
+            Action::RotateAgentKey(payload),
+            "Agent already exists",
+        );
+    }
+
+    #[test]
+    fn test_rotate_agent_key_no_organization() {
+        assert_invalid(
+            base_state(),
+            ORPHAN_KEY,
+            Action::RotateAgentKey(rotate_agent_key_payload(ORPHAN_KEY)),
+            "Organization does not exist",
+        );
+    }
+
+    #[test]
+    fn test_rotate_agent_key_signer_not_authorized() {
+        assert_invalid(
+            base_state(),
+            FACTORY_TRANSACTOR_KEY,
+            Action::RotateAgentKey(rotate_agent_key_payload(FACTORY_KEY)),
+            "is not authorized to rotate the key",
+        );
+    }
+
+    #[test]
+    fn test_rotate_agent_key_proposer_of_open_proposal() {
+        assert_invalid(
+            proposal_state(open_proposal(authorize_admin_proposal())),
+            FACTORY_KEY,
+            Action::RotateAgentKey(rotate_agent_key_payload(FACTORY_KEY)),
+            "is referenced by open proposal",
+        );
+    }
+
+    #[test]
+    fn test_rotate_agent_key_voter_on_open_proposal() {
+        let mut vote = proto::proposal::Proposal_Vote::new();
+        vote.set_public_key(SECOND_FACTORY_ADMIN_KEY.to_string());
+        vote.set_accept(false);
+        let mut proposal = open_proposal(authorize_admin_proposal());
+        proposal.mut_votes().push(vote);
+        assert_invalid(
+            proposal_state(proposal),
+            SECOND_FACTORY_ADMIN_KEY,
+            Action::RotateAgentKey(rotate_agent_key_payload(SECOND_FACTORY_ADMIN_KEY)),
+            "is referenced by open proposal",
+        );
+    }
+
+    #[test]
+    fn test_rotate_agent_key_registry_operator() {
+        assert_invalid(
+            base_state().with_registry(&[OPERATOR_KEY, FACTORY_KEY]),
+            FACTORY_KEY,
+            Action::RotateAgentKey(rotate_agent_key_payload(FACTORY_KEY)),
+            "is a registry operator",
+        );
+    }
+
+    /*
+     * IssueCertificateAction
+     */
+
+    #[test]
+    fn test_issue_certificate() {
+        let mut context = base_state().build();
+        assert_valid(
+            &mut context,
+            CERTIFYING_BODY_KEY,
+            Action::IssueCertificate(issue_certificate_payload()),
+        );
+        let certificate = get_certificate(&mut context, "new_certificate");
+        assert_eq!(
+            certificate.get_status(),
+            proto::certificate::Certificate_Status::VALID
+        );
+        assert_eq!(certificate.get_factory_id(), FACTORY_ID);
+
+        let event_types: Vec<&str> = context
+            .events()
+            .iter()
+            .map(|&(ref event_type, _)| event_type.as_str())
+            .collect();
+        assert_eq!(event_types, vec![events::CERTIFICATE_ISSUED]);
+    }
+
+    #[test]
+    fn test_issue_certificate_latest_standard_version_by_default() {
+        let mut certifying_body = certifying_body(&[
+            (CERTIFYING_BODY_KEY, ADMIN),
+            (CERTIFYING_BODY_KEY, TRANSACTOR),
+        ]);
+        certifying_body
+            .mut_certifying_body_details()
+            .mut_accreditations()
+            .push(accreditation(
+                STANDARD_ID,
+                "2.0",
+                STANDARDS_BODY_ID,
+                ACCREDITED_FROM,
+                ACCREDITED_TO,
+            ));
+        let mut context = base_state()
+            .with_organization(certifying_body)
+            .with_standard(standard(
+                STANDARD_ID,
+                STANDARDS_BODY_ID,
+                &[
+                    ("1.0", STANDARD_APPROVAL_DATE),
+                    ("2.0", STANDARD_APPROVAL_DATE),
+                ],
+            ))
+            .build();
+        let mut payload = issue_certificate_payload();
+        payload.clear_standard_version();
+        assert_valid(
+            &mut context,
+            CERTIFYING_BODY_KEY,
+            Action::IssueCertificate(payload),
+        );
+        assert_eq!(
+            get_certificate(&mut context, "new_certificate").get_standard_version(),
+            "2.0"
+        );
+    }
+
+    #[test]
+    fn test_issue_certificate_by_certifying_body_of_version_0_1() {
+        let mut certifying_body = certifying_body(&[(CERTIFYING_BODY_KEY, TRANSACTOR)]);
+        certifying_body.clear_approval_status();
+        let mut context = base_state().with_organization(certifying_body).build();
+        assert_valid(
+            &mut context,
+            CERTIFYING_BODY_KEY,
+            Action::IssueCertificate(issue_certificate_payload()),
+        );
+    }
+
+    #[test]
+    fn test_issue_certificate_from_request() {
+        let mut context = base_state()
+            .with_request(factory_request(proto::request::Request_Status::ACCEPTED))
+            .build();
+        assert_valid(
+            &mut context,
+            CERTIFYING_BODY_KEY,
+            Action::IssueCertificate(issue_certificate_from_request_payload()),
+        );
+        assert_eq!(
+            get_request(&mut context, REQUEST_ID).get_status(),
+            proto::request::Request_Status::CERTIFIED
+        );
+        assert_eq!(
+            get_certificate(&mut context, "new_certificate").get_standard_id(),
+            STANDARD_ID
+        );
+    }
+
+    #[test]
+    fn test_issue_certificate_from_request_of_version_0_1() {
+        let mut context = base_state()
+            .with_request(legacy_request(proto::request::Request_Status::IN_PROGRESS))
+            .build();
+        assert_valid(
+            &mut context,
+            CERTIFYING_BODY_KEY,
+            Action::IssueCertificate(issue_certificate_from_request_payload()),
+        );
+        let request = get_request(&mut context, REQUEST_ID);
+        assert_eq!(
+            request.get_status(),
+            proto::request::Request_Status::CERTIFIED
+        );
+        assert_eq!(request.get_certifying_body_id(), CERTIFYING_BODY_ID);
+    }
+
+    #[test]
+    fn test_issue_certificate_with_documents() {
+        let mut payload = issue_certificate_from_request_payload();
+        payload.mut_documents().push(document_reference());
+        let mut context = base_state()
+            .with_request(factory_request(proto::request::Request_Status::ACCEPTED))
+            .build();
+        assert_valid(
+            &mut context,
+            CERTIFYING_BODY_KEY,
+            Action::IssueCertificate(payload),
+        );
+        assert_eq!(
+            get_certificate(&mut context, "new_certificate").get_documents(),
+            &[document_reference()]
+        );
+    }
+
+    #[test]
+    fn test_issue_certificate_already_exists() {
+        let mut payload = issue_certificate_payload();
+        payload.set_id(CERTIFICATE_ID.to_string());
+        assert_invalid(
+            base_state().with_certificate(issued_certificate()),
+            CERTIFYING_BODY_KEY,
+            Action::IssueCertificate(payload),
+            "Certificate already exists",
+        );
+    }
+
+    #[test]
+    fn test_issue_certificate_no_agent() {
+        assert_invalid(
+            base_state(),
+            UNKNOWN_KEY,
+            Action::IssueCertificate(issue_certificate_payload()),
+            "No agent exists",
+        );
+    }
+
+    #[test]
+    fn test_issue_certificate_agent_not_associated() {
+        assert_invalid(
+            base_state(),
+            UNAFFILIATED_KEY,
+            Action::IssueCertificate(issue_certificate_payload()),
+            "Agent is not associated with an organization",
+        );
+    }
+
+    #[test]
+    fn test_issue_certificate_no_organization() {
+        assert_invalid(
+            base_state(),
+            ORPHAN_KEY,
+            Action::IssueCertificate(issue_certificate_payload()),
+            "No organization exists",
+        );
+    }
+
+    #[test]
+    fn test_issue_certificate_not_certifying_body() {
+        assert_invalid(
+            base_state(),
+            FACTORY_KEY,
+            Action::IssueCertificate(issue_certificate_payload()),
+            "is not a certifying body",
+        );
+    }
+
+    #[test]
+    fn test_issue_certificate_certifying_body_not_approved() {
+        assert_invalid(
+            base_state().with_organization(pending(certifying_body(&[(
+                CERTIFYING_BODY_KEY,
+                TRANSACTOR,
+            )]))),
+            CERTIFYING_BODY_KEY,
+            Action::IssueCertificate(issue_certificate_payload()),
+            "has not been approved by a registry operator",
+        );
+    }
+
+    #[test]
+    fn test_issue_certificate_not_transactor() {
+        assert_invalid(
+            base_state().with_organization(certifying_body(&[(CERTIFYING_BODY_KEY, ADMIN)])),
+            CERTIFYING_BODY_KEY,
+            Action::IssueCertificate(issue_certificate_payload()),
+            "Agent is not authorized to issue certificate",
+        );
+    }
+
+    #[test]
+    fn test_issue_certificate_within_authorization_window() {
+        let mut context = base_state()
+            .with_organization(limited(
+                certifying_body(&[(CERTIFYING_BODY_KEY, TRANSACTOR)]),
+                ACCREDITED_FROM,
+                VALID_TO,
+            ))
+            .with_block_time(VALID_FROM)
+            .build();
+        assert_valid(
+            &mut context,
+            CERTIFYING_BODY_KEY,
+            Action::IssueCertificate(issue_certificate_payload()),
+        );
+    }
+
+    #[test]
+    fn test_issue_certificate_authorization_expired() {
+        assert_invalid(
+            base_state()
+                .with_organization(limited(
+                    certifying_body(&[(CERTIFYING_BODY_KEY, TRANSACTOR)]),
+                    ACCREDITED_FROM,
+                    VALID_FROM - 1,
+                ))
+                .with_block_time(VALID_FROM),
+            CERTIFYING_BODY_KEY,
+            Action::IssueCertificate(issue_certificate_payload()),
+            "Agent is not authorized to issue certificate",
+        );
+    }
+
+    #[test]
+    fn test_issue_certificate_authorization_not_yet_valid() {
+        assert_invalid(
+            base_state()
+                .with_organization(limited(
+                    certifying_body(&[(CERTIFYING_BODY_KEY, TRANSACTOR)]),
+                    VALID_FROM + 1,
+                    0,
+                ))
+                .with_block_time(VALID_FROM),
+            CERTIFYING_BODY_KEY,
+            Action::IssueCertificate(issue_certificate_payload()),
+            "Agent is not authorized to issue certificate",
+        );
+    }
+
+    #[test]
+    fn test_issue_certificate_valid_to_before_valid_from() {
+        let mut payload = issue_certificate_payload();
+        payload.set_valid_to(VALID_FROM - 1);
+        assert_invalid(
+            base_state(),
+            CERTIFYING_BODY_KEY,
+            Action::IssueCertificate(payload),
+            "Valid to must be after valid from",
+        );
+    }
+
+    #[test]
+    fn test_issue_certificate_expired_at_block_time() {
+        assert_invalid(
+            base_state().with_block_time(VALID_TO + 1),
+            CERTIFYING_BODY_KEY,
+            Action::IssueCertificate(issue_certificate_payload()),
+            "is before the block time",
+        );
+    }
+
+    #[test]
+    fn test_issue_certificate_no_request() {
+        assert_invalid(
+            base_state(),
+            CERTIFYING_BODY_KEY,
+            Action::IssueCertificate(issue_certificate_from_request_payload()),
+            "Request does not exist",
+        );
+    }
+
+    #[test]
+    fn test_issue_certificate_request_addressed_to_other_certifying_body() {
+        let mut request = factory_request(proto::request::Request_Status::ACCEPTED);
+        request.set_certifying_body_id("other_certifying_body".to_string());
+        assert_invalid(
+            base_state().with_request(request),
+            CERTIFYING_BODY_KEY,
+            Action::IssueCertificate(issue_certificate_from_request_payload()),
+            "is not addressed to certifying body",
+        );
+    }
+
+    #[test]
+    fn test_issue_certificate_request_not_accepted() {
+        assert_invalid(
+            base_state().with_request(factory_request(proto::request::Request_Status::OPEN)),
+            CERTIFYING_BODY_KEY,
+            Action::IssueCertificate(issue_certificate_from_request_payload()),
+            "Only requests with status set to ACCEPTED or IN_PROGRESS can be certified",
+        );
+    }
+
+    #[test]
+    fn test_issue_certificate_no_audit() {
+        let mut payload = issue_certificate_from_request_payload();
+        payload.set_audit_id(AUDIT_ID.to_string());
+        assert_invalid(
+            base_state().with_request(factory_request(proto::request::Request_Status::ACCEPTED)),
+            CERTIFYING_BODY_KEY,
+            Action::IssueCertificate(payload),
+            "Audit does not exist",
+        );
+    }
+
+    #[test]
+    fn test_issue_certificate_audit_of_other_request() {
+        let request = factory_request(proto::request::Request_Status::ACCEPTED);
+        let mut other_request = request.clone();
+        other_request.set_id("other_request".to_string());
+        let mut payload = issue_certificate_from_request_payload();
+        payload.set_audit_id(AUDIT_ID.to_string());
+        assert_invalid(
+            base_state().with_request(request).with_audit(audit(
+                AUDIT_ID,
+                &other_request,
+                proto::audit::Audit_Outcome::PASSED,
+            )),
+            CERTIFYING_BODY_KEY,
+            Action::IssueCertificate(payload),
+            "was not recorded for request",
+        );
+    }
+
+    #[test]
+    fn test_issue_certificate_audit_failed() {
+        let request = factory_request(proto::request::Request_Status::ACCEPTED);
+        let mut payload = issue_certificate_from_request_payload();
+        payload.set_audit_id(AUDIT_ID.to_string());
+        assert_invalid(
+            base_state()
+                .with_audit(audit(
+                    AUDIT_ID,
+                    &request,
+                    proto::audit::Audit_Outcome::FAILED,
+                ))
+                .with_request(request),
+            CERTIFYING_BODY_KEY,
+            Action::IssueCertificate(payload),
+            "did not pass",
+        );
+    }
+
+    #[test]
+    fn test_issue_certificate_no_factory() {
+        let mut payload = issue_certificate_payload();
+        payload.set_factory_id(MISSING_ORGANIZATION_ID.to_string());
+        assert_invalid(
+            base_state(),
+            CERTIFYING_BODY_KEY,
+            Action::IssueCertificate(payload),
+            "Factory does not exist",
+        );
+    }
+
+    #[test]
+    fn test_issue_certificate_source_not_set() {
+        let mut payload = issue_certificate_payload();
+        payload.set_source(proto::payload::IssueCertificateAction_Source::UNSET_SOURCE);
+        assert_invalid(
+            base_state(),
+            CERTIFYING_BODY_KEY,
+            Action::IssueCertificate(payload),
+            "Issue Certificate source must be set",
+        );
+    }
+
+    #[test]
+    fn test_issue_certificate_no_standard() {
+        let mut payload = issue_certificate_payload();
+        payload.set_standard_id("missing_standard".to_string());
+        assert_invalid(
+            base_state(),
+            CERTIFYING_BODY_KEY,
+            Action::IssueCertificate(payload),
+            "No standard with ID missing_standard exists",
+        );
+    }
+
+    #[test]
+    fn test_issue_certificate_no_standard_version() {
+        let mut payload = issue_certificate_payload();
+        payload.set_standard_version("2.0".to_string());
+        assert_invalid(
+            base_state(),
+            CERTIFYING_BODY_KEY,
+            Action::IssueCertificate(payload),
+            "Version 2.0 does not exist for Standard",
+        );
+    }
+
+    #[test]
+    fn test_issue_certificate_not_accredited() {
+        assert_invalid(
+            base_state().with_organization(organization(
+                CERTIFYING_BODY_ID,
+                CERTIFYING_BODY,
+                &[(CERTIFYING_BODY_KEY, TRANSACTOR)],
+            )),
+            CERTIFYING_BODY_KEY,
+            Action::IssueCertificate(issue_certificate_payload()),
+            "does not hold a current accreditation",
+        );
+    }
+
+    #[test]
+    fn test_issue_certificate_valid_from_outside_accreditation() {
+        let mut payload = issue_certificate_payload();
+        payload.set_valid_from(ACCREDITED_FROM - 1);
+        assert_invalid(
+            base_state(),
+            CERTIFYING_BODY_KEY,
+            Action::IssueCertificate(payload),
+            "does not hold a current accreditation",
+        );
+    }
+
+    #[test]
+    fn test_issue_certificate_without_block_info() {
+        assert_invalid(
+            base_state_without_block_info(),
+            CERTIFYING_BODY_KEY,
+            Action::IssueCertificate(issue_certificate_payload()),
+            "No block time is available",
+        );
+    }
+
+    #[test]
+    fn test_issue_certificate_accreditation_expired_at_block_time() {
+        let mut payload = issue_certificate_payload();
+        payload.set_valid_to(ACCREDITED_TO * 3);
+        assert_invalid(
+            base_state().with_block_time(ACCREDITED_TO * 2),
+            CERTIFYING_BODY_KEY,
+            Action::IssueCertificate(payload),
+            "does not hold a current accreditation",
+        );
+    }
+
+    #[test]
+    fn test_issue_certificate_with_data_schema() {
+        let mut payload = issue_certificate_payload();
+        payload.set_certificate_data(certificate_data(&[
+            ("workers", "250"),
+            ("grade", "A"),
+            ("lot", "AB-12"),
+        ]));
+        let mut context = schema_state().build();
+        assert_valid(
+            &mut context,
+            CERTIFYING_BODY_KEY,
+            Action::IssueCertificate(payload),
+        );
+        let certificate = get_certificate(&mut context, "new_certificate");
+        assert_eq!(certificate.get_certificate_data().len(), 3);
+    }
+
+    #[test]
+    fn test_issue_certificate_invalid_certificate_data() {
+        let cases = vec![
+            (
+                vec![("workers", "250"), ("colour", "red")],
+                "Field colour is not part of the data schema of version 1.0",
+            ),
+            (
+                vec![("workers", "250"), ("workers", "300")],
+                "Field workers is set more than once",
+            ),
+            (
+                vec![("workers", "many")],
+                "Field workers must be of type INTEGER: many",
+            ),
+            (
+                vec![("workers", "250"), ("grade", "C")],
+                "Field grade must be one of A, B: C",
+            ),
+            (
+                vec![("workers", "250"), ("lot", "AB-12x")],
+                "Field lot does not match pattern [A-Z]{2}-[0-9]+: AB-12x",
+            ),
+            (
+                vec![("grade", "A")],
+                "Required field workers is missing from the certificate data",
+            ),
+        ];
+        for (data, expected) in cases {
+            let mut payload = issue_certificate_payload();
+            payload.set_certificate_data(certificate_data(&data));
+            assert_invalid(
+                schema_state(),
+                CERTIFYING_BODY_KEY,
+                Action::IssueCertificate(payload),
+                expected,
+            );
+        }
+    }
+
+    #[test]
+    fn test_issue_certificate_standard_retired() {
+        assert_invalid(
+            base_state().with_standard(retired_standard()),
+            CERTIFYING_BODY_KEY,
+            Action::IssueCertificate(issue_certificate_payload()),
+            "Standard standard has been retired",
+        );
+    }
+
+    #[test]
+    fn test_issue_certificate_valid_after_version_retirement() {
+        assert_invalid(
+            base_state().with_standard(retiring_standard(VALID_TO - 1)),
+            CERTIFYING_BODY_KEY,
+            Action::IssueCertificate(issue_certificate_payload()),
+            "Version 1.0 of Standard standard retires at 4999",
+        );
+    }
+
+    #[test]
+    fn test_issue_certificate_expires_by_version_retirement() {
+        let mut context = base_state()
+            .with_standard(retiring_standard(VALID_TO))
+            .build();
+        assert_valid(
+            &mut context,
+            CERTIFYING_BODY_KEY,
+            Action::IssueCertificate(issue_certificate_payload()),
+        );
+    }
+
+    /*
+     * RevokeCertificateAction, SuspendCertificateAction and ReinstateCertificateAction
+     */
+
+    /// The status changes of a certificate share their signer validation
+    fn certificate_status_actions() -> Vec<Action> {
+        vec![
+            Action::RevokeCertificate(revoke_certificate_payload()),
+            Action::SuspendCertificate(suspend_certificate_payload()),
+            Action::ReinstateCertificate(reinstate_certificate_payload()),
+        ]
+    }
+
+    #[test]
+    fn test_certificate_status_change_no_certificate() {
+        for action in certificate_status_actions() {
+            assert_invalid(
+                base_state(),
+                CERTIFYING_BODY_KEY,
+                action,
+                "Certificate does not exist",
+            );
+        }
+    }
+
+    #[test]
+    fn test_certificate_status_change_no_agent() {
+        for action in certificate_status_actions() {
+            assert_invalid(
+                base_state().with_certificate(suspended_certificate()),
+                UNKNOWN_KEY,
+                action,
+                "No agent exists",
+            );
+        }
+    }
+
+    #[test]
+    fn test_certificate_status_change_agent_not_associated() {
+        for action in certificate_status_actions() {
+            assert_invalid(
+                base_state().with_certificate(suspended_certificate()),
+                UNAFFILIATED_KEY,
+                action,
+                "Agent is not associated with an organization",
+            );
+        }
+    }
+
+    #[test]
+    fn test_certificate_status_change_not_issuer() {
+        for action in certificate_status_actions() {
+            assert_invalid(
+                base_state().with_certificate(suspended_certificate()),
+                FACTORY_KEY,
+                action,
+                "did not issue certificate",
+            );
+        }
+    }
+
+    #[test]
+    fn test_certificate_status_change_no_organization() {
+        let mut certificate = suspended_certificate();
+        certificate.set_certifying_body_id(MISSING_ORGANIZATION_ID.to_string());
+        for action in certificate_status_actions() {
+            assert_invalid(
+                base_state().with_certificate(certificate.clone()),
+                ORPHAN_KEY,
+                action,
+                "No organization exists",
+            );
+        }
+    }
+
+    #[test]
+    fn test_certificate_status_change_not_transactor() {
+        for (action, verb) in
+            certificate_status_actions()
+                .into_iter()
+                .zip(&["revoke", "suspend", "reinstate"])
+        {
+            assert_invalid(
+                base_state()
+                    .with_certificate(suspended_certificate())
+                    .with_organization(certifying_body(&[(CERTIFYING_BODY_KEY, ADMIN)])),
+                CERTIFYING_BODY_KEY,
+                action,
+                &format!("is not authorized to {} certificate", verb),
+            );
+        }
+    }
+
+    #[test]
+    fn test_revoke_certificate() {
+        let mut context = base_state().with_certificate(issued_certificate()).build();
+        assert_valid(
+            &mut context,
+            CERTIFYING_BODY_KEY,
+            Action::RevokeCertificate(revoke_certificate_payload()),
+        );
+        assert_eq!(
+            get_certificate(&mut context, CERTIFICATE_ID).get_status(),
+            proto::certificate::Certificate_Status::REVOKED
+        );
+    }
+
+    #[test]
+    fn test_certificate_of_version_0_1_is_valid() {
+        let mut certificate = issued_certificate();
+        certificate.clear_status();
+        let mut context = base_state().with_certificate(certificate).build();
+        assert_eq!(
+            get_certificate(&mut context, CERTIFICATE_ID).get_status(),
+            proto::certificate::Certificate_Status::VALID
+        );
+    }
+
+    #[test]
+    fn test_revoke_certificate_already_revoked() {
+        let mut certificate = issued_certificate();
+        certificate.set_status(proto::certificate::Certificate_Status::REVOKED);
+        assert_invalid(
+            base_state().with_certificate(certificate),
+            CERTIFYING_BODY_KEY,
+            Action::RevokeCertificate(revoke_certificate_payload()),
+            "has already been revoked",
+        );
+    }
+
+    #[test]
+    fn test_revoke_certificate_before_valid_from() {
+        let mut payload = revoke_certificate_payload();
+        payload.set_effective_date(VALID_FROM - 1);
+        assert_invalid(
+            base_state().with_certificate(issued_certificate()),
+            CERTIFYING_BODY_KEY,
+            Action::RevokeCertificate(payload),
+            "Revocation cannot take effect before the certificate is valid",
+        );
+    }
+
+    #[test]
+    fn test_revoke_suspended_certificate() {
+        let mut payload = revoke_certificate_payload();
+        payload.set_effective_date(2_500);
+        let mut context = base_state()
+            .with_certificate(suspended_certificate())
+            .build();
+        assert_valid(
+            &mut context,
+            CERTIFYING_BODY_KEY,
+            Action::RevokeCertificate(payload),
+        );
+        let certificate = get_certificate(&mut context, CERTIFICATE_ID);
+        assert_eq!(
+            certificate.get_status(),
+            proto::certificate::Certificate_Status::REVOKED
+        );
+        assert_eq!(certificate.get_suspensions()[0].get_reinstated_at(), 2_500);
+    }
+
+    #[test]
+    fn test_revoke_suspended_certificate_before_suspension() {
+        let mut payload = revoke_certificate_payload();
+        payload.set_effective_date(1_999);
+        assert_invalid(
+            base_state().with_certificate(suspended_certificate()),
+            CERTIFYING_BODY_KEY,
+            Action::RevokeCertificate(payload),
+            "Revocation must be after the suspension took effect",
+        );
+    }
+
+    #[test]
+    fn test_suspend_certificate() {
+        let mut context = base_state().with_certificate(issued_certificate()).build();
+        assert_valid(
+            &mut context,
+            CERTIFYING_BODY_KEY,
+            Action::SuspendCertificate(suspend_certificate_payload()),
+        );
+        assert_eq!(
+            get_certificate(&mut context, CERTIFICATE_ID).get_status(),
+            proto::certificate::Certificate_Status::SUSPENDED
+        );
+    }
+
+    #[test]
+    fn test_suspend_certificate_already_suspended() {
+        assert_invalid(
+            base_state().with_certificate(suspended_certificate()),
+            CERTIFYING_BODY_KEY,
+            Action::SuspendCertificate(suspend_certificate_payload()),
+            "cannot be suspended. Status: SUSPENDED",
+        );
+    }
+
+    #[test]
+    fn test_suspend_certificate_revoked() {
+        let mut certificate = issued_certificate();
+        certificate.set_status(proto::certificate::Certificate_Status::REVOKED);
+        assert_invalid(
+            base_state().with_certificate(certificate),
+            CERTIFYING_BODY_KEY,
+            Action::SuspendCertificate(suspend_certificate_payload()),
+            "cannot be suspended. Status: REVOKED",
+        );
+    }
+
+    #[test]
+    fn test_suspend_certificate_before_valid_from() {
+        let mut payload = suspend_certificate_payload();
+        payload.set_suspension_date(VALID_FROM - 1);
+        assert_invalid(
+            base_state().with_certificate(issued_certificate()),
+            CERTIFYING_BODY_KEY,
+            Action::SuspendCertificate(payload),
+            "Suspension cannot take effect before the certificate is valid",
+        );
+    }
+
+    #[test]
+    fn test_suspend_certificate_before_previous_reinstatement() {
+        let mut certificate = suspended_certificate();
+        certificate.mut_suspensions()[0].set_reinstated_at(3_000);
+        certificate.set_status(proto::certificate::Certificate_Status::VALID);
+        let mut payload = suspend_certificate_payload();
+        payload.set_suspension_date(2_999);
+        assert_invalid(
+            base_state().with_certificate(certificate),
+            CERTIFYING_BODY_KEY,
+            Action::SuspendCertificate(payload),
+            "Suspension cannot take effect before the previous suspension ended at 3000",
+        );
+    }
+
+    #[test]
+    fn test_suspend_certificate_after_previous_reinstatement() {
+        let mut certificate = suspended_certificate();
+        certificate.mut_suspensions()[0].set_reinstated_at(3_000);
+        certificate.set_status(proto::certificate::Certificate_Status::VALID);
+        let mut payload = suspend_certificate_payload();
+        payload.set_suspension_date(3_000);
+        let mut context = base_state().with_certificate(certificate).build();
+        assert_valid(
+            &mut context,
+            CERTIFYING_BODY_KEY,
+            Action::SuspendCertificate(payload),
+        );
+        assert_eq!(
+            get_certificate(&mut context, CERTIFICATE_ID)
+                .get_suspensions()
+                .len(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_reinstate_certificate() {
+        let mut context = base_state()
+            .with_certificate(suspended_certificate())
+            .build();
+        assert_valid(
+            &mut context,
+            CERTIFYING_BODY_KEY,
+            Action::ReinstateCertificate(reinstate_certificate_payload()),
+        );
+        let certificate = get_certificate(&mut context, CERTIFICATE_ID);
+        assert_eq!(
+            certificate.get_status(),
+            proto::certificate::Certificate_Status::VALID
+        );
+        assert_eq!(certificate.get_suspensions()[0].get_reinstated_at(), 3_000);
+    }
+
+    #[test]
+    fn test_reinstate_certificate_not_suspended() {
+        assert_invalid(
+            base_state().with_certificate(issued_certificate()),
+            CERTIFYING_BODY_KEY,
+            Action::ReinstateCertificate(reinstate_certificate_payload()),
+            "is not suspended",
+        );
+    }
+
+    #[test]
+    fn test_reinstate_certificate_no_suspension() {
+        let mut certificate = issued_certificate();
+        certificate.set_status(proto::certificate::Certificate_Status::SUSPENDED);
+        assert_invalid(
+            base_state().with_certificate(certificate),
+            CERTIFYING_BODY_KEY,
+            Action::ReinstateCertificate(reinstate_certificate_payload()),
+            "has no suspension to end",
+        );
+    }
+
+    #[test]
+    fn test_reinstate_certificate_before_suspension() {
+        let mut payload = reinstate_certificate_payload();
+        payload.set_reinstatement_date(1_999);
+        assert_invalid(
+            base_state().with_certificate(suspended_certificate()),
+            CERTIFYING_BODY_KEY,
+            Action::ReinstateCertificate(payload),
+            "Reinstatement must be after the suspension took effect",
+        );
+    }
+
+    /*
+     * RenewCertificateAction
+     */
+
+    #[test]
+    fn test_renew_certificate() {
+        let mut context = base_state().with_certificate(issued_certificate()).build();
+        assert_valid(
+            &mut context,
+            CERTIFYING_BODY_KEY,
+            Action::RenewCertificate(renew_certificate_payload()),
+        );
+        assert_eq!(
+            get_certificate(&mut context, CERTIFICATE_ID).get_next_certificate_id(),
+            "renewed_certificate"
+        );
+        assert_eq!(
+            get_certificate(&mut context, "renewed_certificate").get_previous_certificate_id(),
+            CERTIFICATE_ID
+        );
+    }
+
+    #[test]
+    fn test_renew_certificate_already_exists() {
+        let mut payload = renew_certificate_payload();
+        payload.set_id(CERTIFICATE_ID.to_string());
+        assert_invalid(
+            base_state().with_certificate(issued_certificate()),
+            CERTIFYING_BODY_KEY,
+            Action::RenewCertificate(payload),
+            "Certificate already exists",
+        );
+    }
+
+    #[test]
+    fn test_renew_certificate_no_previous_certificate() {
+        assert_invalid(
+            base_state(),
+            CERTIFYING_BODY_KEY,
+            Action::RenewCertificate(renew_certificate_payload()),
+            "Certificate does not exist",
+        );
+    }
+
+    #[test]
+    fn test_renew_certificate_no_agent() {
+        assert_invalid(
+            base_state().with_certificate(issued_certificate()),
+            UNKNOWN_KEY,
+            Action::RenewCertificate(renew_certificate_payload()),
+            "No agent exists",
+        );
+    }
+
+    #[test]
+    fn test_renew_certificate_agent_not_associated() {
+        assert_invalid(
+            base_state().with_certificate(issued_certificate()),
+            UNAFFILIATED_KEY,
+            Action::RenewCertificate(renew_certificate_payload()),
+            "Agent is not associated with an organization",
+        );
+    }
+
+    #[test]
+    fn test_renew_certificate_no_organization() {
+        assert_invalid(
+            base_state().with_certificate(issued_certificate()),
+            ORPHAN_KEY,
+            Action::RenewCertificate(renew_certificate_payload()),
+            "No organization exists",
+        );
+    }
+
+    #[test]
+    fn test_renew_certificate_not_certifying_body() {
+        assert_invalid(
+            base_state().with_certificate(issued_certificate()),
+            FACTORY_KEY,
+            Action::RenewCertificate(renew_certificate_payload()),
+            "is not a certifying body",
+        );
+    }
+
+    #[test]
+    fn test_renew_certificate_certifying_body_not_approved() {
+        assert_invalid(
+            base_state()
+                .with_certificate(issued_certificate())
+                .with_organization(pending(certifying_body(&[(
+                    CERTIFYING_BODY_KEY,
+                    TRANSACTOR,
+                )]))),
+            CERTIFYING_BODY_KEY,
+            Action::RenewCertificate(renew_certificate_payload()),
+            "has not been approved by a registry operator",
+        );
+    }
+
+    #[test]
+    fn test_renew_certificate_not_transactor() {
+        assert_invalid(
+            base_state()
+                .with_certificate(issued_certificate())
+                .with_organization(certifying_body(&[(CERTIFYING_BODY_KEY, ADMIN)])),
+            CERTIFYING_BODY_KEY,
+            Action::RenewCertificate(renew_certificate_payload()),
+            "Agent is not authorized to issue certificate",
+        );
+    }
+
+    #[test]
+    fn test_renew_certificate_not_issuer() {
+        let mut certificate = issued_certificate();
+        certificate.set_certifying_body_id("other_certifying_body".to_string());
+        assert_invalid(
+            base_state().with_certificate(certificate),
+            CERTIFYING_BODY_KEY,
+            Action::RenewCertificate(renew_certificate_payload()),
+            "did not issue certificate",
+        );
+    }
+
+    #[test]
+    fn test_renew_certificate_revoked() {
+        let mut certificate = issued_certificate();
+        certificate.set_status(proto::certificate::Certificate_Status::REVOKED);
+        assert_invalid(
+            base_state().with_certificate(certificate),
+            CERTIFYING_BODY_KEY,
+            Action::RenewCertificate(renew_certificate_payload()),
+            "has been revoked and cannot be renewed",
+        );
+    }
+
+    #[test]
+    fn test_renew_certificate_already_renewed() {
+        let mut certificate = issued_certificate();
+        certificate.set_next_certificate_id("other_certificate".to_string());
+        assert_invalid(
+            base_state().with_certificate(certificate),
+            CERTIFYING_BODY_KEY,
+            Action::RenewCertificate(renew_certificate_payload()),
+            "has already been renewed by certificate other_certificate",
+        );
+    }
+
+    #[test]
+    fn test_renew_certificate_valid_to_before_valid_from() {
+        let mut payload = renew_certificate_payload();
+        payload.set_valid_to(VALID_TO - 1);
+        assert_invalid(
+            base_state().with_certificate(issued_certificate()),
+            CERTIFYING_BODY_KEY,
+            Action::RenewCertificate(payload),
+            "Valid to must be after valid from",
+        );
+    }
+
+    #[test]
+    fn test_renew_certificate_expired_at_block_time() {
+        assert_invalid(
+            base_state()
+                .with_certificate(issued_certificate())
+                .with_block_time(ACCREDITED_TO + 1),
+            CERTIFYING_BODY_KEY,
+            Action::RenewCertificate(renew_certificate_payload()),
+            "is before the block time",
+        );
+    }
+
+    #[test]
+    fn test_renew_certificate_no_standard() {
+        let mut certificate = issued_certificate();
+        certificate.set_standard_id("missing_standard".to_string());
+        assert_invalid(
+            base_state().with_certificate(certificate),
+            CERTIFYING_BODY_KEY,
+            Action::RenewCertificate(renew_certificate_payload()),
+            "No standard with ID missing_standard exists",
+        );
+    }
+
+    #[test]
+    fn test_renew_certificate_no_standard_version() {
+        let mut payload = renew_certificate_payload();
+        payload.set_standard_version("3.0".to_string());
+        assert_invalid(
+            base_state().with_certificate(issued_certificate()),
+            CERTIFYING_BODY_KEY,
+            Action::RenewCertificate(payload),
+            "Version 3.0 does not exist for Standard",
+        );
+    }
+
+    #[test]
+    fn test_renew_certificate_older_standard_version() {
+        let mut certificate = issued_certificate();
+        certificate.set_standard_version("2.0".to_string());
+        assert_invalid(
+            base_state()
+                .with_certificate(certificate)
+                .with_standard(standard(
+                    STANDARD_ID,
+                    STANDARDS_BODY_ID,
+                    &[("1.0", STANDARD_APPROVAL_DATE), ("2.0", 2_000)],
+                )),
+            CERTIFYING_BODY_KEY,
+            Action::RenewCertificate(renew_certificate_payload()),
+            "Version 1.0 is older than version 2.0",
+        );
+    }
+
+    #[test]
+    fn test_renew_certificate_standard_retired() {
+        assert_invalid(
+            base_state()
+                .with_certificate(issued_certificate())
+                .with_standard(retired_standard()),
+            CERTIFYING_BODY_KEY,
+            Action::RenewCertificate(renew_certificate_payload()),
+            "Standard standard has been retired",
+        );
+    }
+
+    #[test]
+    fn test_renew_certificate_valid_after_version_retirement() {
+        assert_invalid(
+            base_state()
+                .with_certificate(issued_certificate())
+                .with_standard(retiring_standard(ACCREDITED_TO - 1)),
+            CERTIFYING_BODY_KEY,
+            Action::RenewCertificate(renew_certificate_payload()),
+            "Version 1.0 of Standard standard retires at 9999",
+        );
+    }
+
+    #[test]
+    fn test_renew_certificate_expires_by_version_retirement() {
+        let mut context = base_state()
+            .with_certificate(issued_certificate())
+            .with_standard(retiring_standard(ACCREDITED_TO))
+            .build();
+        assert_valid(
+            &mut context,
+            CERTIFYING_BODY_KEY,
+            Action::RenewCertificate(renew_certificate_payload()),
+        );
+    }
+
+    #[test]
+    fn test_renew_certificate_invalid_certificate_data() {
+        let mut payload = renew_certificate_payload();
+        payload.set_certificate_data(certificate_data(&[("grade", "A")]));
+        assert_invalid(
+            schema_state().with_certificate(issued_certificate()),
+            CERTIFYING_BODY_KEY,
+            Action::RenewCertificate(payload),
+            "Required field workers is missing from the certificate data",
+        );
+    }
+
+    #[test]
+    fn test_renew_certificate_not_accredited() {
+        let mut payload = renew_certificate_payload();
+        payload.set_standard_version("2.0".to_string());
+        assert_invalid(
+            base_state()
+                .with_certificate(issued_certificate())
+                .with_standard(standard(
+                    STANDARD_ID,
+                    STANDARDS_BODY_ID,
+                    &[("1.0", STANDARD_APPROVAL_DATE), ("2.0", 2_000)],
+                )),
+            CERTIFYING_BODY_KEY,
+            Action::RenewCertificate(payload),
+            "Certifying body is not accredited for Standard standard, version 2.0",
+        );
+    }
+
+    #[test]
+    fn test_renew_certificate_valid_from_outside_accreditation() {
+        let mut payload = renew_certificate_payload();
+        payload.set_valid_from(ACCREDITED_TO + 1);
+        payload.set_valid_to(ACCREDITED_TO * 2);
+        assert_invalid(
+            base_state().with_certificate(issued_certificate()),
+            CERTIFYING_BODY_KEY,
+            Action::RenewCertificate(payload),
+            "Certifying body is not accredited for Standard standard, version 1.0",
+        );
+    }
+
+    /*
+     * OpenRequestAction
+     */
+
+    #[test]
+    fn test_open_request() {
+        let mut context = base_state().build();
+        assert_valid(
+            &mut context,
+            FACTORY_KEY,
+            Action::OpenRequest(open_request_payload()),
+        );
+        assert_eq!(
+            get_request(&mut context, "new_request").get_status(),
+            proto::request::Request_Status::OPEN
+        );
+    }
+
+    #[test]
+    fn test_open_request_with_documents() {
+        let mut payload = open_request_payload();
+        payload.mut_documents().push(document_reference());
+        let mut context = base_state().build();
+        assert_valid(&mut context, FACTORY_KEY, Action::OpenRequest(payload));
+        assert_eq!(
+            get_request(&mut context, "new_request").get_documents(),
+            &[document_reference()]
+        );
+    }
+
+    #[test]
+    fn test_open_request_no_agent() {
+        assert_invalid(
+            base_state(),
+            UNKNOWN_KEY,
+            Action::OpenRequest(open_request_payload()),
+            "No agent exists",
+        );
+    }
+
+    #[test]
+    fn test_open_request_no_organization() {
+        assert_invalid(
+            base_state(),
+            ORPHAN_KEY,
+            Action::OpenRequest(open_request_payload()),
+            "No organization exists",
+        );
+    }
+
+    #[test]
+    fn test_open_request_not_factory() {
+        assert_invalid(
+            base_state(),
+            CERTIFYING_BODY_KEY,
+            Action::OpenRequest(open_request_payload()),
+            "is not a factory",
+        );
+    }
+
+    #[test]
+    fn test_open_request_not_transactor() {
+        assert_invalid(
+            base_state().with_organization(organization(
+                FACTORY_ID,
+                FACTORY,
+                &[(FACTORY_KEY, ADMIN)],
+            )),
+            FACTORY_KEY,
+            Action::OpenRequest(open_request_payload()),
+            "is not authorized to open a request",
+        );
+    }
+
+    #[test]
+    fn test_open_request_already_exists() {
+        let mut payload = open_request_payload();
+        payload.set_id(REQUEST_ID.to_string());
+        assert_invalid(
+            base_state().with_request(factory_request(proto::request::Request_Status::OPEN)),
+            FACTORY_KEY,
+            Action::OpenRequest(payload),
+            "Request already exists",
+        );
+    }
+
+    #[test]
+    fn test_open_request_no_standard() {
+        let mut payload = open_request_payload();
+        payload.set_standard_id("missing_standard".to_string());
+        assert_invalid(
+            base_state(),
+            FACTORY_KEY,
+            Action::OpenRequest(payload),
+            "No standard with ID missing_standard exists",
+        );
+    }
+
+    #[test]
+    fn test_open_request_addressed_to_non_certifying_body() {
+        let mut payload = open_request_payload();
+        payload.set_certifying_body_id(STANDARDS_BODY_ID.to_string());
+        assert_invalid(
+            base_state(),
+            FACTORY_KEY,
+            Action::OpenRequest(payload),
+            "is not a certifying body",
+        );
+    }
+
+    #[test]
+    fn test_open_request_certifying_body_not_approved() {
+        assert_invalid(
+            base_state().with_organization(pending(certifying_body(&[
+                (CERTIFYING_BODY_KEY, ADMIN),
+                (CERTIFYING_BODY_KEY, TRANSACTOR),
+            ]))),
+            FACTORY_KEY,
+            Action::OpenRequest(open_request_payload()),
+            "Organization certifying_body has not been approved by a registry operator",
+        );
+    }
+
+    #[test]
+    fn test_open_request_no_certifying_body() {
+        let mut payload = open_request_payload();
+        payload.set_certifying_body_id(MISSING_ORGANIZATION_ID.to_string());
+        assert_invalid(
+            base_state(),
+            FACTORY_KEY,
+            Action::OpenRequest(payload),
+            "No organization exists: missing_organization",
+        );
+    }
+
+    #[test]
+    fn test_open_request_date_before_block_time() {
+        assert_invalid(
+            base_state().with_block_time(VALID_FROM + MAX_TIMESTAMP_DRIFT + 1),
+            FACTORY_KEY,
+            Action::OpenRequest(open_request_payload()),
+            "Request date 1000 is more than 300 seconds before the block time",
+        );
+    }
+
+    #[test]
+    fn test_open_request_standard_retired() {
+        assert_invalid(
+            base_state().with_standard(retired_standard()),
+            FACTORY_KEY,
+            Action::OpenRequest(open_request_payload()),
+            "Standard standard has been retired",
+        );
+    }
+
+    #[test]
+    fn test_open_request_latest_version_retired() {
+        assert_invalid(
+            base_state().with_standard(retiring_standard(VALID_FROM)),
+            FACTORY_KEY,
+            Action::OpenRequest(open_request_payload()),
+            "Every version of Standard standard has been retired",
+        );
+    }
+
+    #[test]
+    fn test_open_request_of_version_0_1() {
+        let action = match decode("0.1", &open_request_payload_v0_1()) {
+            Ok(action) => action,
+            Err(err) => panic!("Expected a valid payload, got {:?}", err),
+        };
+        let mut context = base_state().build();
+        assert_valid(&mut context, FACTORY_KEY, action);
+        let request = get_request(&mut context, "new_request");
+        assert_eq!(request.get_status(), proto::request::Request_Status::OPEN);
+        assert_eq!(request.get_certifying_body_id(), "");
+    }
+
+    #[test]
+    fn test_open_request_without_certifying_body_of_version_0_2() {
+        match decode("0.2", &open_request_payload_v0_1()) {
+            Err(ApplyError::InvalidTransaction(msg)) => {
+                assert_eq!(msg, "open_request.certifying_body_id is empty")
+            }
+            Err(err) => panic!("Expected an invalid transaction, got {:?}", err),
+            Ok(_) => panic!("Expected an invalid transaction"),
+        }
+    }
+
+    /*
+     * ChangeRequestStatusAction
+     */
+
+    #[test]
+    fn test_change_request_status() {
+        let mut context = base_state()
+            .with_request(factory_request(proto::request::Request_Status::OPEN))
+            .build();
+        assert_valid(
+            &mut context,
+            FACTORY_KEY,
+            Action::ChangeRequestStatus(change_request_status_payload()),
+        );
+        assert_eq!(
+            get_request(&mut context, REQUEST_ID).get_status(),
+            proto::request::Request_Status::CLOSED
+        );
+    }
+
+    #[test]
+    fn test_change_request_status_no_request() {
+        assert_invalid(
+            base_state(),
+            FACTORY_KEY,
+            Action::ChangeRequestStatus(change_request_status_payload()),
+            "Request does not exists",
+        );
+    }
+
+    #[test]
+    fn test_change_request_status_no_agent() {
+        assert_invalid(
+            base_state().with_request(factory_request(proto::request::Request_Status::OPEN)),
+            UNKNOWN_KEY,
+            Action::ChangeRequestStatus(change_request_status_payload()),
+            "No agent exists",
+        );
+    }
+
+    #[test]
+    fn test_change_request_status_no_organization() {
+        assert_invalid(
+            base_state().with_request(factory_request(proto::request::Request_Status::OPEN)),
+            ORPHAN_KEY,
+            Action::ChangeRequestStatus(change_request_status_payload()),
+            "No organization exists",
+        );
+    }
+
+    #[test]
+    fn test_change_request_status_not_transactor() {
+        assert_invalid(
+            base_state()
+                .with_request(factory_request(proto::request::Request_Status::OPEN))
+                .with_organization(organization(FACTORY_ID, FACTORY, &[(FACTORY_KEY, ADMIN)])),
+            FACTORY_KEY,
+            Action::ChangeRequestStatus(change_request_status_payload()),
+            "Agent factory_agent is not authorized to update request request",
+        );
+    }
+
+    #[test]
+    fn test_change_request_status_of_other_factory() {
+        assert_invalid(
+            base_state()
+                .with_request(factory_request(proto::request::Request_Status::OPEN))
+                .with_agent(agent("other_factory_agent", "other_factory"))
+                .with_organization(organization(
+                    "other_factory",
+                    FACTORY,
+                    &[("other_factory_agent", TRANSACTOR)],
+                )),
+            "other_factory_agent",
+            Action::ChangeRequestStatus(change_request_status_payload()),
+            "Agent other_factory is not authorized to update request factory",
+        );
+    }
+
+    #[test]
+    fn test_change_request_status_finalized() {
+        for status in &[
+            proto::request::Request_Status::CLOSED,
+            proto::request::Request_Status::CERTIFIED,
+            proto::request::Request_Status::DECLINED,
+        ] {
+            assert_invalid(
+                base_state().with_request(factory_request(*status)),
+                FACTORY_KEY,
+                Action::ChangeRequestStatus(change_request_status_payload()),
+                "the request status can not be modified again",
+            );
+        }
+    }
+
+    #[test]
+    fn test_change_request_status_to_in_progress() {
+        let mut payload = change_request_status_payload();
+        payload.set_status(proto::request::Request_Status::IN_PROGRESS);
+        let mut context = base_state()
+            .with_request(factory_request(proto::request::Request_Status::ACCEPTED))
+            .build();
+        assert_valid(
+            &mut context,
+            FACTORY_KEY,
+            Action::ChangeRequestStatus(payload),
+        );
+        assert_eq!(
+            get_request(&mut context, REQUEST_ID).get_status(),
+            proto::request::Request_Status::IN_PROGRESS
+        );
+    }
+
+    #[test]
+    fn test_change_request_status_to_in_progress_not_accepted() {
+        for status in &[
+            proto::request::Request_Status::OPEN,
+            proto::request::Request_Status::IN_PROGRESS,
+        ] {
+            let mut payload = change_request_status_payload();
+            payload.set_status(proto::request::Request_Status::IN_PROGRESS);
+            assert_invalid(
+                base_state().with_request(factory_request(*status)),
+                FACTORY_KEY,
+                Action::ChangeRequestStatus(payload),
+                "Only an ACCEPTED request may be set to IN_PROGRESS",
+            );
+        }
+    }
+
+    /*
+     * AcceptRequestAction, DeclineRequestAction and RecordAuditAction
+     */
+
+    /// The actions of a certifying body on a request share their signer validation
+    fn certifying_body_request_actions() -> Vec<Action> {
+        vec![
+            Action::AcceptRequest(accept_request_payload()),
+            Action::DeclineRequest(decline_request_payload()),
+            Action::RecordAudit(record_audit_payload()),
+        ]
+    }
+
+    #[test]
+    fn test_certifying_body_request_action_no_request() {
+        for action in certifying_body_request_actions() {
+            assert_invalid(
+                base_state(),
+                CERTIFYING_BODY_KEY,
+                action,
+                "Request does not exists",
+            );
+        }
+    }
+
+    #[test]
+    fn test_certifying_body_request_action_no_agent() {
+        for action in certifying_body_request_actions() {
+            assert_invalid(
+                base_state().with_request(factory_request(proto::request::Request_Status::OPEN)),
+                UNKNOWN_KEY,
+                action,
+                "No agent exists",
+            );
+        }
+    }
+
+    #[test]
+    fn test_certifying_body_request_action_not_addressed_to_signer() {
+        for action in certifying_body_request_actions() {
+            assert_invalid(
+                base_state().with_request(factory_request(proto::request::Request_Status::OPEN)),
+                FACTORY_KEY,
+                action,
+                "is not addressed to the organization of agent",
+            );
+        }
+    }
+
+    #[test]
+    fn test_certifying_body_request_action_no_organization() {
+        let mut request = factory_request(proto::request::Request_Status::OPEN);
+        request.set_certifying_body_id(MISSING_ORGANIZATION_ID.to_string());
+        for action in certifying_body_request_actions() {
+            assert_invalid(
+                base_state().with_request(request.clone()),
+                ORPHAN_KEY,
+                action,
+                "No organization exists",
+            );
+        }
+    }
+
+    #[test]
+    fn test_certifying_body_request_action_not_transactor() {
+        for action in certifying_body_request_actions() {
+            assert_invalid(
+                base_state()
+                    .with_request(factory_request(proto::request::Request_Status::OPEN))
+                    .with_organization(certifying_body(&[(CERTIFYING_BODY_KEY, ADMIN)])),
+                CERTIFYING_BODY_KEY,
+                action,
+                "is not authorized to update request",
+            );
+        }
+    }
+
+    #[test]
+    fn test_accept_request() {
+        let mut context = base_state()
+            .with_request(factory_request(proto::request::Request_Status::OPEN))
+            .build();
+        assert_valid(
+            &mut context,
+            CERTIFYING_BODY_KEY,
+            Action::AcceptRequest(accept_request_payload()),
+        );
+        assert_eq!(
+            get_request(&mut context, REQUEST_ID).get_status(),
+            proto::request::Request_Status::ACCEPTED
+        );
+    }
+
+    #[test]
+    fn test_accept_request_event() {
+        let mut context = base_state()
+            .with_request(factory_request(proto::request::Request_Status::OPEN))
+            .build();
+        assert_valid(
+            &mut context,
+            CERTIFYING_BODY_KEY,
+            Action::AcceptRequest(accept_request_payload()),
+        );
+        assert_eq!(context.events().len(), 1);
+        let (ref event_type, ref attributes) = context.events()[0];
+        assert_eq!(event_type, events::REQUEST_STATUS_CHANGED);
+        assert!(attributes.contains(&("status".to_string(), "ACCEPTED".to_string())));
+    }
+
+    #[test]
+    fn test_accept_request_of_version_0_1() {
+        let mut context = base_state()
+            .with_request(legacy_request(proto::request::Request_Status::OPEN))
+            .build();
+        assert_valid(
+            &mut context,
+            CERTIFYING_BODY_KEY,
+            Action::AcceptRequest(accept_request_payload()),
+        );
+        let request = get_request(&mut context, REQUEST_ID);
+        assert_eq!(
+            request.get_status(),
+            proto::request::Request_Status::ACCEPTED
+        );
+        assert_eq!(request.get_certifying_body_id(), CERTIFYING_BODY_ID);
+
+        let mut payload = change_request_status_payload();
+        payload.set_status(proto::request::Request_Status::IN_PROGRESS);
+        assert_valid(
+            &mut context,
+            FACTORY_KEY,
+            Action::ChangeRequestStatus(payload),
+        );
+    }
+
+    #[test]
+    fn test_certifying_body_request_action_of_version_0_1_not_certifying_body() {
+        for action in certifying_body_request_actions() {
+            assert_invalid(
+                base_state().with_request(legacy_request(proto::request::Request_Status::OPEN)),
+                FACTORY_KEY,
+                action,
+                "Organization factory is not a certifying body",
+            );
+        }
+    }
+
+    #[test]
+    fn test_accept_request_not_open() {
+        assert_invalid(
+            base_state().with_request(factory_request(proto::request::Request_Status::ACCEPTED)),
+            CERTIFYING_BODY_KEY,
+            Action::AcceptRequest(accept_request_payload()),
+            "Only OPEN requests can be accepted",
+        );
+    }
+
+    #[test]
+    fn test_decline_request() {
+        let mut context = base_state()
+            .with_request(factory_request(proto::request::Request_Status::OPEN))
+            .build();
+        assert_valid(
+            &mut context,
+            CERTIFYING_BODY_KEY,
+            Action::DeclineRequest(decline_request_payload()),
+        );
+        let request = get_request(&mut context, REQUEST_ID);
+        assert_eq!(
+            request.get_status(),
+            proto::request::Request_Status::DECLINED
+        );
+        assert_eq!(request.get_decline_reason(), "Outside of our scope");
+    }
+
+    #[test]
+    fn test_decline_request_finalized() {
+        for status in &[
+            proto::request::Request_Status::CLOSED,
+            proto::request::Request_Status::CERTIFIED,
+            proto::request::Request_Status::DECLINED,
+        ] {
+            assert_invalid(
+                base_state().with_request(factory_request(*status)),
+                CERTIFYING_BODY_KEY,
+                Action::DeclineRequest(decline_request_payload()),
+                "the request status can not be modified again",
+            );
+        }
+    }
+
+    #[test]
+    fn test_record_audit() {
+        let mut context = base_state()
+            .with_request(factory_request(proto::request::Request_Status::ACCEPTED))
+            .build();
+        assert_valid(
+            &mut context,
+            CERTIFYING_BODY_KEY,
+            Action::RecordAudit(record_audit_payload()),
+        );
+        let audit = CertState::new(&mut context)
+            .get_audit("new_audit")
+            .unwrap()
+            .expect("Audit not found");
+        assert_eq!(audit.get_factory_id(), FACTORY_ID);
+        assert_eq!(audit.get_auditor_public_key(), CERTIFYING_BODY_KEY);
+    }
+
+    #[test]
+    fn test_record_audit_already_exists() {
+        let request = factory_request(proto::request::Request_Status::ACCEPTED);
+        let mut payload = record_audit_payload();
+        payload.set_id(AUDIT_ID.to_string());
+        assert_invalid(
+            base_state()
+                .with_audit(audit(
+                    AUDIT_ID,
+                    &request,
+                    proto::audit::Audit_Outcome::PASSED,
+                ))
+                .with_request(request),
+            CERTIFYING_BODY_KEY,
+            Action::RecordAudit(payload),
+            "Audit already exists",
+        );
+    }
+
+    #[test]
+    fn test_record_audit_of_version_0_1() {
+        let mut context = base_state()
+            .with_request(legacy_request(proto::request::Request_Status::IN_PROGRESS))
+            .build();
+        assert_valid(
+            &mut context,
+            CERTIFYING_BODY_KEY,
+            Action::RecordAudit(record_audit_payload()),
+        );
+        assert_eq!(
+            get_request(&mut context, REQUEST_ID).get_certifying_body_id(),
+            CERTIFYING_BODY_ID
+        );
+    }
+
+    #[test]
+    fn test_record_audit_request_not_accepted() {
+        assert_invalid(
+            base_state().with_request(factory_request(proto::request::Request_Status::OPEN)),
+            CERTIFYING_BODY_KEY,
+            Action::RecordAudit(record_audit_payload()),
+            "Only requests with status set to ACCEPTED or IN_PROGRESS can be audited",
+        );
+    }
+
+    #[test]
+    fn test_record_audit_date_before_block_time() {
+        assert_invalid(
+            base_state()
+                .with_request(factory_request(proto::request::Request_Status::ACCEPTED))
+                .with_block_time(VALID_FROM + MAX_TIMESTAMP_DRIFT + 1),
+            CERTIFYING_BODY_KEY,
+            Action::RecordAudit(record_audit_payload()),
+            "Audit date 1000 is more than 300 seconds before the block time",
+        );
+    }
+
+    /*
+     * RaiseNonConformanceAction, SubmitCorrectiveActionAction and CloseNonConformanceAction
+     */
+
+    /// The non-conformance actions share their signer validation
+    fn non_conformance_actions() -> Vec<Action> {
+        vec![
+            Action::RaiseNonConformance(raise_non_conformance_payload()),
+            Action::SubmitCorrectiveAction(submit_corrective_action_payload()),
+            Action::CloseNonConformance(close_non_conformance_payload()),
+        ]
+    }
+
+    fn non_conformance_state() -> StateBuilder {
+        base_state().with_non_conformance(raised_non_conformance(
+            proto::non_conformance::NonConformance_Status::CORRECTIVE_ACTION_SUBMITTED,
+        ))
+    }
+
+    #[test]
+    fn test_non_conformance_action_no_agent() {
+        for action in non_conformance_actions() {
+            assert_invalid(
+                non_conformance_state(),
+                UNKNOWN_KEY,
+                action,
+                "No agent exists",
+            );
+        }
+    }
+
+    #[test]
+    fn test_non_conformance_action_agent_not_associated() {
+        for action in non_conformance_actions() {
+            assert_invalid(
+                non_conformance_state(),
+                UNAFFILIATED_KEY,
+                action,
+                "Agent is not associated with an organization",
+            );
+        }
+    }
+
+    #[test]
+    fn test_non_conformance_action_no_organization() {
+        for action in non_conformance_actions() {
+            assert_invalid(
+                non_conformance_state(),
+                ORPHAN_KEY,
+                action,
+                "No organization exists",
+            );
+        }
+    }
+
+    #[test]
+    fn test_non_conformance_action_not_transactor() {
+        let state = || {
+            non_conformance_state()
+                .with_organization(certifying_body(&[(CERTIFYING_BODY_KEY, ADMIN)]))
+                .with_organization(organization(FACTORY_ID, FACTORY, &[(FACTORY_KEY, ADMIN)]))
+        };
+        for (action, signer_public_key) in non_conformance_actions().into_iter().zip(&[
+            CERTIFYING_BODY_KEY,
+            FACTORY_KEY,
+            CERTIFYING_BODY_KEY,
+        ]) {
+            assert_invalid(
+                state(),
+                signer_public_key,
+                action,
+                "permission within organization",
+            );
+        }
+    }
+
+    #[test]
+    fn test_raise_non_conformance() {
+        let mut context = base_state().build();
+        assert_valid(
+            &mut context,
+            CERTIFYING_BODY_KEY,
+            Action::RaiseNonConformance(raise_non_conformance_payload()),
+        );
+        let non_conformance = CertState::new(&mut context)
+            .get_non_conformance("new_non_conformance")
+            .unwrap()
+            .expect("Non-conformance not found");
+        assert_eq!(
+            non_conformance.get_status(),
+            proto::non_conformance::NonConformance_Status::OPEN
+        );
+    }
+
+    #[test]
+    fn test_raise_non_conformance_already_exists() {
+        let mut payload = raise_non_conformance_payload();
+        payload.set_id(NON_CONFORMANCE_ID.to_string());
+        assert_invalid(
+            non_conformance_state(),
+            CERTIFYING_BODY_KEY,
+            Action::RaiseNonConformance(payload),
+            "Non-conformance already exists",
+        );
+    }
+
+    #[test]
+    fn test_raise_non_conformance_not_certifying_body() {
+        assert_invalid(
+            base_state(),
+            FACTORY_KEY,
+            Action::RaiseNonConformance(raise_non_conformance_payload()),
+            "is not a certifying body",
+        );
+    }
+
+    #[test]
+    fn test_raise_non_conformance_no_factory() {
+        for factory_id in &[MISSING_ORGANIZATION_ID, STANDARDS_BODY_ID] {
+            let mut payload = raise_non_conformance_payload();
+            payload.set_factory_id(factory_id.to_string());
+            assert_invalid(
+                base_state(),
+                CERTIFYING_BODY_KEY,
+                Action::RaiseNonConformance(payload),
+                "Factory does not exist",
+            );
+        }
+    }
+
+    #[test]
+    fn test_raise_non_conformance_no_audit() {
+        let mut payload = raise_non_conformance_payload();
+        payload.set_audit_id(AUDIT_ID.to_string());
+        assert_invalid(
+            base_state(),
+            CERTIFYING_BODY_KEY,
+            Action::RaiseNonConformance(payload),
+            "Audit does not exist",
+        );
+    }
+
+    #[test]
+    fn test_raise_non_conformance_audit_of_other_factory() {
+        let mut request = factory_request(proto::request::Request_Status::ACCEPTED);
+        request.set_factory_id("other_factory".to_string());
+        let mut payload = raise_non_conformance_payload();
+        payload.set_audit_id(AUDIT_ID.to_string());
+        assert_invalid(
+            base_state().with_audit(audit(
+                AUDIT_ID,
+                &request,
+                proto::audit::Audit_Outcome::FAILED,
+            )),
+            CERTIFYING_BODY_KEY,
+            Action::RaiseNonConformance(payload),
+            "was not performed by certifying body",
+        );
+    }
+
+    #[test]
+    fn test_raise_non_conformance_date_before_block_time() {
+        assert_invalid(
+            base_state().with_block_time(VALID_FROM + MAX_TIMESTAMP_DRIFT + 1),
+            CERTIFYING_BODY_KEY,
+            Action::RaiseNonConformance(raise_non_conformance_payload()),
+            "Raised at date 1000 is more than 300 seconds before the block time",
+        );
+    }
+
+    #[test]
+    fn test_submit_corrective_action() {
+        let mut context = base_state()
+            .with_non_conformance(raised_non_conformance(
+                proto::non_conformance::NonConformance_Status::OPEN,
+            ))
+            .build();
+        assert_valid(
+            &mut context,
+            FACTORY_KEY,
+            Action::SubmitCorrectiveAction(submit_corrective_action_payload()),
+        );
+        let non_conformance = CertState::new(&mut context)
+            .get_non_conformance(NON_CONFORMANCE_ID)
+            .unwrap()
+            .expect("Non-conformance not found");
+        assert_eq!(
+            non_conformance.get_status(),
+            proto::non_conformance::NonConformance_Status::CORRECTIVE_ACTION_SUBMITTED
+        );
+    }
+
+    #[test]
+    fn test_submit_corrective_action_no_non_conformance() {
+        assert_invalid(
+            base_state(),
+            FACTORY_KEY,
+            Action::SubmitCorrectiveAction(submit_corrective_action_payload()),
+            "Non-conformance does not exist",
+        );
+    }
+
+    #[test]
+    fn test_submit_corrective_action_other_organization() {
+        assert_invalid(
+            non_conformance_state(),
+            CERTIFYING_BODY_KEY,
+            Action::SubmitCorrectiveAction(submit_corrective_action_payload()),
+            "was not raised against organization",
+        );
+    }
+
+    #[test]
+    fn test_submit_corrective_action_closed() {
+        assert_invalid(
+            base_state().with_non_conformance(raised_non_conformance(
+                proto::non_conformance::NonConformance_Status::CLOSED,
+            )),
+            FACTORY_KEY,
+            Action::SubmitCorrectiveAction(submit_corrective_action_payload()),
+            "is already CLOSED",
+        );
+    }
+
+    #[test]
+    fn test_submit_corrective_action_date_before_block_time() {
+        assert_invalid(
+            non_conformance_state().with_block_time(VALID_FROM + MAX_TIMESTAMP_DRIFT + 1),
+            FACTORY_KEY,
+            Action::SubmitCorrectiveAction(submit_corrective_action_payload()),
+            "Submitted at date 1000 is more than 300 seconds before the block time",
+        );
+    }
+
+    #[test]
+    fn test_close_non_conformance() {
+        let mut context = non_conformance_state().build();
+        assert_valid(
+            &mut context,
+            CERTIFYING_BODY_KEY,
+            Action::CloseNonConformance(close_non_conformance_payload()),
+        );
+        let non_conformance = CertState::new(&mut context)
+            .get_non_conformance(NON_CONFORMANCE_ID)
+            .unwrap()
+            .expect("Non-conformance not found");
+        assert_eq!(
+            non_conformance.get_status(),
+            proto::non_conformance::NonConformance_Status::CLOSED
+        );
+    }
+
+    #[test]
+    fn test_close_non_conformance_no_non_conformance() {
+        assert_invalid(
+            base_state(),
+            CERTIFYING_BODY_KEY,
+            Action::CloseNonConformance(close_non_conformance_payload()),
+            "Non-conformance does not exist",
+        );
+    }
+
+    #[test]
+    fn test_close_non_conformance_other_organization() {
+        assert_invalid(
+            non_conformance_state(),
+            FACTORY_KEY,
+            Action::CloseNonConformance(close_non_conformance_payload()),
+            "was not raised by organization",
+        );
+    }
+
+    #[test]
+    fn test_close_non_conformance_no_corrective_action() {
+        assert_invalid(
+            base_state().with_non_conformance(raised_non_conformance(
+                proto::non_conformance::NonConformance_Status::OPEN,
+            )),
+            CERTIFYING_BODY_KEY,
+            Action::CloseNonConformance(close_non_conformance_payload()),
+            "Only non-conformances with a submitted corrective action can be closed",
+        );
+    }
+
+    #[test]
+    fn test_close_non_conformance_date_before_block_time() {
+        assert_invalid(
+            non_conformance_state().with_block_time(VALID_FROM + MAX_TIMESTAMP_DRIFT + 1),
+            CERTIFYING_BODY_KEY,
+            Action::CloseNonConformance(close_non_conformance_payload()),
+            "Closed at date 1000 is more than 300 seconds before the block time",
+        );
+    }
+
+    /*
+     * CreateStandardAction
+     */
+
+    #[test]
+    fn test_create_standard() {
+        let mut context = base_state().build();
+        assert_valid(
+            &mut context,
+            STANDARDS_BODY_KEY,
+            Action::CreateStandard(create_standard_payload()),
+        );
+        let standard = CertState::new(&mut context)
+            .get_standard("new_standard")
+            .unwrap()
+            .expect("Standard not found");
+        assert_eq!(standard.get_organization_id(), STANDARDS_BODY_ID);
+        assert_eq!(
+            standard.get_status(),
+            proto::standard::Standard_Status::ACTIVE
+        );
+    }
+
+    #[test]
+    fn test_create_standard_already_exists() {
+        let mut payload = create_standard_payload();
+        payload.set_standard_id(STANDARD_ID.to_string());
+        assert_invalid(
+            base_state(),
+            STANDARDS_BODY_KEY,
+            Action::CreateStandard(payload),
+            "Standard already exists",
+        );
+    }
+
+    #[test]
+    fn test_create_standard_no_agent() {
+        assert_invalid(
+            base_state(),
+            UNKNOWN_KEY,
+            Action::CreateStandard(create_standard_payload()),
+            "No agent exists",
+        );
+    }
+
+    #[test]
+    fn test_create_standard_agent_not_associated() {
+        assert_invalid(
+            base_state(),
+            UNAFFILIATED_KEY,
+            Action::CreateStandard(create_standard_payload()),
+            "Agent is not associated with an organization",
+        );
+    }
+
+    #[test]
+    fn test_create_standard_no_organization() {
+        assert_invalid(
+            base_state(),
+            ORPHAN_KEY,
+            Action::CreateStandard(create_standard_payload()),
+            "No organization exists",
+        );
+    }
+
+    #[test]
+    fn test_create_standard_not_standards_body() {
+        assert_invalid(
+            base_state(),
+            CERTIFYING_BODY_KEY,
+            Action::CreateStandard(create_standard_payload()),
+            "Organization associated with agent cannot create standards",
+        );
+    }
+
+    #[test]
+    fn test_create_standard_standards_body_not_approved() {
+        assert_invalid(
+            base_state().with_organization(pending(organization(
+                STANDARDS_BODY_ID,
+                STANDARDS_BODY,
+                &[(STANDARDS_BODY_KEY, TRANSACTOR)],
+            ))),
+            STANDARDS_BODY_KEY,
+            Action::CreateStandard(create_standard_payload()),
+            "has not been approved by a registry operator",
+        );
+    }
+
+    #[test]
+    fn test_create_standard_not_transactor() {
+        assert_invalid(
+            base_state().with_organization(organization(
+                STANDARDS_BODY_ID,
+                STANDARDS_BODY,
+                &[(STANDARDS_BODY_KEY, ADMIN)],
+            )),
+            STANDARDS_BODY_KEY,
+            Action::CreateStandard(create_standard_payload()),
+            "Agent is not authorized to create a certification standard",
+        );
+    }
+
+    /*
+     * UpdateStandardAction
+     */
+
+    #[test]
+    fn test_update_standard() {
+        let mut context = base_state().build();
+        assert_valid(
+            &mut context,
+            STANDARDS_BODY_KEY,
+            Action::UpdateStandard(update_standard_payload()),
+        );
+        let standard = CertState::new(&mut context)
+            .get_standard(STANDARD_ID)
+            .unwrap()
+            .expect("Standard not found");
+        assert_eq!(standard.get_versions().len(), 2);
+    }
+
+    #[test]
+    fn test_update_standard_with_data_schema() {
+        let mut payload = update_standard_payload();
+        payload.set_data_schema(protobuf::RepeatedField::from_vec(data_schema()));
+        let mut context = base_state().build();
+        assert_valid(
+            &mut context,
+            STANDARDS_BODY_KEY,
+            Action::UpdateStandard(payload),
+        );
+        let standard = CertState::new(&mut context)
+            .get_standard(STANDARD_ID)
+            .unwrap()
+            .expect("Standard not found");
+        assert!(standard.get_versions()[0].get_data_schema().is_empty());
+        assert_eq!(
+            standard.get_versions()[1].get_data_schema(),
+            &data_schema()[..]
+        );
+    }
+
+    #[test]
+    fn test_update_standard_no_standard() {
+        let mut payload = update_standard_payload();
+        payload.set_standard_id("missing_standard".to_string());
+        assert_invalid(
+            base_state(),
+            STANDARDS_BODY_KEY,
+            Action::UpdateStandard(payload),
+            "Standard missing_standard does not exist",
+        );
+    }
+
+    #[test]
+    fn test_update_standard_version_already_exists() {
+        let mut payload = update_standard_payload();
+        payload.set_version("1.0".to_string());
+        assert_invalid(
+            base_state(),
+            STANDARDS_BODY_KEY,
+            Action::UpdateStandard(payload),
+            "Version already exists",
+        );
+    }
+
+    #[test]
+    fn test_update_standard_no_agent() {
+        assert_invalid(
+            base_state(),
+            UNKNOWN_KEY,
+            Action::UpdateStandard(update_standard_payload()),
+            "Agent does not exist",
+        );
+    }
+
+    #[test]
+    fn test_update_standard_agent_not_associated() {
+        assert_invalid(
+            base_state(),
+            UNAFFILIATED_KEY,
+            Action::UpdateStandard(update_standard_payload()),
+            "Agent is not associated with an organization",
+        );
+    }
+
+    #[test]
+    fn test_update_standard_no_organization() {
+        assert_invalid(
+            base_state(),
+            ORPHAN_KEY,
+            Action::UpdateStandard(update_standard_payload()),
+            "Organization does not exist",
+        );
+    }
+
+    #[test]
+    fn test_update_standard_not_standards_body() {
+        assert_invalid(
+            base_state(),
+            CERTIFYING_BODY_KEY,
+            Action::UpdateStandard(update_standard_payload()),
+            "Organization associated with agent cannot create standards",
+        );
+    }
+
+    #[test]
+    fn test_update_standard_standards_body_not_approved() {
+        assert_invalid(
+            base_state().with_organization(pending(organization(
+                STANDARDS_BODY_ID,
+                STANDARDS_BODY,
+                &[(STANDARDS_BODY_KEY, TRANSACTOR)],
+            ))),
+            STANDARDS_BODY_KEY,
+            Action::UpdateStandard(update_standard_payload()),
+            "has not been approved by a registry operator",
+        );
+    }
+
+    #[test]
+    fn test_update_standard_not_transactor() {
+        assert_invalid(
+            base_state().with_organization(organization(
+                STANDARDS_BODY_ID,
+                STANDARDS_BODY,
+                &[(STANDARDS_BODY_KEY, ADMIN)],
+            )),
+            STANDARDS_BODY_KEY,
+            Action::UpdateStandard(update_standard_payload()),
+            "Agent is not authorized to create a certification standard",
+        );
+    }
+
+    #[test]
+    fn test_update_standard_of_other_standards_body() {
+        assert_invalid(
+            base_state().with_standard(standard(
+                STANDARD_ID,
+                "other_standards_body",
+                &[("1.0", STANDARD_APPROVAL_DATE)],
+            )),
+            STANDARDS_BODY_KEY,
+            Action::UpdateStandard(update_standard_payload()),
+            "did not create the certification standard",
+        );
+    }
+
+    #[test]
+    fn test_update_standard_retired() {
+        assert_invalid(
+            base_state().with_standard(retired_standard()),
+            STANDARDS_BODY_KEY,
+            Action::UpdateStandard(update_standard_payload()),
+            "Standard standard has been retired",
+        );
+    }
+
+    /*
+     * RetireStandardVersionAction and RetireStandardAction
+     */
+
+    #[test]
+    fn test_retire_standard_version() {
+        let mut context = base_state().build();
+        assert_valid(
+            &mut context,
+            STANDARDS_BODY_KEY,
+            Action::RetireStandardVersion(retire_standard_version_payload(VALID_TO)),
+        );
+        let standard = get_standard(&mut context, STANDARD_ID);
+        assert_eq!(standard.get_versions()[0].get_retirement_date(), VALID_TO);
+    }
+
+    #[test]
+    fn test_retire_standard_version_reschedule() {
+        let mut context = base_state()
+            .with_standard(retiring_standard(VALID_TO))
+            .with_block_time(VALID_FROM)
+            .build();
+        assert_valid(
+            &mut context,
+            STANDARDS_BODY_KEY,
+            Action::RetireStandardVersion(retire_standard_version_payload(VALID_FROM + 1)),
+        );
+        let standard = get_standard(&mut context, STANDARD_ID);
+        assert_eq!(
+            standard.get_versions()[0].get_retirement_date(),
+            VALID_FROM + 1
+        );
+    }
+
+    #[test]
+    fn test_retire_standard_version_already_retired() {
+        assert_invalid(
+            base_state()
+                .with_standard(retiring_standard(VALID_FROM))
+                .with_block_time(VALID_FROM),
+            STANDARDS_BODY_KEY,
+            Action::RetireStandardVersion(retire_standard_version_payload(VALID_TO)),
+            "Version 1.0 of Standard standard has already been retired",
+        );
+    }
+
+    #[test]
+    fn test_retire_standard_version_before_approval_date() {
+        assert_invalid(
+            base_state(),
+            STANDARDS_BODY_KEY,
+            Action::RetireStandardVersion(retire_standard_version_payload(
+                STANDARD_APPROVAL_DATE - 1,
+            )),
+            "is before the approval date 100 of version 1.0",
+        );
+    }
+
+    #[test]
+    fn test_retire_standard_version_no_version() {
+        let mut payload = retire_standard_version_payload(VALID_TO);
+        payload.set_version("3.0".to_string());
+        assert_invalid(
+            base_state(),
+            STANDARDS_BODY_KEY,
+            Action::RetireStandardVersion(payload),
+            "Version 3.0 does not exist for Standard standard",
+        );
+    }
+
+    #[test]
+    fn test_retire_standard_version_not_authorized() {
+        assert_invalid(
+            base_state().with_organization(organization(
+                STANDARDS_BODY_ID,
+                STANDARDS_BODY,
+                &[(STANDARDS_BODY_KEY, ADMIN)],
+            )),
+            STANDARDS_BODY_KEY,
+            Action::RetireStandardVersion(retire_standard_version_payload(VALID_TO)),
+            "is not authorized to retire standard standard",
+        );
+    }
+
+    #[test]
+    fn test_retire_standard_version_of_other_standards_body() {
+        assert_invalid(
+            other_standard_state(),
+            OTHER_STANDARDS_BODY_KEY,
+            Action::RetireStandardVersion(retire_standard_version_payload(VALID_TO)),
+            "did not create the certification standard",
+        );
+    }
+
+    #[test]
+    fn test_retire_standard() {
+        let mut context = base_state().build();
+        assert_valid(
+            &mut context,
+            STANDARDS_BODY_KEY,
+            Action::RetireStandard(retire_standard_payload()),
+        );
+        assert_eq!(
+            get_standard(&mut context, STANDARD_ID).get_status(),
+            proto::standard::Standard_Status::RETIRED
+        );
+    }
+
+    #[test]
+    fn test_retire_standard_not_standards_body() {
+        assert_invalid(
+            base_state(),
+            CERTIFYING_BODY_KEY,
+            Action::RetireStandard(retire_standard_payload()),
+            "Organization associated with agent cannot retire standards",
+        );
+    }
+
+    #[test]
+    fn test_retire_standard_already_retired() {
+        assert_invalid(
+            base_state().with_standard(retired_standard()),
+            STANDARDS_BODY_KEY,
+            Action::RetireStandard(retire_standard_payload()),
+            "Standard standard has been retired",
+        );
+    }
+
+    /*
+     * AccreditCertifyingBodyAction
+     */
+
+    /// State in which the standard has a version 2.0 the certifying body is not accredited for
+    fn accreditation_state() -> StateBuilder {
+        base_state().with_standard(standard(
+            STANDARD_ID,
+            STANDARDS_BODY_ID,
+            &[("1.0", STANDARD_APPROVAL_DATE), ("2.0", 2_000)],
+        ))
+    }
+
+    #[test]
+    fn test_accredit_certifying_body() {
+        let mut context = accreditation_state().build();
+        assert_valid(
+            &mut context,
+            STANDARDS_BODY_KEY,
+            Action::AccreditCertifyingBody(accredit_certifying_body_payload()),
+        );
+        let certifying_body = get_organization(&mut context, CERTIFYING_BODY_ID);
+        let accreditations = certifying_body
+            .get_certifying_body_details()
+            .get_accreditations();
+        assert_eq!(accreditations.len(), 2);
+        assert_eq!(accreditations[1].get_standard_version(), "2.0");
+    }
+
+    #[test]
+    fn test_accredit_certifying_body_no_agent() {
+        assert_invalid(
+            accreditation_state(),
+            UNKNOWN_KEY,
+            Action::AccreditCertifyingBody(accredit_certifying_body_payload()),
+            "Agent does not exist",
+        );
+    }
+
+    #[test]
+    fn test_accredit_certifying_body_agent_not_associated() {
+        assert_invalid(
+            accreditation_state(),
+            UNAFFILIATED_KEY,
+            Action::AccreditCertifyingBody(accredit_certifying_body_payload()),
+            "Agent is not associated with an organization",
+        );
+    }
+
+    #[test]
+    fn test_accredit_certifying_body_no_organization() {
+        assert_invalid(
+            accreditation_state(),
+            ORPHAN_KEY,
+            Action::AccreditCertifyingBody(accredit_certifying_body_payload()),
+            "No organization exists",
+        );
+    }
+
+    #[test]
+    fn test_accredit_certifying_body_not_standards_body() {
+        assert_invalid(
+            accreditation_state(),
+            CERTIFYING_BODY_KEY,
+            Action::AccreditCertifyingBody(accredit_certifying_body_payload()),
+            "Organization associated with agent cannot accredit Certifying Bodies",
+        );
+    }
+
+    #[test]
+    fn test_accredit_certifying_body_standards_body_not_approved() {
+        assert_invalid(
+            accreditation_state().with_organization(pending(organization(
+                STANDARDS_BODY_ID,
+                STANDARDS_BODY,
+                &[(STANDARDS_BODY_KEY, TRANSACTOR)],
+            ))),
+            STANDARDS_BODY_KEY,
+            Action::AccreditCertifyingBody(accredit_certifying_body_payload()),
+            "Organization standards_body has not been approved by a registry operator",
+        );
+    }
+
+    #[test]
+    fn test_accredit_certifying_body_not_transactor() {
+        assert_invalid(
+            accreditation_state().with_organization(organization(
+                STANDARDS_BODY_ID,
+                STANDARDS_BODY,
+                &[(STANDARDS_BODY_KEY, ADMIN)],
+            )),
+            STANDARDS_BODY_KEY,
+            Action::AccreditCertifyingBody(accredit_certifying_body_payload()),
+            "is not authorized to accredit certifying body",
+        );
+    }
+
+    #[test]
+    fn test_accredit_certifying_body_no_certifying_body() {
+        let mut payload = accredit_certifying_body_payload();
+        payload.set_certifying_body_id(MISSING_ORGANIZATION_ID.to_string());
+        assert_invalid(
+            accreditation_state(),
+            STANDARDS_BODY_KEY,
+            Action::AccreditCertifyingBody(payload),
+            "No organization exists: missing_organization",
+        );
+    }
+
+    #[test]
+    fn test_accredit_certifying_body_not_certifying_body() {
+        let mut payload = accredit_certifying_body_payload();
+        payload.set_certifying_body_id(FACTORY_ID.to_string());
+        assert_invalid(
+            accreditation_state(),
+            STANDARDS_BODY_KEY,
+            Action::AccreditCertifyingBody(payload),
+            "Only Certifying Bodies may be accredited",
+        );
+    }
+
+    #[test]
+    fn test_accredit_certifying_body_certifying_body_not_approved() {
+        assert_invalid(
+            accreditation_state().with_organization(pending(certifying_body(&[(
+                CERTIFYING_BODY_KEY,
+                TRANSACTOR,
+            )]))),
+            STANDARDS_BODY_KEY,
+            Action::AccreditCertifyingBody(accredit_certifying_body_payload()),
+            "Organization certifying_body has not been approved by a registry operator",
+        );
+    }
+
+    #[test]
+    fn test_accredit_certifying_body_no_standard() {
+        let mut payload = accredit_certifying_body_payload();
+        payload.set_standard_id("missing_standard".to_string());
+        assert_invalid(
+            accreditation_state(),
+            STANDARDS_BODY_KEY,
+            Action::AccreditCertifyingBody(payload),
+            "No standard with ID missing_standard exists",
+        );
+    }
+
+    #[test]
+    fn test_accredit_certifying_body_standard_of_other_standards_body() {
+        assert_invalid(
+            accreditation_state().with_standard(standard(
+                STANDARD_ID,
+                "other_standards_body",
+                &[("1.0", STANDARD_APPROVAL_DATE), ("2.0", 2_000)],
+            )),
+            STANDARDS_BODY_KEY,
+            Action::AccreditCertifyingBody(accredit_certifying_body_payload()),
+            "did not create the certification standard",
+        );
+    }
+
+    #[test]
+    fn test_accredit_certifying_body_standard_without_versions() {
+        assert_invalid(
+            accreditation_state().with_standard(standard(STANDARD_ID, STANDARDS_BODY_ID, &[])),
+            STANDARDS_BODY_KEY,
+            Action::AccreditCertifyingBody(accredit_certifying_body_payload()),
+            "Invalid version for Standard",
+        );
+    }
+
+    #[test]
+    fn test_accredit_certifying_body_already_accredited() {
+        assert_invalid(
+            base_state(),
+            STANDARDS_BODY_KEY,
+            Action::AccreditCertifyingBody(accredit_certifying_body_payload()),
+            "Accreditation for Standard standard, version 1.0 already exists",
+        );
+    }
+
+    #[test]
+    fn test_accredit_certifying_body_before_standard_approval() {
+        let mut payload = accredit_certifying_body_payload();
+        payload.set_valid_from(1_999);
+        assert_invalid(
+            accreditation_state(),
+            STANDARDS_BODY_KEY,
+            Action::AccreditCertifyingBody(payload),
+            "Standard is not valid from this date",
+        );
+    }
+
+    #[test]
+    fn test_accredit_certifying_body_valid_to_before_valid_from() {
+        let mut payload = accredit_certifying_body_payload();
+        payload.set_valid_to(1_999);
+        assert_invalid(
+            accreditation_state(),
+            STANDARDS_BODY_KEY,
+            Action::AccreditCertifyingBody(payload),
+            "Valid to must be after valid from",
+        );
+    }
+
+    #[test]
+    fn test_accredit_certifying_body_standard_retired() {
+        let mut standard = standard(
+            STANDARD_ID,
+            STANDARDS_BODY_ID,
+            &[("1.0", STANDARD_APPROVAL_DATE), ("2.0", 2_000)],
+        );
+        standard.set_status(proto::standard::Standard_Status::RETIRED);
+        assert_invalid(
+            base_state().with_standard(standard),
+            STANDARDS_BODY_KEY,
+            Action::AccreditCertifyingBody(accredit_certifying_body_payload()),
+            "Standard standard has been retired",
+        );
+    }
+
+    #[test]
+    fn test_accredit_certifying_body_valid_after_version_retirement() {
+        let mut standard = standard(
+            STANDARD_ID,
+            STANDARDS_BODY_ID,
+            &[("1.0", STANDARD_APPROVAL_DATE), ("2.0", 2_000)],
+        );
+        standard.mut_versions()[1].set_retirement_date(VALID_TO);
+        assert_invalid(
+            base_state().with_standard(standard),
+            STANDARDS_BODY_KEY,
+            Action::AccreditCertifyingBody(accredit_certifying_body_payload()),
+            "Version 2.0 of Standard standard retires at 5000",
+        );
+    }
+
+    /*
+     * RevokeAccreditationAction
+     */
+
+    #[test]
+    fn test_revoke_accreditation() {
+        let mut context = base_state().build();
+        assert_valid(
+            &mut context,
+            STANDARDS_BODY_KEY,
+            Action::RevokeAccreditation(revoke_accreditation_payload()),
+        );
+        assert!(get_organization(&mut context, CERTIFYING_BODY_ID)
+            .get_certifying_body_details()
+            .get_accreditations()
+            .is_empty());
+        assert_eq!(context.events().len(), 1);
+        assert_eq!(context.events()[0].0, events::ACCREDITATION_REVOKED);
+    }
+
+    #[test]
+    fn test_revoke_accreditation_no_agent() {
+        assert_invalid(
+            base_state(),
+            UNKNOWN_KEY,
+            Action::RevokeAccreditation(revoke_accreditation_payload()),
+            "Agent does not exist",
+        );
+    }
+
+    #[test]
+    fn test_revoke_accreditation_agent_not_associated() {
+        assert_invalid(
+            base_state(),
+            UNAFFILIATED_KEY,
+            Action::RevokeAccreditation(revoke_accreditation_payload()),
+            "Agent is not associated with an organization",
+        );
+    }
+
+    #[test]
+    fn test_revoke_accreditation_no_organization() {
+        assert_invalid(
+            base_state(),
+            ORPHAN_KEY,
+            Action::RevokeAccreditation(revoke_accreditation_payload()),
+            "No organization exists",
+        );
+    }
+
+    #[test]
+    fn test_revoke_accreditation_not_standards_body() {
+        assert_invalid(
+            base_state(),
+            CERTIFYING_BODY_KEY,
+            Action::RevokeAccreditation(revoke_accreditation_payload()),
+            "Organization associated with agent cannot revoke accreditations",
+        );
+    }
+
+    #[test]
+    fn test_revoke_accreditation_not_transactor() {
+        assert_invalid(
+            base_state().with_organization(organization(
+                STANDARDS_BODY_ID,
+                STANDARDS_BODY,
+                &[(STANDARDS_BODY_KEY, ADMIN)],
+            )),
+            STANDARDS_BODY_KEY,
+            Action::RevokeAccreditation(revoke_accreditation_payload()),
+            "is not authorized to revoke accreditations of certifying body",
+        );
+    }
+
+    #[test]
+    fn test_revoke_accreditation_no_certifying_body() {
+        let mut payload = revoke_accreditation_payload();
+        payload.set_certifying_body_id(MISSING_ORGANIZATION_ID.to_string());
+        assert_invalid(
+            base_state(),
+            STANDARDS_BODY_KEY,
+            Action::RevokeAccreditation(payload),
+            "No organization exists: missing_organization",
+        );
+    }
+
+    #[test]
+    fn test_revoke_accreditation_not_certifying_body() {
+        let mut payload = revoke_accreditation_payload();
+        payload.set_certifying_body_id(FACTORY_ID.to_string());
+        assert_invalid(
+            base_state(),
+            STANDARDS_BODY_KEY,
+            Action::RevokeAccreditation(payload),
+            "Only Certifying Bodies hold accreditations",
+        );
+    }
+
+    #[test]
+    fn test_revoke_accreditation_no_standard() {
+        let mut payload = revoke_accreditation_payload();
+        payload.set_standard_id("missing_standard".to_string());
+        assert_invalid(
+            base_state(),
+            STANDARDS_BODY_KEY,
+            Action::RevokeAccreditation(payload),
+            "No standard with ID missing_standard exists",
+        );
+    }
+
+    #[test]
+    fn test_revoke_accreditation_standard_of_other_standards_body() {
+        assert_invalid(
+            base_state().with_standard(standard(
+                STANDARD_ID,
+                "other_standards_body",
+                &[("1.0", STANDARD_APPROVAL_DATE)],
+            )),
+            STANDARDS_BODY_KEY,
+            Action::RevokeAccreditation(revoke_accreditation_payload()),
+            "did not create the certification standard",
+        );
+    }
+
+    #[test]
+    fn test_revoke_accreditation_not_accredited() {
+        let mut payload = revoke_accreditation_payload();
+        payload.set_standard_version("2.0".to_string());
+        assert_invalid(
+            base_state(),
+            STANDARDS_BODY_KEY,
+            Action::RevokeAccreditation(payload),
+            "holds no accreditation for Standard standard 2.0",
+        );
+    }
+
+    /*
+     * UpdateRegistryOperatorsAction
+     */
+
+    #[test]
+    fn test_update_registry_operators_creates_registry() {
+        let mut context = StateBuilder::new()
+            .with_setting(
+                addressing::REGISTRY_OPERATORS_SETTING,
+                &format!("{}, {}", UNKNOWN_KEY, OPERATOR_KEY),
+            )
+            .build();
+        assert_valid(
+            &mut context,
+            OPERATOR_KEY,
+            Action::UpdateRegistryOperators(update_registry_operators_payload()),
+        );
+        let registry = CertState::new(&mut context)
+            .get_registry()
+            .unwrap()
+            .expect("Registry not found");
+        assert_eq!(registry.get_operators().len(), 2);
+    }
+
+    #[test]
+    fn test_update_registry_operators_creates_registry_not_permitted() {
+        assert_invalid(
+            StateBuilder::new().with_setting(addressing::REGISTRY_OPERATORS_SETTING, OPERATOR_KEY),
+            UNKNOWN_KEY,
+            Action::UpdateRegistryOperators(update_registry_operators_payload()),
+            "is not permitted to create the registry",
+        );
+    }
+
+    #[test]
+    fn test_update_registry_operators_creates_registry_no_setting() {
+        assert_invalid(
+            StateBuilder::new(),
+            OPERATOR_KEY,
+            Action::UpdateRegistryOperators(update_registry_operators_payload()),
+            "is not permitted to create the registry",
+        );
+    }
+
+    #[test]
+    fn test_update_registry_operators_not_operator() {
+        assert_invalid(
+            base_state(),
+            UNKNOWN_KEY,
+            Action::UpdateRegistryOperators(update_registry_operators_payload()),
+            "is not a registry operator",
+        );
+    }
+
+    /*
+     * ReviewOrganizationAction
+     */
+
+    #[test]
+    fn test_review_organization() {
+        let mut context = base_state()
+            .with_organization(pending(certifying_body(&[(
+                CERTIFYING_BODY_KEY,
+                TRANSACTOR,
+            )])))
+            .build();
+        assert_valid(
+            &mut context,
+            OPERATOR_KEY,
+            Action::ReviewOrganization(review_organization_payload(CERTIFYING_BODY_ID)),
+        );
+        assert_eq!(
+            get_organization(&mut context, CERTIFYING_BODY_ID).get_approval_status(),
+            proto::organization::Organization_ApprovalStatus::APPROVED
+        );
+    }
+
+    #[test]
+    fn test_review_organization_no_registry() {
+        assert_invalid(
+            StateBuilder::new(),
+            OPERATOR_KEY,
+            Action::ReviewOrganization(review_organization_payload(CERTIFYING_BODY_ID)),
+            "No registry operators have been set",
+        );
+    }
+
+    #[test]
+    fn test_review_organization_not_operator() {
+        assert_invalid(
+            base_state(),
+            STANDARDS_BODY_KEY,
+            Action::ReviewOrganization(review_organization_payload(CERTIFYING_BODY_ID)),
+            "is not a registry operator",
+        );
+    }
+
+    #[test]
+    fn test_review_organization_no_organization() {
+        assert_invalid(
+            base_state(),
+            OPERATOR_KEY,
+            Action::ReviewOrganization(review_organization_payload(MISSING_ORGANIZATION_ID)),
+            "No organization exists",
+        );
+    }
+
+    #[test]
+    fn test_review_organization_factory() {
+        assert_invalid(
+            base_state(),
+            OPERATOR_KEY,
+            Action::ReviewOrganization(review_organization_payload(FACTORY_ID)),
+            "does not require approval",
+        );
+    }
+
+    /*
+     * AddSupplierAction and RemoveSupplierAction
+     */
+
+    #[test]
+    fn test_add_supplier() {
+        let mut context = retailer_state().build();
+        assert_valid(
+            &mut context,
+            RETAILER_KEY,
+            Action::AddSupplier(add_supplier_payload()),
+        );
+        assert_eq!(
+            get_organization(&mut context, RETAILER_ID)
+                .get_retailer_details()
+                .get_supplier_ids(),
+            &[FACTORY_ID.to_string()][..]
+        );
+    }
+
+    #[test]
+    fn test_supplier_actions_not_retailer() {
+        for action in vec![
+            Action::AddSupplier(add_supplier_payload()),
+            Action::RemoveSupplier(remove_supplier_payload()),
+        ] {
+            assert_invalid(
+                retailer_state(),
+                FACTORY_KEY,
+                action,
+                "Organization factory is not a retailer",
+            );
+        }
+    }
+
+    #[test]
+    fn test_supplier_actions_not_transactor() {
+        for action in vec![
+            Action::AddSupplier(add_supplier_payload()),
+            Action::RemoveSupplier(remove_supplier_payload()),
+        ] {
+            assert_invalid(
+                retailer_state().with_organization(organization(
+                    RETAILER_ID,
+                    RETAILER,
+                    &[(RETAILER_KEY, ADMIN)],
+                )),
+                RETAILER_KEY,
+                action,
+                "is not authorized to manage the suppliers of retailer",
+            );
+        }
+    }
+
+    #[test]
+    fn test_add_supplier_not_factory() {
+        let mut payload = add_supplier_payload();
+        payload.set_factory_id(CERTIFYING_BODY_ID.to_string());
+        assert_invalid(
+            retailer_state(),
+            RETAILER_KEY,
+            Action::AddSupplier(payload),
+            "Factory does not exist: certifying_body",
+        );
+    }
+
+    #[test]
+    fn test_add_supplier_already_supplier() {
+        assert_invalid(
+            retailer_state().with_organization(retailer_with_supplier()),
+            RETAILER_KEY,
+            Action::AddSupplier(add_supplier_payload()),
+            "Factory factory is already a supplier of retailer retailer",
+        );
+    }
+
+    #[test]
+    fn test_remove_supplier() {
+        let mut context = retailer_state()
+            .with_organization(retailer_with_supplier())
+            .build();
+        assert_valid(
+            &mut context,
+            RETAILER_KEY,
+            Action::RemoveSupplier(remove_supplier_payload()),
+        );
+        assert!(get_organization(&mut context, RETAILER_ID)
+            .get_retailer_details()
+            .get_supplier_ids()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_remove_supplier_not_supplier() {
+        assert_invalid(
+            retailer_state(),
+            RETAILER_KEY,
+            Action::RemoveSupplier(remove_supplier_payload()),
+            "Factory factory is not a supplier of retailer retailer",
+        );
+    }
+
+    /*
+     * DefineRoleAction and CUSTOM roles
+     */
+
+    #[test]
+    fn test_define_role() {
+        let mut context = base_state().build();
+        assert_valid(
+            &mut context,
+            FACTORY_KEY,
+            Action::DefineRole(define_role_payload("request_manager", &[MANAGE_REQUESTS])),
+        );
+        let factory = get_organization(&mut context, FACTORY_ID);
+        assert_eq!(factory.get_roles().len(), 1);
+        assert_eq!(factory.get_roles()[0].get_name(), "request_manager");
+        assert_eq!(factory.get_roles()[0].get_permissions(), &[MANAGE_REQUESTS]);
+    }
+
+    #[test]
+    fn test_define_role_replaces_permissions() {
+        let mut context = base_state().build();
+        assert_valid(
+            &mut context,
+            FACTORY_KEY,
+            Action::DefineRole(define_role_payload("request_manager", &[MANAGE_REQUESTS])),
+        );
+        assert_valid(
+            &mut context,
+            FACTORY_KEY,
+            Action::DefineRole(define_role_payload(
+                "request_manager",
+                &[MANAGE_REQUESTS, ISSUE_CERTIFICATE],
+            )),
+        );
+        let factory = get_organization(&mut context, FACTORY_ID);
+        assert_eq!(factory.get_roles().len(), 1);
+        assert_eq!(
+            factory.get_roles()[0].get_permissions(),
+            &[MANAGE_REQUESTS, ISSUE_CERTIFICATE]
+        );
+    }
+
+    #[test]
+    fn test_define_role_not_authorized() {
+        assert_invalid(
+            base_state(),
+            FACTORY_TRANSACTOR_KEY,
+            Action::DefineRole(define_role_payload("request_manager", &[MANAGE_REQUESTS])),
+            "is not authorized to define roles",
+        );
+    }
+
+    #[test]
+    fn test_authorize_agent_custom_role() {
+        let mut factory = organization(FACTORY_ID, FACTORY, &[(FACTORY_KEY, ADMIN)]);
+        factory
+            .mut_roles()
+            .push(role_definition("request_manager", &[MANAGE_REQUESTS]));
+        let mut context = base_state().with_organization(factory).build();
+
+        let mut payload = authorize_agent_payload(UNAFFILIATED_KEY, CUSTOM);
+        payload.set_role_name("request_manager".to_string());
+        assert_valid(&mut context, FACTORY_KEY, Action::AuthorizeAgent(payload));
+
+        let factory = get_organization(&mut context, FACTORY_ID);
+        let authorization = factory
+            .get_authorizations()
+            .iter()
+            .find(|auth| auth.get_public_key() == UNAFFILIATED_KEY)
+            .expect("Authorization not found");
+        assert_eq!(authorization.get_role(), CUSTOM);
+        assert_eq!(authorization.get_role_name(), "request_manager");
+    }
+
+    #[test]
+    fn test_authorize_agent_undefined_custom_role() {
+        let mut payload = authorize_agent_payload(UNAFFILIATED_KEY, CUSTOM);
+        payload.set_role_name("request_manager".to_string());
+        assert_invalid(
+            base_state(),
+            FACTORY_KEY,
+            Action::AuthorizeAgent(payload),
+            "Role request_manager is not defined by organization",
+        );
+    }
+
+    #[test]
+    fn test_revoke_agent_authorization_custom_role() {
+        let mut context = base_state()
+            .with_organization(with_custom_role(
+                organization(FACTORY_ID, FACTORY, &[(FACTORY_KEY, ADMIN)]),
+                FACTORY_TRANSACTOR_KEY,
+                role_definition("request_manager", &[MANAGE_REQUESTS]),
+            ))
+            .build();
+        let mut payload = revoke_agent_authorization_payload(FACTORY_TRANSACTOR_KEY, CUSTOM);
+        payload.set_role_name("request_manager".to_string());
+        assert_valid(
+            &mut context,
+            FACTORY_KEY,
+            Action::RevokeAgentAuthorization(payload),
+        );
+        assert_eq!(
+            get_agent(&mut context, FACTORY_TRANSACTOR_KEY).get_organization_id(),
+            ""
+        );
+    }
+
+    #[test]
+    fn test_issue_certificate_custom_role() {
+        let mut context = base_state()
+            .with_organization(with_custom_role(
+                certifying_body(&[(CERTIFYING_BODY_KEY, ADMIN)]),
+                CERTIFYING_BODY_KEY,
+                role_definition("issuer", &[ISSUE_CERTIFICATE]),
+            ))
+            .build();
+        assert_valid(
+            &mut context,
+            CERTIFYING_BODY_KEY,
+            Action::IssueCertificate(issue_certificate_payload()),
+        );
+    }
+
+    #[test]
+    fn test_issue_certificate_custom_role_without_permission() {
+        assert_invalid(
+            base_state().with_organization(with_custom_role(
+                certifying_body(&[(CERTIFYING_BODY_KEY, ADMIN)]),
+                CERTIFYING_BODY_KEY,
+                role_definition("request_manager", &[MANAGE_REQUESTS]),
+            )),
+            CERTIFYING_BODY_KEY,
+            Action::IssueCertificate(issue_certificate_payload()),
+            "Agent is not authorized to issue certificate",
+        );
+    }
+
+    /*
+     * DeclareEquivalenceAction
+     */
+
+    #[test]
+    fn test_declare_equivalence() {
+        let mut context = other_standard_state().build();
+        assert_valid(
+            &mut context,
+            STANDARDS_BODY_KEY,
+            Action::DeclareEquivalence(declare_equivalence_payload()),
+        );
+        let equivalences = get_standard(&mut context, STANDARD_ID)
+            .get_equivalences()
+            .to_vec();
+        assert_eq!(equivalences.len(), 1);
+        assert_eq!(equivalences[0].get_version(), "1.0");
+        assert_eq!(
+            equivalences[0].get_equivalent_standard_id(),
+            OTHER_STANDARD_ID
+        );
+        assert_eq!(equivalences[0].get_equivalent_standard_version(), "1.0");
+        assert!(get_standard(&mut context, OTHER_STANDARD_ID)
+            .get_equivalences()
+            .is_empty());
+
+        let mut payload = proto::payload::DeclareEquivalenceAction::new();
+        payload.set_standard_id(OTHER_STANDARD_ID.to_string());
+        payload.set_standard_version("1.0".to_string());
+        payload.set_equivalent_standard_id(STANDARD_ID.to_string());
+        payload.set_equivalent_standard_version("1.0".to_string());
+        assert_valid(
+            &mut context,
+            OTHER_STANDARDS_BODY_KEY,
+            Action::DeclareEquivalence(payload),
+        );
+        assert_eq!(
+            get_standard(&mut context, OTHER_STANDARD_ID)
+                .get_equivalences()
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_declare_equivalence_same_standards_body() {
+        let mut context = base_state()
+            .with_standard(standard(
+                OTHER_STANDARD_ID,
+                STANDARDS_BODY_ID,
+                &[("1.0", STANDARD_APPROVAL_DATE)],
+            ))
+            .build();
+        assert_valid(
+            &mut context,
+            STANDARDS_BODY_KEY,
+            Action::DeclareEquivalence(declare_equivalence_payload()),
+        );
+        let equivalences = get_standard(&mut context, OTHER_STANDARD_ID)
+            .get_equivalences()
+            .to_vec();
+        assert_eq!(equivalences.len(), 1);
+        assert_eq!(equivalences[0].get_equivalent_standard_id(), STANDARD_ID);
+    }
+
+    #[test]
+    fn test_declare_equivalence_no_agent() {
+        assert_invalid(
+            other_standard_state(),
+            UNKNOWN_KEY,
+            Action::DeclareEquivalence(declare_equivalence_payload()),
+            "Agent does not exist",
+        );
+    }
+
+    #[test]
+    fn test_declare_equivalence_not_standards_body() {
+        assert_invalid(
+            other_standard_state(),
+            CERTIFYING_BODY_KEY,
+            Action::DeclareEquivalence(declare_equivalence_payload()),
+            "Organization associated with agent cannot declare equivalences",
+        );
+    }
+
+    #[test]
+    fn test_declare_equivalence_standards_body_not_approved() {
+        assert_invalid(
+            other_standard_state().with_organization(pending(organization(
+                STANDARDS_BODY_ID,
+                STANDARDS_BODY,
+                &[(STANDARDS_BODY_KEY, TRANSACTOR)],
+            ))),
+            STANDARDS_BODY_KEY,
+            Action::DeclareEquivalence(declare_equivalence_payload()),
+            "has not been approved by a registry operator",
+        );
+    }
+
+    #[test]
+    fn test_declare_equivalence_not_transactor() {
+        assert_invalid(
+            other_standard_state().with_organization(organization(
+                STANDARDS_BODY_ID,
+                STANDARDS_BODY,
+                &[(STANDARDS_BODY_KEY, ADMIN)],
+            )),
+            STANDARDS_BODY_KEY,
+            Action::DeclareEquivalence(declare_equivalence_payload()),
+            "is not authorized to declare equivalences",
+        );
+    }
+
+    #[test]
+    fn test_declare_equivalence_no_equivalent_standard_version() {
+        let mut payload = declare_equivalence_payload();
+        payload.set_equivalent_standard_version("2.0".to_string());
+        assert_invalid(
+            other_standard_state(),
+            STANDARDS_BODY_KEY,
+            Action::DeclareEquivalence(payload),
+            "Version 2.0 does not exist for Standard other_standard",
+        );
+    }
+
+    #[test]
+    fn test_declare_equivalence_not_creator() {
+        assert_invalid(
+            other_standard_state(),
+            OTHER_STANDARDS_BODY_KEY,
+            Action::DeclareEquivalence(declare_equivalence_payload()),
+            "did not create the certification standard",
+        );
+    }
+
+    #[test]
+    fn test_declare_equivalence_already_declared() {
+        let mut standard = standard(
+            STANDARD_ID,
+            STANDARDS_BODY_ID,
+            &[("1.0", STANDARD_APPROVAL_DATE)],
+        );
+        add_equivalence(&mut standard, "1.0", OTHER_STANDARD_ID, "1.0");
+        assert_invalid(
+            other_standard_state().with_standard(standard),
+            STANDARDS_BODY_KEY,
+            Action::DeclareEquivalence(declare_equivalence_payload()),
+            "is already declared equivalent to Standard other_standard version 1.0",
+        );
+    }
+
+    /*
+     * SetAdminQuorumAction, ProposeChangeAction, VoteOnProposalAction and
+     * WithdrawProposalAction
+     */
+
+    #[test]
+    fn test_set_admin_quorum() {
+        let mut context = base_state().build();
+        assert_valid(
+            &mut context,
+            FACTORY_KEY,
+            Action::SetAdminQuorum(set_admin_quorum_payload(1)),
+        );
+        assert_eq!(
+            get_organization(&mut context, FACTORY_ID).get_admin_quorum(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_set_admin_quorum_exceeds_admins() {
+        assert_invalid(
+            base_state(),
+            FACTORY_KEY,
+            Action::SetAdminQuorum(set_admin_quorum_payload(2)),
+            "Admin quorum 2 exceeds the 1 ADMINs",
+        );
+    }
+
+    #[test]
+    fn test_set_admin_quorum_not_authorized() {
+        assert_invalid(
+            base_state(),
+            FACTORY_TRANSACTOR_KEY,
+            Action::SetAdminQuorum(set_admin_quorum_payload(1)),
+            "is not authorized to set the admin quorum",
+        );
+    }
+
+    #[test]
+    fn test_sensitive_actions_require_proposal() {
+        assert_invalid(
+            quorum_state(),
+            FACTORY_KEY,
+            Action::UpdateOrganization(update_organization_payload()),
+            "requires 2 ADMIN approvals",
+        );
+        assert_invalid(
+            quorum_state(),
+            FACTORY_KEY,
+            Action::AuthorizeAgent(authorize_agent_payload(FACTORY_TRANSACTOR_KEY, ADMIN)),
+            "requires 2 ADMIN approvals",
+        );
+        assert_invalid(
+            quorum_state(),
+            FACTORY_KEY,
+            Action::BatchActions(vec![Action::RevokeAgentAuthorization(
+                revoke_agent_authorization_payload(THIRD_FACTORY_ADMIN_KEY, ADMIN),
+            )]),
+            "requires 2 ADMIN approvals",
+        );
+        assert_invalid(
+            quorum_state(),
+            FACTORY_KEY,
+            Action::RotateAgentKey(rotate_agent_key_payload(THIRD_FACTORY_ADMIN_KEY)),
+            "requires 2 ADMIN approvals",
+        );
+    }
+
+    #[test]
+    fn test_rotate_own_agent_key_without_proposal() {
+        let mut context = quorum_state().build();
+        assert_valid(
+            &mut context,
+            THIRD_FACTORY_ADMIN_KEY,
+            Action::RotateAgentKey(rotate_agent_key_payload(THIRD_FACTORY_ADMIN_KEY)),
+        );
+        assert_eq!(
+            get_agent(&mut context, THIRD_FACTORY_ADMIN_KEY).get_successor_public_key(),
+            "new_agent"
+        );
+    }
+
+    #[test]
+    fn test_authorize_transactor_without_proposal() {
+        let mut context = quorum_state().build();
+        assert_valid(
+            &mut context,
+            FACTORY_KEY,
+            Action::AuthorizeAgent(authorize_agent_payload(
+                SECOND_FACTORY_ADMIN_KEY,
+                TRANSACTOR,
+            )),
+        );
+        assert!(get_organization(&mut context, FACTORY_ID)
+            .get_authorizations()
+            .iter()
+            .any(|auth| auth.get_public_key() == SECOND_FACTORY_ADMIN_KEY
+                && auth.get_role() == TRANSACTOR));
+    }
+
+    #[test]
+    fn test_propose_change() {
+        let mut context = quorum_state().build();
+        assert_valid(
+            &mut context,
+            FACTORY_KEY,
+            Action::ProposeChange(propose_change_payload(authorize_admin_proposal())),
+        );
+        let proposal = get_proposal(&mut context, PROPOSAL_ID);
+        assert_eq!(proposal.get_organization_id(), FACTORY_ID);
+        assert_eq!(proposal.get_required_approvals(), 2);
+        assert_eq!(
+            proposal.get_status(),
+            proto::proposal::Proposal_Status::OPEN
+        );
+        assert_eq!(proposal.get_votes().len(), 1);
+        assert_eq!(
+            get_organization(&mut context, FACTORY_ID).get_open_proposal_ids(),
+            &[PROPOSAL_ID.to_string()]
+        );
+        assert!(!get_organization(&mut context, FACTORY_ID)
+            .get_authorizations()
+            .iter()
+            .any(
+                |auth| auth.get_public_key() == FACTORY_TRANSACTOR_KEY && auth.get_role() == ADMIN
+            ));
+    }
+
+    #[test]
+    fn test_propose_change_not_required() {
+        assert_invalid(
+            base_state(),
+            FACTORY_KEY,
+            Action::ProposeChange(propose_change_payload(authorize_admin_proposal())),
+            "does not require proposals",
+        );
+    }
+
+    #[test]
+    fn test_propose_change_not_authorized() {
+        assert_invalid(
+            quorum_state(),
+            FACTORY_TRANSACTOR_KEY,
+            Action::ProposeChange(propose_change_payload(authorize_admin_proposal())),
+            "is not authorized to propose changes",
+        );
+    }
+
+    #[test]
+    fn test_propose_change_duplicate() {
+        assert_invalid(
+            quorum_state().with_proposal(open_proposal(authorize_admin_proposal())),
+            FACTORY_KEY,
+            Action::ProposeChange(propose_change_payload(authorize_admin_proposal())),
+            "Proposal already exists",
+        );
+    }
+
+    #[test]
+    fn test_vote_on_proposal_accepts_proposal() {
+        let mut context = proposal_state(open_proposal(authorize_admin_proposal())).build();
+        assert_valid(
+            &mut context,
+            SECOND_FACTORY_ADMIN_KEY,
+            Action::VoteOnProposal(vote_on_proposal_payload(true)),
+        );
+        assert_eq!(
+            get_proposal(&mut context, PROPOSAL_ID).get_status(),
+            proto::proposal::Proposal_Status::ACCEPTED
+        );
+        assert!(get_organization(&mut context, FACTORY_ID)
+            .get_open_proposal_ids()
+            .is_empty());
+        assert!(get_organization(&mut context, FACTORY_ID)
+            .get_authorizations()
+            .iter()
+            .any(
+                |auth| auth.get_public_key() == FACTORY_TRANSACTOR_KEY && auth.get_role() == ADMIN
+            ));
+    }
+
+    #[test]
+    fn test_vote_on_proposal_rotates_agent_key() {
+        let mut context = quorum_state()
+            .with_proposal(open_proposal(rotate_agent_key_proposal(
+                THIRD_FACTORY_ADMIN_KEY,
+            )))
+            .build();
+        assert_valid(
+            &mut context,
+            SECOND_FACTORY_ADMIN_KEY,
+            Action::VoteOnProposal(vote_on_proposal_payload(true)),
+        );
+        assert_eq!(
+            get_proposal(&mut context, PROPOSAL_ID).get_status(),
+            proto::proposal::Proposal_Status::ACCEPTED
+        );
+        assert_eq!(
+            get_agent(&mut context, THIRD_FACTORY_ADMIN_KEY).get_successor_public_key(),
+            "new_agent"
+        );
+    }
+
+    #[test]
+    fn test_vote_on_proposal_rejects_proposal() {
+        let mut context = proposal_state(open_proposal(authorize_admin_proposal())).build();
+        assert_valid(
+            &mut context,
+            SECOND_FACTORY_ADMIN_KEY,
+            Action::VoteOnProposal(vote_on_proposal_payload(false)),
+        );
+        assert_eq!(
+            get_proposal(&mut context, PROPOSAL_ID).get_status(),
+            proto::proposal::Proposal_Status::OPEN
+        );
+        assert_valid(
+            &mut context,
+            THIRD_FACTORY_ADMIN_KEY,
+            Action::VoteOnProposal(vote_on_proposal_payload(false)),
+        );
+        assert_eq!(
+            get_proposal(&mut context, PROPOSAL_ID).get_status(),
+            proto::proposal::Proposal_Status::REJECTED
+        );
+        assert!(get_organization(&mut context, FACTORY_ID)
+            .get_open_proposal_ids()
+            .is_empty());
+        assert!(!get_organization(&mut context, FACTORY_ID)
+            .get_authorizations()
+            .iter()
+            .any(
+                |auth| auth.get_public_key() == FACTORY_TRANSACTOR_KEY && auth.get_role() == ADMIN
+            ));
+    }
+
+    #[test]
+    fn test_vote_on_proposal_ignores_votes_of_former_admins() {
+        let mut vote = proto::proposal::Proposal_Vote::new();
+        vote.set_public_key(FACTORY_TRANSACTOR_KEY.to_string());
+        vote.set_accept(true);
+        let mut proposal = open_proposal(authorize_admin_proposal());
+        proposal.mut_votes().push(vote);
+
+        let mut context = quorum_state().with_proposal(proposal).build();
+        assert_valid(
+            &mut context,
+            SECOND_FACTORY_ADMIN_KEY,
+            Action::VoteOnProposal(vote_on_proposal_payload(false)),
+        );
+        assert_eq!(
+            get_proposal(&mut context, PROPOSAL_ID).get_status(),
+            proto::proposal::Proposal_Status::OPEN
+        );
+        assert!(!get_organization(&mut context, FACTORY_ID)
+            .get_authorizations()
+            .iter()
+            .any(
+                |auth| auth.get_public_key() == FACTORY_TRANSACTOR_KEY && auth.get_role() == ADMIN
+            ));
+    }
+
+    #[test]
+    fn test_vote_on_proposal_proposer_no_longer_admin() {
+        let mut factory = organization(
+            FACTORY_ID,
+            FACTORY,
+            &[
+                (FACTORY_KEY, TRANSACTOR),
+                (FACTORY_TRANSACTOR_KEY, TRANSACTOR),
+                (SECOND_FACTORY_ADMIN_KEY, ADMIN),
+                (THIRD_FACTORY_ADMIN_KEY, ADMIN),
+            ],
+        );
+        factory.set_admin_quorum(2);
+        let mut vote = proto::proposal::Proposal_Vote::new();
+        vote.set_public_key(THIRD_FACTORY_ADMIN_KEY.to_string());
+        vote.set_accept(true);
+        let mut proposal = open_proposal(authorize_admin_proposal());
+        proposal.mut_votes().push(vote);
+
+        assert_invalid(
+            quorum_state()
+                .with_organization(factory)
+                .with_proposal(proposal),
+            SECOND_FACTORY_ADMIN_KEY,
+            Action::VoteOnProposal(vote_on_proposal_payload(true)),
+            "is no longer authorized to change organization",
+        );
+    }
+
+    #[test]
+    fn test_vote_on_proposal_already_voted() {
+        assert_invalid(
+            quorum_state().with_proposal(open_proposal(authorize_admin_proposal())),
+            FACTORY_KEY,
+            Action::VoteOnProposal(vote_on_proposal_payload(true)),
+            "has already voted",
+        );
+    }
+
+    #[test]
+    fn test_vote_on_proposal_not_authorized() {
+        assert_invalid(
+            quorum_state().with_proposal(open_proposal(authorize_admin_proposal())),
+            FACTORY_TRANSACTOR_KEY,
+            Action::VoteOnProposal(vote_on_proposal_payload(true)),
+            "is not authorized to vote on proposals",
+        );
+    }
+
+    #[test]
+    fn test_vote_on_proposal_not_open() {
+        let mut proposal = open_proposal(authorize_admin_proposal());
+        proposal.set_status(proto::proposal::Proposal_Status::ACCEPTED);
+        assert_invalid(
+            quorum_state().with_proposal(proposal),
+            SECOND_FACTORY_ADMIN_KEY,
+            Action::VoteOnProposal(vote_on_proposal_payload(true)),
+            "is not open",
+        );
+    }
+
+    #[test]
+    fn test_vote_on_proposal_missing() {
+        assert_invalid(
+            quorum_state(),
+            SECOND_FACTORY_ADMIN_KEY,
+            Action::VoteOnProposal(vote_on_proposal_payload(true)),
+            "Proposal does not exist",
+        );
+    }
+
+    #[test]
+    fn test_vote_on_proposal_revoke_below_admin_quorum() {
+        let mut factory = get_organization(&mut quorum_state().build(), FACTORY_ID);
+        factory.set_admin_quorum(3);
+        let mut vote = proto::proposal::Proposal_Vote::new();
+        vote.set_public_key(SECOND_FACTORY_ADMIN_KEY.to_string());
+        vote.set_accept(true);
+        let mut proposal = open_proposal(revoke_admin_proposal(THIRD_FACTORY_ADMIN_KEY));
+        proposal.set_required_approvals(3);
+        proposal.mut_votes().push(vote);
+
+        assert_invalid(
+            quorum_state()
+                .with_organization(factory)
+                .with_proposal(proposal),
+            THIRD_FACTORY_ADMIN_KEY,
+            Action::VoteOnProposal(vote_on_proposal_payload(true)),
+            "would have fewer ADMINs than its admin quorum of 3",
+        );
+    }
+
+    #[test]
+    fn test_withdraw_proposal() {
+        let mut context = proposal_state(open_proposal(authorize_admin_proposal())).build();
+        assert_valid(
+            &mut context,
+            FACTORY_KEY,
+            Action::WithdrawProposal(withdraw_proposal_payload()),
+        );
+        assert_eq!(
+            get_proposal(&mut context, PROPOSAL_ID).get_status(),
+            proto::proposal::Proposal_Status::WITHDRAWN
+        );
+        assert!(get_organization(&mut context, FACTORY_ID)
+            .get_open_proposal_ids()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_withdraw_proposal_unblocks_key_rotation() {
+        let mut context = proposal_state(open_proposal(authorize_admin_proposal())).build();
+        assert_valid(
+            &mut context,
+            FACTORY_KEY,
+            Action::WithdrawProposal(withdraw_proposal_payload()),
+        );
+        assert_valid(
+            &mut context,
+            FACTORY_KEY,
+            Action::RotateAgentKey(rotate_agent_key_payload(FACTORY_KEY)),
+        );
+    }
+
+    #[test]
+    fn test_withdraw_proposal_proposer_no_longer_admin() {
+        let mut factory = organization(
+            FACTORY_ID,
+            FACTORY,
+            &[
+                (FACTORY_KEY, TRANSACTOR),
+                (SECOND_FACTORY_ADMIN_KEY, ADMIN),
+                (THIRD_FACTORY_ADMIN_KEY, ADMIN),
+            ],
+        );
+        factory.set_admin_quorum(2);
+        factory
+            .mut_open_proposal_ids()
+            .push(PROPOSAL_ID.to_string());
+        let mut context = quorum_state()
+            .with_organization(factory)
+            .with_proposal(open_proposal(authorize_admin_proposal()))
+            .build();
+        assert_valid(
+            &mut context,
+            SECOND_FACTORY_ADMIN_KEY,
+            Action::WithdrawProposal(withdraw_proposal_payload()),
+        );
+        assert_eq!(
+            get_proposal(&mut context, PROPOSAL_ID).get_status(),
+            proto::proposal::Proposal_Status::WITHDRAWN
+        );
+    }
+
+    #[test]
+    fn test_withdraw_proposal_not_proposer() {
+        assert_invalid(
+            proposal_state(open_proposal(authorize_admin_proposal())),
+            SECOND_FACTORY_ADMIN_KEY,
+            Action::WithdrawProposal(withdraw_proposal_payload()),
+            "Agent second_factory_admin_agent is not authorized to withdraw proposal proposal",
+        );
+    }
+
+    #[test]
+    fn test_withdraw_proposal_not_admin() {
+        let mut factory = organization(
+            FACTORY_ID,
+            FACTORY,
+            &[
+                (FACTORY_KEY, TRANSACTOR),
+                (FACTORY_TRANSACTOR_KEY, TRANSACTOR),
+                (SECOND_FACTORY_ADMIN_KEY, ADMIN),
+                (THIRD_FACTORY_ADMIN_KEY, ADMIN),
+            ],
+        );
+        factory.set_admin_quorum(2);
+        assert_invalid(
+            quorum_state()
+                .with_organization(factory)
+                .with_proposal(open_proposal(authorize_admin_proposal())),
+            FACTORY_TRANSACTOR_KEY,
+            Action::WithdrawProposal(withdraw_proposal_payload()),
+            "is not authorized to withdraw proposal",
+        );
+    }
+
+    #[test]
+    fn test_withdraw_proposal_not_open() {
+        let mut proposal = open_proposal(authorize_admin_proposal());
+        proposal.set_status(proto::proposal::Proposal_Status::REJECTED);
+        assert_invalid(
+            quorum_state().with_proposal(proposal),
+            FACTORY_KEY,
+            Action::WithdrawProposal(withdraw_proposal_payload()),
+            "is not open",
+        );
+    }
+
+    #[test]
+    fn test_withdraw_proposal_missing() {
+        assert_invalid(
+            quorum_state(),
+            FACTORY_KEY,
+            Action::WithdrawProposal(withdraw_proposal_payload()),
+            "Proposal does not exist",
+        );
+    }
+
+    /*
+     * OpenAccreditationRequestAction, ChangeAccreditationRequestStatusAction and
+     * ApproveAccreditationRequestAction
+     */
+
+    #[test]
+    fn test_open_accreditation_request() {
+        let mut context = base_state().build();
+        assert_valid(
+            &mut context,
+            CERTIFYING_BODY_KEY,
+            Action::OpenAccreditationRequest(open_accreditation_request_payload()),
+        );
+        let request = get_accreditation_request(&mut context, "new_accreditation_request");
+        assert_eq!(
+            request.get_status(),
+            proto::accreditation_request::AccreditationRequest_Status::OPEN
+        );
+        assert_eq!(request.get_certifying_body_id(), CERTIFYING_BODY_ID);
+        assert_eq!(request.get_standards_body_id(), STANDARDS_BODY_ID);
+
+        let event_types: Vec<&str> = context
+            .events()
+            .iter()
+            .map(|&(ref event_type, _)| event_type.as_str())
+            .collect();
+        assert_eq!(
+            event_types,
+            vec![events::ACCREDITATION_REQUEST_STATUS_CHANGED]
+        );
+    }
+
+    #[test]
+    fn test_open_accreditation_request_not_certifying_body() {
+        assert_invalid(
+            base_state(),
+            FACTORY_KEY,
+            Action::OpenAccreditationRequest(open_accreditation_request_payload()),
+            "is not a certifying body",
+        );
+    }
+
+    #[test]
+    fn test_open_accreditation_request_certifying_body_not_approved() {
+        assert_invalid(
+            base_state().with_organization(pending(certifying_body(&[
+                (CERTIFYING_BODY_KEY, ADMIN),
+                (CERTIFYING_BODY_KEY, TRANSACTOR),
+            ]))),
+            CERTIFYING_BODY_KEY,
+            Action::OpenAccreditationRequest(open_accreditation_request_payload()),
+            "has not been approved by a registry operator",
+        );
+    }
+
+    #[test]
+    fn test_open_accreditation_request_not_authorized() {
+        assert_invalid(
+            base_state().with_organization(certifying_body(&[(CERTIFYING_BODY_KEY, ADMIN)])),
+            CERTIFYING_BODY_KEY,
+            Action::OpenAccreditationRequest(open_accreditation_request_payload()),
+            "is not authorized to open an accreditation request",
+        );
+    }
+
+    #[test]
+    fn test_open_accreditation_request_already_exists() {
+        let mut payload = open_accreditation_request_payload();
+        payload.set_id(ACCREDITATION_REQUEST_ID.to_string());
+        assert_invalid(
+            base_state().with_accreditation_request(certifying_body_accreditation_request(
+                proto::accreditation_request::AccreditationRequest_Status::OPEN,
+            )),
+            CERTIFYING_BODY_KEY,
+            Action::OpenAccreditationRequest(payload),
+            "Accreditation request already exists",
+        );
+    }
+
+    #[test]
+    fn test_open_accreditation_request_no_standard() {
+        let mut payload = open_accreditation_request_payload();
+        payload.set_standard_id("missing_standard".to_string());
+        assert_invalid(
+            base_state(),
+            CERTIFYING_BODY_KEY,
+            Action::OpenAccreditationRequest(payload),
+            "No standard with ID missing_standard exists",
+        );
+    }
+
+    #[test]
+    fn test_open_accreditation_request_retired_standard() {
+        assert_invalid(
+            base_state().with_standard(retired_standard()),
+            CERTIFYING_BODY_KEY,
+            Action::OpenAccreditationRequest(open_accreditation_request_payload()),
+            "Standard standard has been retired",
+        );
+    }
+
+    #[test]
+    fn test_change_accreditation_request_status() {
+        let mut context = base_state()
+            .with_accreditation_request(certifying_body_accreditation_request(
+                proto::accreditation_request::AccreditationRequest_Status::OPEN,
+            ))
+            .build();
+        assert_valid(
+            &mut context,
+            STANDARDS_BODY_KEY,
+            Action::ChangeAccreditationRequestStatus(change_accreditation_request_status_payload(
+                proto::accreditation_request::AccreditationRequest_Status::IN_REVIEW,
+                "",
+            )),
+        );
+        assert_eq!(
+            get_accreditation_request(&mut context, ACCREDITATION_REQUEST_ID).get_status(),
+            proto::accreditation_request::AccreditationRequest_Status::IN_REVIEW
+        );
+    }
+
+    #[test]
+    fn test_change_accreditation_request_status_rejected() {
+        let mut context = base_state()
+            .with_accreditation_request(certifying_body_accreditation_request(
+                proto::accreditation_request::AccreditationRequest_Status::IN_REVIEW,
+            ))
+            .build();
+        assert_valid(
+            &mut context,
+            STANDARDS_BODY_KEY,
+            Action::ChangeAccreditationRequestStatus(change_accreditation_request_status_payload(
+                proto::accreditation_request::AccreditationRequest_Status::REJECTED,
+                "Insufficient auditor training",
+            )),
+        );
+        let request = get_accreditation_request(&mut context, ACCREDITATION_REQUEST_ID);
+        assert_eq!(
+            request.get_status(),
+            proto::accreditation_request::AccreditationRequest_Status::REJECTED
+        );
+        assert_eq!(
+            request.get_rejection_reason(),
+            "Insufficient auditor training"
+        );
+    }
+
+    #[test]
+    fn test_change_accreditation_request_status_no_request() {
+        assert_invalid(
+            base_state(),
+            STANDARDS_BODY_KEY,
+            Action::ChangeAccreditationRequestStatus(change_accreditation_request_status_payload(
+                proto::accreditation_request::AccreditationRequest_Status::IN_REVIEW,
+                "",
+            )),
+            "Accreditation request does not exist",
+        );
+    }
+
+    #[test]
+    fn test_change_accreditation_request_status_other_standards_body() {
+        assert_invalid(
+            other_standard_state().with_accreditation_request(
+                certifying_body_accreditation_request(
+                    proto::accreditation_request::AccreditationRequest_Status::OPEN,
+                ),
+            ),
+            OTHER_STANDARDS_BODY_KEY,
+            Action::ChangeAccreditationRequestStatus(change_accreditation_request_status_payload(
+                proto::accreditation_request::AccreditationRequest_Status::IN_REVIEW,
+                "",
+            )),
+            "is not addressed to the organization of agent",
+        );
+    }
+
+    #[test]
+    fn test_change_accreditation_request_status_finalized() {
+        assert_invalid(
+            base_state().with_accreditation_request(certifying_body_accreditation_request(
+                proto::accreditation_request::AccreditationRequest_Status::REJECTED,
+            )),
+            STANDARDS_BODY_KEY,
+            Action::ChangeAccreditationRequestStatus(change_accreditation_request_status_payload(
+                proto::accreditation_request::AccreditationRequest_Status::IN_REVIEW,
+                "",
+            )),
+            "Once APPROVED or REJECTED",
+        );
+    }
+
+    #[test]
+    fn test_change_accreditation_request_status_unchanged() {
+        assert_invalid(
+            base_state().with_accreditation_request(certifying_body_accreditation_request(
+                proto::accreditation_request::AccreditationRequest_Status::IN_REVIEW,
+            )),
+            STANDARDS_BODY_KEY,
+            Action::ChangeAccreditationRequestStatus(change_accreditation_request_status_payload(
+                proto::accreditation_request::AccreditationRequest_Status::IN_REVIEW,
+                "",
+            )),
+            "Accreditation request accreditation_request is already IN_REVIEW",
+        );
+    }
+
+    #[test]
+    fn test_approve_accreditation_request() {
+        let mut context = accreditation_state()
+            .with_accreditation_request(certifying_body_accreditation_request(
+                proto::accreditation_request::AccreditationRequest_Status::IN_REVIEW,
+            ))
+            .build();
+        assert_valid(
+            &mut context,
+            STANDARDS_BODY_KEY,
+            Action::ApproveAccreditationRequest(approve_accreditation_request_payload()),
+        );
+        let request = get_accreditation_request(&mut context, ACCREDITATION_REQUEST_ID);
+        assert_eq!(
+            request.get_status(),
+            proto::accreditation_request::AccreditationRequest_Status::APPROVED
+        );
+        assert_eq!(request.get_standard_version(), "2.0");
+
+        let certifying_body = get_organization(&mut context, CERTIFYING_BODY_ID);
+        let accreditations = certifying_body
+            .get_certifying_body_details()
+            .get_accreditations();
+        assert_eq!(accreditations.len(), 2);
+        assert_eq!(accreditations[1].get_standard_version(), "2.0");
+
+        let event_types: Vec<&str> = context
+            .events()
+            .iter()
+            .map(|&(ref event_type, _)| event_type.as_str())
+            .collect();
+        assert_eq!(
+            event_types,
+            vec![
+                events::ACCREDITATION_GRANTED,
+                events::ACCREDITATION_REQUEST_STATUS_CHANGED,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_approve_accreditation_request_not_authorized() {
+        assert_invalid(
+            accreditation_state()
+                .with_organization(organization(
+                    STANDARDS_BODY_ID,
+                    STANDARDS_BODY,
+                    &[(STANDARDS_BODY_KEY, ADMIN)],
+                ))
+                .with_accreditation_request(certifying_body_accreditation_request(
+                    proto::accreditation_request::AccreditationRequest_Status::IN_REVIEW,
+                )),
+            STANDARDS_BODY_KEY,
+            Action::ApproveAccreditationRequest(approve_accreditation_request_payload()),
+            "is not authorized to review accreditation request",
+        );
+    }
+
+    #[test]
+    fn test_approve_accreditation_request_already_accredited() {
+        assert_invalid(
+            base_state().with_accreditation_request(certifying_body_accreditation_request(
+                proto::accreditation_request::AccreditationRequest_Status::IN_REVIEW,
+            )),
+            STANDARDS_BODY_KEY,
+            Action::ApproveAccreditationRequest(approve_accreditation_request_payload()),
+            "Accreditation for Standard standard, version 1.0 already exists",
+        );
+    }
+
+    #[test]
+    fn test_approve_accreditation_request_finalized() {
+        assert_invalid(
+            accreditation_state().with_accreditation_request(
+                certifying_body_accreditation_request(
+                    proto::accreditation_request::AccreditationRequest_Status::APPROVED,
+                ),
+            ),
+            STANDARDS_BODY_KEY,
+            Action::ApproveAccreditationRequest(approve_accreditation_request_payload()),
+            "Once APPROVED or REJECTED",
+        );
+    }
+
+    #[test]
+    fn test_approve_accreditation_request_not_in_review() {
+        assert_invalid(
+            accreditation_state().with_accreditation_request(
+                certifying_body_accreditation_request(
+                    proto::accreditation_request::AccreditationRequest_Status::OPEN,
+                ),
+            ),
+            STANDARDS_BODY_KEY,
+            Action::ApproveAccreditationRequest(approve_accreditation_request_payload()),
+            "Only an IN_REVIEW accreditation request can be approved. Status: OPEN",
+        );
+    }
+
+    /*
+     * BatchActionsAction
+     */
+
+    #[test]
+    fn test_batch_actions() {
+        let mut context = base_state().build();
+        assert_valid(
+            &mut context,
+            UNAFFILIATED_KEY,
+            Action::BatchActions(vec![
+                Action::CreateOrganization(create_organization_payload()),
+                Action::UpdateOrganization(proto::payload::UpdateOrganizationAction::new()),
+            ]),
+        );
+        assert_eq!(
+            get_agent(&mut context, UNAFFILIATED_KEY).get_organization_id(),
+            "new_organization"
+        );
+    }
+
+    #[test]
+    fn test_batch_actions_failed_action() {
+        assert_invalid(
+            base_state(),
+            "new_agent",
+            Action::BatchActions(vec![
+                Action::CreateAgent(create_agent_payload()),
+                Action::CreateOrganization(create_organization_payload()),
+                Action::CreateAgent(create_agent_payload()),
+            ]),
+            "Batch action 2 failed: Agent already exists",
+        );
+    }
 }
@@ -2,6 +2,7 @@
 extern crate cfg_if;
 extern crate common;
 extern crate protobuf;
+extern crate regex;
 
 cfg_if! {
     if #[cfg(target_arch = "wasm32")] {
@@ -26,6 +27,8 @@ cfg_if! {
 mod handler;
 mod payload;
 mod state;
+#[cfg(test)]
+mod testing;
 
 /// Standard entry point
 #[cfg(not(target_arch = "wasm32"))]
@@ -10,10 +10,17 @@ cfg_if! {
     }
 }
 
+use common::proto::accreditation_request;
+use common::proto::audit;
+use common::proto::certificate;
+use common::proto::document;
 use common::proto::organization;
 use common::proto::payload;
 use common::proto::request;
+use common::proto::standard;
 use protobuf;
+use regex::Regex;
+use std::collections::HashSet;
 
 #[derive(Clone)]
 pub enum Action {
@@ -27,6 +34,35 @@ pub enum Action {
     OpenRequest(payload::OpenRequestAction),
     ChangeRequestStatus(payload::ChangeRequestStatusAction),
     AccreditCertifyingBody(payload::AccreditCertifyingBodyAction),
+    RevokeCertificate(payload::RevokeCertificateAction),
+    SuspendCertificate(payload::SuspendCertificateAction),
+    ReinstateCertificate(payload::ReinstateCertificateAction),
+    RenewCertificate(payload::RenewCertificateAction),
+    RevokeAccreditation(payload::RevokeAccreditationAction),
+    RevokeAgentAuthorization(payload::RevokeAgentAuthorizationAction),
+    RotateAgentKey(payload::RotateAgentKeyAction),
+    UpdateRegistryOperators(payload::UpdateRegistryOperatorsAction),
+    ReviewOrganization(payload::ReviewOrganizationAction),
+    AcceptRequest(payload::AcceptRequestAction),
+    DeclineRequest(payload::DeclineRequestAction),
+    RecordAudit(payload::RecordAuditAction),
+    RaiseNonConformance(payload::RaiseNonConformanceAction),
+    SubmitCorrectiveAction(payload::SubmitCorrectiveActionAction),
+    CloseNonConformance(payload::CloseNonConformanceAction),
+    DeclareEquivalence(payload::DeclareEquivalenceAction),
+    AddSupplier(payload::AddSupplierAction),
+    RemoveSupplier(payload::RemoveSupplierAction),
+    DefineRole(payload::DefineRoleAction),
+    SetAdminQuorum(payload::SetAdminQuorumAction),
+    ProposeChange(payload::ProposeChangeAction),
+    VoteOnProposal(payload::VoteOnProposalAction),
+    WithdrawProposal(payload::WithdrawProposalAction),
+    OpenAccreditationRequest(payload::OpenAccreditationRequestAction),
+    ChangeAccreditationRequestStatus(payload::ChangeAccreditationRequestStatusAction),
+    ApproveAccreditationRequest(payload::ApproveAccreditationRequestAction),
+    RetireStandardVersion(payload::RetireStandardVersionAction),
+    RetireStandard(payload::RetireStandardAction),
+    BatchActions(Vec<Action>),
 }
 
 pub struct CertPayload {
@@ -80,201 +116,771 @@ impl CertPayload {
     /// It checks that all necessary fields have been passed for the specified
     /// action and peforms some validation when possible.
     /// This peforms all paylod validation that does not require fetching data from
-    /// state.
+    /// state. The payload is decoded according to the family version of the transaction.
     /// ```
     /// # Errors
-    /// Returns an error if the payload is not valid
+    /// Returns an error if the payload is not valid or the family version is not supported
     /// ```
-    pub fn new(payload_data: &[u8]) -> Result<CertPayload, ApplyError> {
+    pub fn new(family_version: &str, payload_data: &[u8]) -> Result<CertPayload, ApplyError> {
         let payload: payload::CertificateRegistryPayload = unpack_data(&payload_data)?;
+        let action = match family_version {
+            "0.1" => decode_v0_1(&payload)?,
+            "0.2" => validate_action(&payload)?,
+            _ => {
+                return Err(ApplyError::InvalidTransaction(format!(
+                    "Unsupported family version: {}",
+                    family_version
+                )));
+            }
+        };
+        Ok(CertPayload { action })
+    }
+
+    pub fn get_action(&self) -> Action {
+        self.action.clone()
+    }
+}
+
+/// Decodes a payload submitted under family version 0.1. Version 0.1 clients only know the
+/// actions that existed in that version. They do not provide a standard version when issuing
+/// a certificate, nor a certifying body when opening a request.
+fn decode_v0_1(payload: &payload::CertificateRegistryPayload) -> Result<Action, ApplyError> {
+    match payload.get_action() {
+        payload::CertificateRegistryPayload_Action::ISSUE_CERTIFICATE => {
+            validate_issue_certificate(payload.get_issue_certificate(), false)
+        }
+        payload::CertificateRegistryPayload_Action::OPEN_REQUEST_ACTION => {
+            validate_open_request(payload.get_open_request_action(), false)
+        }
+        payload::CertificateRegistryPayload_Action::CREATE_AGENT
+        | payload::CertificateRegistryPayload_Action::CREATE_ORGANIZATION
+        | payload::CertificateRegistryPayload_Action::UPDATE_ORGANIZATION
+        | payload::CertificateRegistryPayload_Action::AUTHORIZE_AGENT
+        | payload::CertificateRegistryPayload_Action::CREATE_STANDARD
+        | payload::CertificateRegistryPayload_Action::UPDATE_STANDARD
+        | payload::CertificateRegistryPayload_Action::CHANGE_REQUEST_STATUS_ACTION
+        | payload::CertificateRegistryPayload_Action::ACCREDIT_CERTIFYING_BODY_ACTION => {
+            validate_action(payload)
+        }
+        action => Err(ApplyError::InvalidTransaction(format!(
+            "Action {:?} is not supported by family version 0.1",
+            action
+        ))),
+    }
+}
+
+/// Validates a single payload and returns its action. Payloads nested in a BATCH_ACTIONS
+/// payload are validated the same way, except that they cannot be batches themselves.
+fn validate_action(payload: &payload::CertificateRegistryPayload) -> Result<Action, ApplyError> {
+    let payload_action = match payload.get_action() {
+        payload::CertificateRegistryPayload_Action::UNSET_ACTION => {
+            return Err(ApplyError::InvalidTransaction(String::from(
+                "No action specified",
+            )));
+        }
+        payload::CertificateRegistryPayload_Action::CREATE_AGENT => {
+            let create_agent = payload.get_create_agent();
+
+            if create_agent.get_name() == "" {
+                return Err(ApplyError::InvalidTransaction(String::from(
+                    "Name was not provided",
+                )));
+            }
+            Action::CreateAgent(create_agent.clone())
+        }
+        payload::CertificateRegistryPayload_Action::CREATE_ORGANIZATION => {
+            let create_org = payload.get_create_organization();
+
+            reject_empty!(create_org, id, name, contacts)?;
 
-        let payload_action = match payload.get_action() {
-            payload::CertificateRegistryPayload_Action::UNSET_ACTION => {
+            if create_org.get_organization_type() == organization::Organization_Type::UNSET_TYPE {
                 return Err(ApplyError::InvalidTransaction(String::from(
-                    "No action specified",
+                    "Organization type is unset",
                 )));
             }
-            payload::CertificateRegistryPayload_Action::CREATE_AGENT => {
-                let create_agent = payload.get_create_agent();
 
-                if create_agent.get_name() == "" {
+            if create_org.get_organization_type() == organization::Organization_Type::FACTORY {
+                if create_org.has_address() {
+                    let address = create_org.get_address();
+                    reject_empty!(address, street_line_1, city, country)?;
+                } else {
                     return Err(ApplyError::InvalidTransaction(String::from(
-                        "Name was not provided",
+                        "Factory must be created with an address",
                     )));
                 }
-                Action::CreateAgent(create_agent.clone())
-            }
-            payload::CertificateRegistryPayload_Action::CREATE_ORGANIZATION => {
-                let create_org = payload.get_create_organization();
-
-                reject_empty!(create_org, id, name, contacts)?;
-
-                if create_org.get_organization_type() == organization::Organization_Type::UNSET_TYPE
-                {
+            } else {
+                if create_org.has_address() {
                     return Err(ApplyError::InvalidTransaction(String::from(
-                        "Organization type is unset",
+                        "Only a factory can have an address",
                     )));
                 }
+            }
 
-                if create_org.get_organization_type() == organization::Organization_Type::FACTORY {
-                    if create_org.has_address() {
-                        let address = create_org.get_address();
-                        reject_empty!(address, street_line_1, city, country)?;
-                    } else {
-                        return Err(ApplyError::InvalidTransaction(String::from(
-                            "Factory must be created with an address",
-                        )));
-                    }
-                } else {
-                    if create_org.has_address() {
-                        return Err(ApplyError::InvalidTransaction(String::from(
-                            "Only a factory can have an address",
-                        )));
-                    }
-                }
+            Action::CreateOrganization(create_org.clone())
+        }
+        payload::CertificateRegistryPayload_Action::UPDATE_ORGANIZATION => {
+            let update = payload.get_update_organization();
+            Action::UpdateOrganization(update.clone())
+        }
+        payload::CertificateRegistryPayload_Action::AUTHORIZE_AGENT => {
+            let authorize_agent = payload.get_authorize_agent();
 
-                Action::CreateOrganization(create_org.clone())
+            reject_empty!(authorize_agent, public_key)?;
+
+            if authorize_agent.get_role()
+                == organization::Organization_Authorization_Role::UNSET_ROLE
+            {
+                return Err(ApplyError::InvalidTransaction(String::from(
+                    "Agent role is UNSET. Set the role to TRANSACTOR or ADMIN",
+                )));
+            }
+
+            if authorize_agent.get_role()
+                != organization::Organization_Authorization_Role::TRANSACTOR
+                && authorize_agent.get_role()
+                    != organization::Organization_Authorization_Role::ADMIN
+                && authorize_agent.get_role()
+                    != organization::Organization_Authorization_Role::CUSTOM
+            {
+                return Err(ApplyError::InvalidTransaction(String::from(
+                    "Agent role is invalid. Agents can only have the roles: TRANSACTOR, ADMIN or CUSTOM",
+                )));
             }
-            payload::CertificateRegistryPayload_Action::UPDATE_ORGANIZATION => {
-                let update = payload.get_update_organization();
-                Action::UpdateOrganization(update.clone())
+
+            validate_role_name(authorize_agent.get_role(), authorize_agent.get_role_name())?;
+
+            if authorize_agent.get_valid_to() != 0
+                && authorize_agent.get_valid_to() < authorize_agent.get_valid_from()
+            {
+                return Err(ApplyError::InvalidTransaction(String::from(
+                    "Authorization's valid_to must not be before its valid_from",
+                )));
             }
-            payload::CertificateRegistryPayload_Action::AUTHORIZE_AGENT => {
-                let authorize_agent = payload.get_authorize_agent();
 
-                reject_empty!(authorize_agent, public_key)?;
+            Action::AuthorizeAgent(authorize_agent.clone())
+        }
+        payload::CertificateRegistryPayload_Action::REVOKE_AGENT_AUTHORIZATION => {
+            let revoke_authorization = payload.get_revoke_agent_authorization();
 
-                if authorize_agent.get_role()
-                    == organization::Organization_Authorization_Role::UNSET_ROLE
-                {
-                    return Err(ApplyError::InvalidTransaction(String::from(
-                        "Agent role is UNSET. Set the role to TRANSACTOR or ADMIN",
+            reject_empty!(revoke_authorization, public_key)?;
+
+            if revoke_authorization.get_role()
+                != organization::Organization_Authorization_Role::TRANSACTOR
+                && revoke_authorization.get_role()
+                    != organization::Organization_Authorization_Role::ADMIN
+                && revoke_authorization.get_role()
+                    != organization::Organization_Authorization_Role::CUSTOM
+            {
+                return Err(ApplyError::InvalidTransaction(String::from(
+                    "Agent role is invalid. Only the roles TRANSACTOR, ADMIN or CUSTOM can be revoked",
+                )));
+            }
+
+            validate_role_name(
+                revoke_authorization.get_role(),
+                revoke_authorization.get_role_name(),
+            )?;
+
+            Action::RevokeAgentAuthorization(revoke_authorization.clone())
+        }
+        payload::CertificateRegistryPayload_Action::ROTATE_AGENT_KEY => {
+            let rotate_agent_key = payload.get_rotate_agent_key();
+
+            reject_empty!(rotate_agent_key, public_key, new_public_key)?;
+
+            if rotate_agent_key.get_public_key() == rotate_agent_key.get_new_public_key() {
+                return Err(ApplyError::InvalidTransaction(String::from(
+                    "New public key must differ from the current public key",
+                )));
+            }
+
+            Action::RotateAgentKey(rotate_agent_key.clone())
+        }
+        payload::CertificateRegistryPayload_Action::UPDATE_REGISTRY_OPERATORS => {
+            let update_operators = payload.get_update_registry_operators();
+
+            reject_empty!(update_operators, operators)?;
+
+            if update_operators
+                .get_operators()
+                .iter()
+                .any(|operator| operator.is_empty())
+            {
+                return Err(ApplyError::InvalidTransaction(String::from(
+                    "Registry operator public key is empty",
+                )));
+            }
+
+            Action::UpdateRegistryOperators(update_operators.clone())
+        }
+        payload::CertificateRegistryPayload_Action::REVIEW_ORGANIZATION => {
+            let review_organization = payload.get_review_organization();
+
+            reject_empty!(review_organization, organization_id)?;
+
+            match review_organization.get_approval_status() {
+                organization::Organization_ApprovalStatus::APPROVED
+                | organization::Organization_ApprovalStatus::REJECTED => Ok(()),
+                _ => Err(ApplyError::InvalidTransaction(String::from(
+                    "Approval status is invalid. Organizations can only be APPROVED or REJECTED",
+                ))),
+            }?;
+
+            Action::ReviewOrganization(review_organization.clone())
+        }
+        payload::CertificateRegistryPayload_Action::ISSUE_CERTIFICATE => {
+            validate_issue_certificate(payload.get_issue_certificate(), true)?
+        }
+        payload::CertificateRegistryPayload_Action::OPEN_REQUEST_ACTION => {
+            validate_open_request(payload.get_open_request_action(), true)?
+        }
+        payload::CertificateRegistryPayload_Action::CHANGE_REQUEST_STATUS_ACTION => {
+            let change_request = payload.get_change_request_status_action();
+            reject_empty!(change_request, request_id)?;
+
+            if change_request.status != request::Request_Status::IN_PROGRESS
+                && change_request.status != request::Request_Status::CLOSED
+            {
+                return Err(ApplyError::InvalidTransaction(format!(
+                        "ChangeRequest status is invalid. Status can only be set to IN_PROGRESS or CLOSED.
+                        Status: {:?}",
+                        change_request.status
                     )));
-                }
+            }
 
-                if authorize_agent.get_role()
-                    != organization::Organization_Authorization_Role::TRANSACTOR
-                    && authorize_agent.get_role()
-                        != organization::Organization_Authorization_Role::ADMIN
-                {
+            Action::ChangeRequestStatus(change_request.clone())
+        }
+        payload::CertificateRegistryPayload_Action::ACCEPT_REQUEST => {
+            let accept_request = payload.get_accept_request();
+            reject_empty!(accept_request, request_id)?;
+            Action::AcceptRequest(accept_request.clone())
+        }
+        payload::CertificateRegistryPayload_Action::DECLINE_REQUEST => {
+            let decline_request = payload.get_decline_request();
+            reject_empty!(decline_request, request_id, reason)?;
+            Action::DeclineRequest(decline_request.clone())
+        }
+        payload::CertificateRegistryPayload_Action::RECORD_AUDIT => {
+            let record_audit = payload.get_record_audit();
+            reject_empty!(record_audit, id, request_id)?;
+
+            if record_audit.get_audit_date() == 0 {
+                return Err(ApplyError::InvalidTransaction(String::from(
+                    "Audit's audit_date field is invalid",
+                )));
+            }
+
+            if record_audit.get_outcome() == audit::Audit_Outcome::UNSET_OUTCOME {
+                return Err(ApplyError::InvalidTransaction(String::from(
+                    "Audit outcome must be set. It can be PASSED or FAILED",
+                )));
+            }
+
+            for finding in record_audit.get_findings() {
+                reject_empty!(finding, description)?;
+                if finding.get_severity() == audit::Audit_Finding_Severity::UNSET_SEVERITY {
                     return Err(ApplyError::InvalidTransaction(String::from(
-                        "Agent role is invalid. Agents can only have the roles: TRANSACTOR or ADMIN",
+                        "Finding severity must be set. It can be MINOR, MAJOR or CRITICAL",
                     )));
                 }
+            }
 
-                Action::AuthorizeAgent(authorize_agent.clone())
+            Action::RecordAudit(record_audit.clone())
+        }
+        payload::CertificateRegistryPayload_Action::RAISE_NON_CONFORMANCE => {
+            let raise_non_conformance = payload.get_raise_non_conformance();
+            reject_empty!(raise_non_conformance, id, factory_id, description)?;
+
+            if raise_non_conformance.get_severity() == audit::Audit_Finding_Severity::UNSET_SEVERITY
+            {
+                return Err(ApplyError::InvalidTransaction(String::from(
+                    "Non-conformance severity must be set. It can be MINOR, MAJOR or CRITICAL",
+                )));
             }
-            payload::CertificateRegistryPayload_Action::ISSUE_CERTIFICATE => {
-                let issue_cert = payload.get_issue_certificate();
-                reject_empty!(issue_cert, id)?;
 
-                match issue_cert.get_source() {
-                    payload::IssueCertificateAction_Source::UNSET_SOURCE => {
+            if raise_non_conformance.get_raised_at() == 0 {
+                return Err(ApplyError::InvalidTransaction(String::from(
+                    "Non-conformance's raised_at field is invalid",
+                )));
+            }
+
+            Action::RaiseNonConformance(raise_non_conformance.clone())
+        }
+        payload::CertificateRegistryPayload_Action::SUBMIT_CORRECTIVE_ACTION => {
+            let submit_corrective_action = payload.get_submit_corrective_action();
+            reject_empty!(
+                submit_corrective_action,
+                non_conformance_id,
+                corrective_action_plan
+            )?;
+
+            if submit_corrective_action.get_submitted_at() == 0 {
+                return Err(ApplyError::InvalidTransaction(String::from(
+                    "Corrective action's submitted_at field is invalid",
+                )));
+            }
+
+            Action::SubmitCorrectiveAction(submit_corrective_action.clone())
+        }
+        payload::CertificateRegistryPayload_Action::CLOSE_NON_CONFORMANCE => {
+            let close_non_conformance = payload.get_close_non_conformance();
+            reject_empty!(close_non_conformance, non_conformance_id)?;
+
+            if close_non_conformance.get_closed_at() == 0 {
+                return Err(ApplyError::InvalidTransaction(String::from(
+                    "Non-conformance's closed_at field is invalid",
+                )));
+            }
+
+            Action::CloseNonConformance(close_non_conformance.clone())
+        }
+        payload::CertificateRegistryPayload_Action::CREATE_STANDARD => {
+            let create_standard = payload.get_create_standard();
+
+            // Check if any fields are empty and return error if so
+            reject_empty!(
+                create_standard,
+                standard_id,
+                name,
+                version,
+                description,
+                link
+            )?;
+            if create_standard.approval_date == 0 {
+                return Err(ApplyError::InvalidTransaction(
+                    "Approval date must be provided".to_string(),
+                ));
+            }
+            validate_data_schema(create_standard.get_data_schema())?;
+            Action::CreateStandard(create_standard.clone())
+        }
+        payload::CertificateRegistryPayload_Action::UPDATE_STANDARD => {
+            let update_standard = payload.get_update_standard();
+            reject_empty!(update_standard, standard_id, version, description, link)?;
+            if update_standard.approval_date == 0 {
+                return Err(ApplyError::InvalidTransaction(
+                    "Approval date must be provided".to_string(),
+                ));
+            }
+            validate_data_schema(update_standard.get_data_schema())?;
+            Action::UpdateStandard(update_standard.clone())
+        }
+        payload::CertificateRegistryPayload_Action::RETIRE_STANDARD_VERSION => {
+            let retire_version = payload.get_retire_standard_version();
+            reject_empty!(retire_version, standard_id, version)?;
+            if retire_version.retirement_date == 0 {
+                return Err(ApplyError::InvalidTransaction(
+                    "Retirement date must be provided".to_string(),
+                ));
+            }
+            Action::RetireStandardVersion(retire_version.clone())
+        }
+        payload::CertificateRegistryPayload_Action::RETIRE_STANDARD => {
+            let retire_standard = payload.get_retire_standard();
+            reject_empty!(retire_standard, standard_id)?;
+            Action::RetireStandard(retire_standard.clone())
+        }
+        payload::CertificateRegistryPayload_Action::ACCREDIT_CERTIFYING_BODY_ACTION => {
+            let accredit_certifying_body = payload.get_accredit_certifying_body_action();
+            reject_empty!(accredit_certifying_body, certifying_body_id, standard_id)?;
+
+            if accredit_certifying_body.get_valid_from() == 0 {
+                return Err(ApplyError::InvalidTransaction(String::from(
+                    "Accreditation's valid_from field is invalid",
+                )));
+            }
+
+            if accredit_certifying_body.get_valid_to() == 0 {
+                return Err(ApplyError::InvalidTransaction(String::from(
+                    "Accreditations's valid_to field is invalid",
+                )));
+            }
+
+            Action::AccreditCertifyingBody(accredit_certifying_body.clone())
+        }
+        payload::CertificateRegistryPayload_Action::REVOKE_CERTIFICATE => {
+            let revoke_cert = payload.get_revoke_certificate();
+            reject_empty!(revoke_cert, certificate_id)?;
+
+            if revoke_cert.get_reason() == certificate::Certificate_RevocationReason::UNSET_REASON {
+                return Err(ApplyError::InvalidTransaction(String::from(
+                    "Revocation reason must be set",
+                )));
+            }
+
+            if revoke_cert.get_effective_date() == 0 {
+                return Err(ApplyError::InvalidTransaction(String::from(
+                    "Revocation's effective_date field is invalid",
+                )));
+            }
+
+            Action::RevokeCertificate(revoke_cert.clone())
+        }
+        payload::CertificateRegistryPayload_Action::SUSPEND_CERTIFICATE => {
+            let suspend_cert = payload.get_suspend_certificate();
+            reject_empty!(suspend_cert, certificate_id, reason)?;
+
+            if suspend_cert.get_suspension_date() == 0 {
+                return Err(ApplyError::InvalidTransaction(String::from(
+                    "Suspension's suspension_date field is invalid",
+                )));
+            }
+
+            Action::SuspendCertificate(suspend_cert.clone())
+        }
+        payload::CertificateRegistryPayload_Action::REINSTATE_CERTIFICATE => {
+            let reinstate_cert = payload.get_reinstate_certificate();
+            reject_empty!(reinstate_cert, certificate_id)?;
+
+            if reinstate_cert.get_reinstatement_date() == 0 {
+                return Err(ApplyError::InvalidTransaction(String::from(
+                    "Reinstatement's reinstatement_date field is invalid",
+                )));
+            }
+
+            Action::ReinstateCertificate(reinstate_cert.clone())
+        }
+        payload::CertificateRegistryPayload_Action::RENEW_CERTIFICATE => {
+            let renew_cert = payload.get_renew_certificate();
+            reject_empty!(renew_cert, id, previous_certificate_id, standard_version)?;
+
+            if renew_cert.get_valid_from() == 0 {
+                return Err(ApplyError::InvalidTransaction(String::from(
+                    "Certificate's valid_from field is invalid",
+                )));
+            }
+
+            if renew_cert.get_valid_to() == 0 {
+                return Err(ApplyError::InvalidTransaction(String::from(
+                    "Certificate's valid_to field is invalid",
+                )));
+            }
+
+            Action::RenewCertificate(renew_cert.clone())
+        }
+        payload::CertificateRegistryPayload_Action::REVOKE_ACCREDITATION => {
+            let revoke_accreditation = payload.get_revoke_accreditation();
+            reject_empty!(revoke_accreditation, certifying_body_id, standard_id)?;
+            Action::RevokeAccreditation(revoke_accreditation.clone())
+        }
+        payload::CertificateRegistryPayload_Action::DECLARE_EQUIVALENCE => {
+            let declare_equivalence = payload.get_declare_equivalence();
+            reject_empty!(
+                declare_equivalence,
+                standard_id,
+                standard_version,
+                equivalent_standard_id,
+                equivalent_standard_version
+            )?;
+
+            if declare_equivalence.get_standard_id()
+                == declare_equivalence.get_equivalent_standard_id()
+            {
+                return Err(ApplyError::InvalidTransaction(String::from(
+                    "A standard cannot be declared equivalent to itself",
+                )));
+            }
+
+            Action::DeclareEquivalence(declare_equivalence.clone())
+        }
+        payload::CertificateRegistryPayload_Action::ADD_SUPPLIER => {
+            let add_supplier = payload.get_add_supplier();
+            reject_empty!(add_supplier, factory_id)?;
+            Action::AddSupplier(add_supplier.clone())
+        }
+        payload::CertificateRegistryPayload_Action::REMOVE_SUPPLIER => {
+            let remove_supplier = payload.get_remove_supplier();
+            reject_empty!(remove_supplier, factory_id)?;
+            Action::RemoveSupplier(remove_supplier.clone())
+        }
+        payload::CertificateRegistryPayload_Action::DEFINE_ROLE => {
+            let define_role = payload.get_define_role();
+            reject_empty!(define_role, name, permissions)?;
+
+            if define_role
+                .get_permissions()
+                .contains(&organization::Organization_Permission::UNSET_PERMISSION)
+            {
+                return Err(ApplyError::InvalidTransaction(String::from(
+                    "Role permissions cannot be UNSET",
+                )));
+            }
+
+            Action::DefineRole(define_role.clone())
+        }
+        payload::CertificateRegistryPayload_Action::SET_ADMIN_QUORUM => {
+            Action::SetAdminQuorum(payload.get_set_admin_quorum().clone())
+        }
+        payload::CertificateRegistryPayload_Action::PROPOSE_CHANGE => {
+            let propose_change = payload.get_propose_change();
+            reject_empty!(propose_change, proposal_id)?;
+
+            if !propose_change.has_payload() {
+                return Err(ApplyError::InvalidTransaction(String::from(
+                    "A proposal must contain the proposed payload",
+                )));
+            }
+            validate_proposed_payload(propose_change.get_payload())?;
+
+            Action::ProposeChange(propose_change.clone())
+        }
+        payload::CertificateRegistryPayload_Action::VOTE_ON_PROPOSAL => {
+            let vote_on_proposal = payload.get_vote_on_proposal();
+            reject_empty!(vote_on_proposal, proposal_id)?;
+            Action::VoteOnProposal(vote_on_proposal.clone())
+        }
+        payload::CertificateRegistryPayload_Action::WITHDRAW_PROPOSAL => {
+            let withdraw_proposal = payload.get_withdraw_proposal();
+            reject_empty!(withdraw_proposal, proposal_id)?;
+            Action::WithdrawProposal(withdraw_proposal.clone())
+        }
+        payload::CertificateRegistryPayload_Action::OPEN_ACCREDITATION_REQUEST => {
+            let open_request = payload.get_open_accreditation_request();
+            reject_empty!(open_request, id, standard_id)?;
+            Action::OpenAccreditationRequest(open_request.clone())
+        }
+        payload::CertificateRegistryPayload_Action::CHANGE_ACCREDITATION_REQUEST_STATUS => {
+            let change_request = payload.get_change_accreditation_request_status();
+            reject_empty!(change_request, request_id)?;
+
+            match change_request.get_status() {
+                accreditation_request::AccreditationRequest_Status::IN_REVIEW => {
+                    if !change_request.get_reason().is_empty() {
                         return Err(ApplyError::InvalidTransaction(String::from(
-                            "Issue Certificate source must be set. It can be
-                            FROM_REQUEST if the there is an request associated with the
-                            action, or INDEPENDENT if there is not request associated.",
+                            "A reason can only be given when rejecting an accreditation request",
                         )));
                     }
-                    payload::IssueCertificateAction_Source::FROM_REQUEST => {
-                        reject_empty!(issue_cert, id, request_id)?;
-                    }
-                    payload::IssueCertificateAction_Source::INDEPENDENT => {
-                        reject_empty!(issue_cert, id, factory_id, standard_id)?;
+                }
+                accreditation_request::AccreditationRequest_Status::REJECTED => {
+                    if change_request.get_reason().is_empty() {
+                        return Err(ApplyError::InvalidTransaction(String::from(
+                            "A reason must be given when rejecting an accreditation request",
+                        )));
                     }
                 }
-
-                if issue_cert.get_valid_from() == 0 {
-                    return Err(ApplyError::InvalidTransaction(String::from(
-                        "Certificate's valid_from field is invalid",
+                status => {
+                    return Err(ApplyError::InvalidTransaction(format!(
+                        "Accreditation request status can only be set to IN_REVIEW or REJECTED. Status: {:?}",
+                        status
                     )));
                 }
+            }
 
-                if issue_cert.get_valid_to() == 0 {
-                    return Err(ApplyError::InvalidTransaction(String::from(
-                        "Certificate's valid_to field is invalid",
-                    )));
-                }
+            Action::ChangeAccreditationRequestStatus(change_request.clone())
+        }
+        payload::CertificateRegistryPayload_Action::APPROVE_ACCREDITATION_REQUEST => {
+            let approve_request = payload.get_approve_accreditation_request();
+            reject_empty!(approve_request, request_id)?;
 
-                Action::IssueCertificate(issue_cert.clone())
+            if approve_request.get_valid_from() == 0 {
+                return Err(ApplyError::InvalidTransaction(String::from(
+                    "Accreditation's valid_from field is invalid",
+                )));
             }
-            payload::CertificateRegistryPayload_Action::OPEN_REQUEST_ACTION => {
-                let open_request = payload.get_open_request_action();
-                reject_empty!(open_request, id, standard_id)?;
-                Action::OpenRequest(open_request.clone())
+
+            if approve_request.get_valid_to() == 0 {
+                return Err(ApplyError::InvalidTransaction(String::from(
+                    "Accreditations's valid_to field is invalid",
+                )));
             }
-            payload::CertificateRegistryPayload_Action::CHANGE_REQUEST_STATUS_ACTION => {
-                let change_request = payload.get_change_request_status_action();
-                reject_empty!(change_request, request_id)?;
 
-                if change_request.status != request::Request_Status::IN_PROGRESS
-                    && change_request.status != request::Request_Status::CLOSED
+            Action::ApproveAccreditationRequest(approve_request.clone())
+        }
+        payload::CertificateRegistryPayload_Action::BATCH_ACTIONS => {
+            let batch_actions = payload.get_batch_actions();
+            reject_empty!(batch_actions, actions)?;
+
+            let mut actions = Vec::new();
+            for action in batch_actions.get_actions() {
+                if action.get_action() == payload::CertificateRegistryPayload_Action::BATCH_ACTIONS
                 {
-                    return Err(ApplyError::InvalidTransaction(format!(
-                        "ChangeRequest status is invalid. Status can only be set to IN_PROGRESS or CLOSED.
-                        Status: {:?}",
-                        change_request.status
+                    return Err(ApplyError::InvalidTransaction(String::from(
+                        "Batch actions cannot contain another BATCH_ACTIONS payload",
                     )));
                 }
-
-                Action::ChangeRequestStatus(change_request.clone())
-            }
-            payload::CertificateRegistryPayload_Action::CREATE_STANDARD => {
-                let create_standard = payload.get_create_standard();
-
-                // Check if any fields are empty and return error if so
-                reject_empty!(
-                    create_standard,
-                    standard_id,
-                    name,
-                    version,
-                    description,
-                    link
-                )?;
-                if create_standard.approval_date == 0 {
-                    return Err(ApplyError::InvalidTransaction(
-                        "Approval date must be provided".to_string(),
-                    ));
-                }
-                Action::CreateStandard(create_standard.clone())
-            }
-            payload::CertificateRegistryPayload_Action::UPDATE_STANDARD => {
-                let update_standard = payload.get_update_standard();
-                reject_empty!(update_standard, standard_id, version, description, link)?;
-                if update_standard.approval_date == 0 {
-                    return Err(ApplyError::InvalidTransaction(
-                        "Approval date must be provided".to_string(),
-                    ));
-                }
-                Action::UpdateStandard(update_standard.clone())
+                actions.push(validate_action(action)?);
             }
-            payload::CertificateRegistryPayload_Action::ACCREDIT_CERTIFYING_BODY_ACTION => {
-                let accredit_certifying_body = payload.get_accredit_certifying_body_action();
-                reject_empty!(accredit_certifying_body, certifying_body_id, standard_id)?;
 
-                if accredit_certifying_body.get_valid_from() == 0 {
-                    return Err(ApplyError::InvalidTransaction(String::from(
-                        "Accreditation's valid_from field is invalid",
-                    )));
-                }
+            Action::BatchActions(actions)
+        }
+    };
+    Ok(payload_action)
+}
 
-                if accredit_certifying_body.get_valid_to() == 0 {
-                    return Err(ApplyError::InvalidTransaction(String::from(
-                        "Accreditations's valid_to field is invalid",
-                    )));
-                }
+/// Validates the payload of a proposal and returns its action. Only the actions that may
+/// require the approval of an organization's ADMINs can be proposed.
+pub fn validate_proposed_payload(
+    payload: &payload::CertificateRegistryPayload,
+) -> Result<Action, ApplyError> {
+    match payload.get_action() {
+        payload::CertificateRegistryPayload_Action::UPDATE_ORGANIZATION
+        | payload::CertificateRegistryPayload_Action::AUTHORIZE_AGENT
+        | payload::CertificateRegistryPayload_Action::REVOKE_AGENT_AUTHORIZATION
+        | payload::CertificateRegistryPayload_Action::DEFINE_ROLE
+        | payload::CertificateRegistryPayload_Action::SET_ADMIN_QUORUM
+        | payload::CertificateRegistryPayload_Action::ROTATE_AGENT_KEY => validate_action(payload),
+        action => Err(ApplyError::InvalidTransaction(format!(
+            "Action {:?} cannot be proposed",
+            action
+        ))),
+    }
+}
+
+/// Validates an IssueCertificateAction. A standard version is required unless the payload
+/// was submitted under family version 0.1.
+fn validate_issue_certificate(
+    issue_cert: &payload::IssueCertificateAction,
+    standard_version_required: bool,
+) -> Result<Action, ApplyError> {
+    reject_empty!(issue_cert, id)?;
 
-                Action::AccreditCertifyingBody(accredit_certifying_body.clone())
+    match issue_cert.get_source() {
+        payload::IssueCertificateAction_Source::UNSET_SOURCE => {
+            return Err(ApplyError::InvalidTransaction(String::from(
+                "Issue Certificate source must be set. It can be
+                    FROM_REQUEST if the there is an request associated with the
+                    action, or INDEPENDENT if there is not request associated.",
+            )));
+        }
+        payload::IssueCertificateAction_Source::FROM_REQUEST => {
+            reject_empty!(issue_cert, id, request_id)?;
+        }
+        payload::IssueCertificateAction_Source::INDEPENDENT => {
+            reject_empty!(issue_cert, id, factory_id, standard_id)?;
+
+            if !issue_cert.get_audit_id().is_empty() {
+                return Err(ApplyError::InvalidTransaction(String::from(
+                    "Only certificates issued FROM_REQUEST can reference an audit",
+                )));
             }
-        };
-        Ok(CertPayload {
-            action: payload_action,
-        })
+        }
     }
 
-    pub fn get_action(&self) -> Action {
-        self.action.clone()
+    if issue_cert.get_valid_from() == 0 {
+        return Err(ApplyError::InvalidTransaction(String::from(
+            "Certificate's valid_from field is invalid",
+        )));
+    }
+
+    if issue_cert.get_valid_to() == 0 {
+        return Err(ApplyError::InvalidTransaction(String::from(
+            "Certificate's valid_to field is invalid",
+        )));
+    }
+
+    validate_document_references(issue_cert.get_documents())?;
+
+    if standard_version_required {
+        reject_empty!(issue_cert, standard_version)?;
+    }
+
+    Ok(Action::IssueCertificate(issue_cert.clone()))
+}
+
+/// Validates an OpenRequestAction. A certifying body is required unless the payload was
+/// submitted under family version 0.1.
+fn validate_open_request(
+    open_request: &payload::OpenRequestAction,
+    certifying_body_required: bool,
+) -> Result<Action, ApplyError> {
+    reject_empty!(open_request, id, standard_id)?;
+
+    if certifying_body_required {
+        reject_empty!(open_request, certifying_body_id)?;
+    }
+
+    validate_document_references(open_request.get_documents())?;
+
+    Ok(Action::OpenRequest(open_request.clone()))
+}
+
+/// Checks that every document reference carries a title, a media type and a lowercase hex
+/// encoded SHA-256 hash
+fn validate_document_references(
+    documents: &[document::DocumentReference],
+) -> Result<(), ApplyError> {
+    for document in documents {
+        reject_empty!(document, sha256, media_type, title)?;
+
+        if document.get_sha256().len() != 64
+            || !document
+                .get_sha256()
+                .chars()
+                .all(|c| c.is_ascii_hexdigit() && !c.is_ascii_uppercase())
+        {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Document {} has an invalid SHA-256 hash: {}",
+                document.get_title(),
+                document.get_sha256()
+            )));
+        }
     }
+    Ok(())
+}
+
+/// Checks that a role name is given for CUSTOM roles, and only for them
+fn validate_role_name(
+    role: organization::Organization_Authorization_Role,
+    role_name: &str,
+) -> Result<(), ApplyError> {
+    match role {
+        organization::Organization_Authorization_Role::CUSTOM if role_name.is_empty() => {
+            Err(ApplyError::InvalidTransaction(String::from(
+                "A role name is required for CUSTOM roles",
+            )))
+        }
+        organization::Organization_Authorization_Role::CUSTOM => Ok(()),
+        _ if !role_name.is_empty() => Err(ApplyError::InvalidTransaction(String::from(
+            "A role name can only be provided for CUSTOM roles",
+        ))),
+        _ => Ok(()),
+    }
+}
+
+/// Checks that every field of a certificate data schema is named once, has a type, lists the
+/// values of ENUMERATION fields and carries a valid pattern
+fn validate_data_schema(data_schema: &[standard::Standard_DataField]) -> Result<(), ApplyError> {
+    let mut names = HashSet::new();
+    for field in data_schema {
+        reject_empty!(field, name)?;
+
+        if !names.insert(field.get_name()) {
+            return Err(ApplyError::InvalidTransaction(format!(
+                "Data field {} is defined more than once",
+                field.get_name()
+            )));
+        }
+
+        match field.get_data_type() {
+            standard::Standard_DataField_Type::UNSET_TYPE => {
+                return Err(ApplyError::InvalidTransaction(format!(
+                    "Data field {} must have a type",
+                    field.get_name()
+                )));
+            }
+            standard::Standard_DataField_Type::ENUMERATION
+                if field.get_allowed_values().is_empty() =>
+            {
+                return Err(ApplyError::InvalidTransaction(format!(
+                    "Data field {} is an ENUMERATION without allowed values",
+                    field.get_name()
+                )));
+            }
+            _ => (),
+        }
+
+        if !field.get_pattern().is_empty() {
+            compile_data_pattern(field.get_pattern())?;
+        }
+    }
+    Ok(())
+}
+
+/// Compiles the pattern of a data field. Patterns must match the whole value.
+pub fn compile_data_pattern(pattern: &str) -> Result<Regex, ApplyError> {
+    Regex::new(&format!("^(?:{})$", pattern)).map_err(|err| {
+        ApplyError::InvalidTransaction(format!("Invalid pattern {}: {}", pattern, err))
+    })
 }
 
 /// Deserializes binary data to a protobuf object
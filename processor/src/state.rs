@@ -82,7 +82,7 @@ impl<'a> CertState<'a> {
 
                 for organization in organizations.get_entries() {
                     if organization.id == organization_id {
-                        return Ok(Some(organization.clone()));
+                        return Ok(Some(upgrade_organization(organization.clone())));
                     }
                 }
                 Ok(None)
@@ -113,7 +113,7 @@ impl<'a> CertState<'a> {
 
                 for certificate in certificates.get_entries() {
                     if certificate.id == certificate_id {
-                        return Ok(Some(certificate.clone()));
+                        return Ok(Some(upgrade_certificate(certificate.clone())));
                     }
                 }
                 Ok(None)
@@ -429,4 +429,487 @@ impl<'a> CertState<'a> {
         self.context.set_state(sets)?;
         Ok(())
     }
+
+    /// Fetches and deserializes an Audit's data from state
+    /// ```
+    /// # Errors
+    /// Return an error if it fails to deserialize the Audit's data
+    /// ```
+    pub fn get_audit(&mut self, audit_id: &str) -> Result<Option<proto::audit::Audit>, ApplyError> {
+        let address = addressing::make_audit_address(audit_id);
+        let state_data = self.context.get_state(vec![address])?;
+        match state_data {
+            Some(data) => {
+                let audits: proto::audit::AuditContainer =
+                    protobuf::parse_from_bytes(data.as_slice()).map_err(|_err| {
+                        ApplyError::InvalidTransaction(String::from(
+                            "Cannot deserialize Audit container",
+                        ))
+                    })?;
+
+                for audit in audits.get_entries() {
+                    if audit.id == audit_id {
+                        return Ok(Some(audit.clone()));
+                    }
+                }
+                Ok(None)
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// As the addressing scheme does not guarantee uniquesness, this adds an Audit into an Audit
+    /// Container which works like a hashbucket, serializes the container and puts it into state,
+    /// ```
+    /// # Errors
+    /// Returns an error if it fails to serialize the container or fails to set it to state
+    /// ```
+    pub fn set_audit(
+        &mut self,
+        audit_id: &str,
+        audit: proto::audit::Audit,
+    ) -> Result<(), ApplyError> {
+        let address = addressing::make_audit_address(audit_id);
+        let state_data = self.context.get_state(vec![address.clone()])?;
+        let mut audits: proto::audit::AuditContainer = match state_data {
+            Some(data) => protobuf::parse_from_bytes(data.as_slice()).map_err(|_err| {
+                ApplyError::InvalidTransaction(String::from("Cannot deserialize audit container"))
+            })?,
+            // If there nothing at that memory address in state, make a new container, and create a new audit
+            None => proto::audit::AuditContainer::new(),
+        };
+
+        if let Some((i, _)) = audits
+            .entries
+            .iter()
+            .enumerate()
+            .find(|(_i, audit)| audit.id == audit_id)
+        {
+            let mut audit_slice = audits.entries.as_mut_slice();
+            audit_slice[i] = audit;
+        } else {
+            audits.entries.push(audit);
+            audits.entries.sort_by_key(|a| a.clone().id);
+        }
+
+        let serialized = protobuf::Message::write_to_bytes(&audits).map_err(|_err| {
+            ApplyError::InvalidTransaction(String::from("Cannot serialize audit container"))
+        })?;
+
+        // Insert serialized AuditContainer to an address in the merkle tree
+        let mut sets = HashMap::new();
+        sets.insert(address, serialized);
+        self.context.set_state(sets)?;
+        Ok(())
+    }
+
+    /// Fetches and deserializes a NonConformance's data from state
+    /// ```
+    /// # Errors
+    /// Return an error if it fails to deserialize the NonConformance's data
+    /// ```
+    pub fn get_non_conformance(
+        &mut self,
+        non_conformance_id: &str,
+    ) -> Result<Option<proto::non_conformance::NonConformance>, ApplyError> {
+        let address = addressing::make_non_conformance_address(non_conformance_id);
+        let state_data = self.context.get_state(vec![address])?;
+        match state_data {
+            Some(data) => {
+                let non_conformances: proto::non_conformance::NonConformanceContainer =
+                    protobuf::parse_from_bytes(data.as_slice()).map_err(|_err| {
+                        ApplyError::InvalidTransaction(String::from(
+                            "Cannot deserialize NonConformance container",
+                        ))
+                    })?;
+
+                for non_conformance in non_conformances.get_entries() {
+                    if non_conformance.id == non_conformance_id {
+                        return Ok(Some(non_conformance.clone()));
+                    }
+                }
+                Ok(None)
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// As the addressing scheme does not guarantee uniquesness, this adds a NonConformance into a
+    /// NonConformance Container which works like a hashbucket, serializes the container and puts it into state,
+    /// ```
+    /// # Errors
+    /// Returns an error if it fails to serialize the container or fails to set it to state
+    /// ```
+    pub fn set_non_conformance(
+        &mut self,
+        non_conformance_id: &str,
+        non_conformance: proto::non_conformance::NonConformance,
+    ) -> Result<(), ApplyError> {
+        let address = addressing::make_non_conformance_address(non_conformance_id);
+        let state_data = self.context.get_state(vec![address.clone()])?;
+        let mut non_conformances: proto::non_conformance::NonConformanceContainer = match state_data
+        {
+            Some(data) => protobuf::parse_from_bytes(data.as_slice()).map_err(|_err| {
+                ApplyError::InvalidTransaction(String::from(
+                    "Cannot deserialize non-conformance container",
+                ))
+            })?,
+            // If there nothing at that memory address in state, make a new container, and create a new non-conformance
+            None => proto::non_conformance::NonConformanceContainer::new(),
+        };
+
+        if let Some((i, _)) = non_conformances
+            .entries
+            .iter()
+            .enumerate()
+            .find(|(_i, non_conformance)| non_conformance.id == non_conformance_id)
+        {
+            let mut non_conformance_slice = non_conformances.entries.as_mut_slice();
+            non_conformance_slice[i] = non_conformance;
+        } else {
+            non_conformances.entries.push(non_conformance);
+            non_conformances.entries.sort_by_key(|a| a.clone().id);
+        }
+
+        let serialized = protobuf::Message::write_to_bytes(&non_conformances).map_err(|_err| {
+            ApplyError::InvalidTransaction(String::from(
+                "Cannot serialize non-conformance container",
+            ))
+        })?;
+
+        // Insert serialized NonConformanceContainer to an address in the merkle tree
+        let mut sets = HashMap::new();
+        sets.insert(address, serialized);
+        self.context.set_state(sets)?;
+        Ok(())
+    }
+
+    /// Fetches and deserializes a Proposal's data from state
+    /// ```
+    /// # Errors
+    /// Return an error if it fails to deserialize the Proposal's data
+    /// ```
+    pub fn get_proposal(
+        &mut self,
+        proposal_id: &str,
+    ) -> Result<Option<proto::proposal::Proposal>, ApplyError> {
+        let address = addressing::make_proposal_address(proposal_id);
+        let state_data = self.context.get_state(vec![address])?;
+        match state_data {
+            Some(data) => {
+                let proposals: proto::proposal::ProposalContainer =
+                    protobuf::parse_from_bytes(data.as_slice()).map_err(|_err| {
+                        ApplyError::InvalidTransaction(String::from(
+                            "Cannot deserialize Proposal container",
+                        ))
+                    })?;
+
+                for proposal in proposals.get_entries() {
+                    if proposal.id == proposal_id {
+                        return Ok(Some(proposal.clone()));
+                    }
+                }
+                Ok(None)
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// As the addressing scheme does not guarantee uniquesness, this adds a Proposal into a
+    /// Proposal Container which works like a hashbucket, serializes the container and puts it into state,
+    /// ```
+    /// # Errors
+    /// Returns an error if it fails to serialize the container or fails to set it to state
+    /// ```
+    pub fn set_proposal(
+        &mut self,
+        proposal_id: &str,
+        proposal: proto::proposal::Proposal,
+    ) -> Result<(), ApplyError> {
+        let address = addressing::make_proposal_address(proposal_id);
+        let state_data = self.context.get_state(vec![address.clone()])?;
+        let mut proposals: proto::proposal::ProposalContainer = match state_data {
+            Some(data) => protobuf::parse_from_bytes(data.as_slice()).map_err(|_err| {
+                ApplyError::InvalidTransaction(String::from(
+                    "Cannot deserialize proposal container",
+                ))
+            })?,
+            // If there nothing at that memory address in state, make a new container, and create a new proposal
+            None => proto::proposal::ProposalContainer::new(),
+        };
+
+        if let Some((i, _)) = proposals
+            .entries
+            .iter()
+            .enumerate()
+            .find(|(_i, proposal)| proposal.id == proposal_id)
+        {
+            let mut proposal_slice = proposals.entries.as_mut_slice();
+            proposal_slice[i] = proposal;
+        } else {
+            proposals.entries.push(proposal);
+            proposals.entries.sort_by_key(|a| a.clone().id);
+        }
+
+        let serialized = protobuf::Message::write_to_bytes(&proposals).map_err(|_err| {
+            ApplyError::InvalidTransaction(String::from("Cannot serialize proposal container"))
+        })?;
+
+        // Insert serialized ProposalContainer to an address in the merkle tree
+        let mut sets = HashMap::new();
+        sets.insert(address, serialized);
+        self.context.set_state(sets)?;
+        Ok(())
+    }
+
+    /// Fetches and deserializes an AccreditationRequest's data from state
+    /// ```
+    /// # Errors
+    /// Return an error if it fails to deserialize the AccreditationRequest's data
+    /// ```
+    pub fn get_accreditation_request(
+        &mut self,
+        request_id: &str,
+    ) -> Result<Option<proto::accreditation_request::AccreditationRequest>, ApplyError> {
+        let address = addressing::make_accreditation_request_address(request_id);
+        let state_data = self.context.get_state(vec![address])?;
+        match state_data {
+            Some(data) => {
+                let accreditation_requests: proto::accreditation_request::AccreditationRequestContainer =
+                    protobuf::parse_from_bytes(data.as_slice()).map_err(|_err| {
+                        ApplyError::InvalidTransaction(String::from(
+                            "Cannot deserialize AccreditationRequest container",
+                        ))
+                    })?;
+
+                for accreditation_request in accreditation_requests.get_entries() {
+                    if accreditation_request.id == request_id {
+                        return Ok(Some(accreditation_request.clone()));
+                    }
+                }
+                Ok(None)
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// As the addressing scheme does not guarantee uniquesness, this adds an AccreditationRequest into a
+    /// AccreditationRequest Container which works like a hashbucket, serializes the container and puts it into state,
+    /// ```
+    /// # Errors
+    /// Returns an error if it fails to serialize the container or fails to set it to state
+    /// ```
+    pub fn set_accreditation_request(
+        &mut self,
+        request_id: &str,
+        accreditation_request: proto::accreditation_request::AccreditationRequest,
+    ) -> Result<(), ApplyError> {
+        let address = addressing::make_accreditation_request_address(request_id);
+        let state_data = self.context.get_state(vec![address.clone()])?;
+        let mut accreditation_requests: proto::accreditation_request::AccreditationRequestContainer = match state_data {
+            Some(data) => protobuf::parse_from_bytes(data.as_slice()).map_err(|_err| {
+                ApplyError::InvalidTransaction(String::from(
+                    "Cannot deserialize accreditation request container",
+                ))
+            })?,
+            // If there nothing at that memory address in state, make a new container, and create a new accreditation request
+            None => proto::accreditation_request::AccreditationRequestContainer::new(),
+        };
+
+        if let Some((i, _)) = accreditation_requests
+            .entries
+            .iter()
+            .enumerate()
+            .find(|(_i, accreditation_request)| accreditation_request.id == request_id)
+        {
+            let mut accreditation_request_slice = accreditation_requests.entries.as_mut_slice();
+            accreditation_request_slice[i] = accreditation_request;
+        } else {
+            accreditation_requests.entries.push(accreditation_request);
+            accreditation_requests.entries.sort_by_key(|a| a.clone().id);
+        }
+
+        let serialized =
+            protobuf::Message::write_to_bytes(&accreditation_requests).map_err(|_err| {
+                ApplyError::InvalidTransaction(String::from(
+                    "Cannot serialize accreditation request container",
+                ))
+            })?;
+
+        // Insert serialized AccreditationRequestContainer to an address in the merkle tree
+        let mut sets = HashMap::new();
+        sets.insert(address, serialized);
+        self.context.set_state(sets)?;
+        Ok(())
+    }
+
+    /// Fetches and deserializes the Registry governance settings from state
+    /// ```
+    /// # Errors
+    /// Return an error if it fails to deserialize the Registry's data
+    /// ```
+    pub fn get_registry(&mut self) -> Result<Option<proto::registry::Registry>, ApplyError> {
+        let address = addressing::make_registry_address();
+        let state_data = self.context.get_state(vec![address])?;
+        match state_data {
+            Some(data) => {
+                let registry: proto::registry::Registry =
+                    protobuf::parse_from_bytes(data.as_slice()).map_err(|_err| {
+                        ApplyError::InvalidTransaction(String::from("Cannot deserialize registry"))
+                    })?;
+                Ok(Some(registry))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// The Registry lives at a single well-known address, so it is stored directly
+    /// rather than in a container
+    /// ```
+    /// # Errors
+    /// Returns an error if it fails to serialize the Registry or fails to set it to state
+    /// ```
+    pub fn set_registry(&mut self, registry: proto::registry::Registry) -> Result<(), ApplyError> {
+        let address = addressing::make_registry_address();
+        let serialized = protobuf::Message::write_to_bytes(&registry).map_err(|_err| {
+            ApplyError::InvalidTransaction(String::from("Cannot serialize registry"))
+        })?;
+
+        let mut sets = HashMap::new();
+        sets.insert(address, serialized);
+        self.context.set_state(sets)?;
+        Ok(())
+    }
+
+    /// Fetches the value of a setting recorded by the Settings family
+    /// ```
+    /// # Errors
+    /// Return an error if it fails to deserialize the Setting's data
+    /// ```
+    pub fn get_setting(&mut self, key: &str) -> Result<Option<String>, ApplyError> {
+        let address = addressing::make_setting_address(key);
+        let state_data = self.context.get_state(vec![address])?;
+        match state_data {
+            Some(data) => {
+                let setting: proto::setting::Setting = protobuf::parse_from_bytes(data.as_slice())
+                    .map_err(|_err| {
+                        ApplyError::InvalidTransaction(String::from("Cannot deserialize setting"))
+                    })?;
+                Ok(setting
+                    .get_entries()
+                    .iter()
+                    .find(|entry| entry.get_key() == key)
+                    .map(|entry| entry.get_value().to_string()))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Fetches the timestamp of the most recent block recorded by the BlockInfo family
+    /// ```
+    /// # Errors
+    /// Return an error if BlockInfo has not been recorded, or if it fails to deserialize the
+    /// BlockInfo config or block data
+    /// ```
+    pub fn get_block_timestamp(&mut self) -> Result<u64, ApplyError> {
+        let config_address = addressing::make_block_info_config_address();
+        let config_data = match self.context.get_state(vec![config_address])? {
+            Some(data) => data,
+            None => {
+                return Err(ApplyError::InvalidTransaction(String::from(
+                    "No block time is available. BlockInfo has not been recorded",
+                )));
+            }
+        };
+        let config: proto::block_info::BlockInfoConfig =
+            protobuf::parse_from_bytes(config_data.as_slice()).map_err(|_err| {
+                ApplyError::InvalidTransaction(String::from("Cannot deserialize block info config"))
+            })?;
+
+        let address = addressing::make_block_info_address(config.get_latest_block());
+        match self.context.get_state(vec![address])? {
+            Some(data) => {
+                let block_info: proto::block_info::BlockInfo =
+                    protobuf::parse_from_bytes(data.as_slice()).map_err(|_err| {
+                        ApplyError::InvalidTransaction(String::from(
+                            "Cannot deserialize block info",
+                        ))
+                    })?;
+                Ok(block_info.get_timestamp())
+            }
+            None => Err(ApplyError::InvalidTransaction(format!(
+                "No block time is available. BlockInfo has not recorded block {}",
+                config.get_latest_block()
+            ))),
+        }
+    }
+
+    /// Emits an event with the provided type and attributes. Sabre does not support
+    /// transaction family events, so no event is emitted in the wasm32 build
+    /// ```
+    /// # Errors
+    /// Returns an error if it fails to add the event to the transaction context
+    /// ```
+    pub fn add_event(
+        &mut self,
+        event_type: &str,
+        attributes: &[(&str, &str)],
+    ) -> Result<(), ApplyError> {
+        let attributes = attributes
+            .iter()
+            .map(|&(key, value)| (key.to_string(), value.to_string()))
+            .collect::<Vec<_>>();
+        self.emit_event(event_type.to_string(), attributes)
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn emit_event(
+        &mut self,
+        event_type: String,
+        attributes: Vec<(String, String)>,
+    ) -> Result<(), ApplyError> {
+        self.context.add_event(event_type, attributes, &[])?;
+        Ok(())
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn emit_event(
+        &mut self,
+        _event_type: String,
+        _attributes: Vec<(String, String)>,
+    ) -> Result<(), ApplyError> {
+        Ok(())
+    }
+}
+
+/// Upgrades an organization written by family version 0.1. Standards bodies and certifying
+/// bodies created before registry approval existed have no approval status, and are treated
+/// as approved.
+fn upgrade_organization(
+    mut organization: proto::organization::Organization,
+) -> proto::organization::Organization {
+    let requires_approval = match organization.get_organization_type() {
+        proto::organization::Organization_Type::STANDARDS_BODY
+        | proto::organization::Organization_Type::CERTIFYING_BODY => true,
+        _ => false,
+    };
+    if requires_approval
+        && organization.get_approval_status()
+            == proto::organization::Organization_ApprovalStatus::UNSET_APPROVAL
+    {
+        organization
+            .set_approval_status(proto::organization::Organization_ApprovalStatus::APPROVED);
+    }
+    organization
+}
+
+/// Upgrades a certificate written by family version 0.1. Certificates issued before
+/// certificate status existed have no status, and are treated as valid.
+fn upgrade_certificate(
+    mut certificate: proto::certificate::Certificate,
+) -> proto::certificate::Certificate {
+    if certificate.get_status() == proto::certificate::Certificate_Status::UNSET_STATUS {
+        certificate.set_status(proto::certificate::Certificate_Status::VALID);
+    }
+    certificate
 }
@@ -0,0 +1,416 @@
+/*
+ * Test harness for the transaction handler
+ */
+
+use std::collections::HashMap;
+
+use sawtooth_sdk::processor::handler::{ContextError, TransactionContext};
+
+use common::addressing;
+use common::proto;
+use protobuf;
+use state::CertState;
+
+/// In-memory TransactionContext. State entries are kept in a map keyed by address and
+/// events are recorded in the order they are added.
+#[derive(Default)]
+pub struct MockTransactionContext {
+    state: HashMap<String, Vec<u8>>,
+    events: Vec<(String, Vec<(String, String)>)>,
+}
+
+impl MockTransactionContext {
+    pub fn new() -> MockTransactionContext {
+        MockTransactionContext::default()
+    }
+
+    /// Returns the events added by the transactions applied against this context
+    pub fn events(&self) -> &[(String, Vec<(String, String)>)] {
+        &self.events
+    }
+}
+
+impl TransactionContext for MockTransactionContext {
+    fn get_state(&mut self, addresses: Vec<String>) -> Result<Option<Vec<u8>>, ContextError> {
+        Ok(addresses
+            .first()
+            .and_then(|address| self.state.get(address).cloned()))
+    }
+
+    fn set_state(&mut self, entries: HashMap<String, Vec<u8>>) -> Result<(), ContextError> {
+        self.state.extend(entries);
+        Ok(())
+    }
+
+    fn delete_state(
+        &mut self,
+        addresses: Vec<String>,
+    ) -> Result<Option<Vec<String>>, ContextError> {
+        let deleted: Vec<String> = addresses
+            .into_iter()
+            .filter(|address| self.state.remove(address).is_some())
+            .collect();
+        if deleted.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(deleted))
+        }
+    }
+
+    fn add_receipt_data(&mut self, _data: &[u8]) -> Result<(), ContextError> {
+        Ok(())
+    }
+
+    fn add_event(
+        &mut self,
+        event_type: String,
+        attributes: Vec<(String, String)>,
+        _data: &[u8],
+    ) -> Result<(), ContextError> {
+        self.events.push((event_type, attributes));
+        Ok(())
+    }
+}
+
+/// Builds the state a test starts from. Entries are written through CertState, so they are
+/// stored exactly as the handler would store them.
+pub struct StateBuilder {
+    context: MockTransactionContext,
+}
+
+impl StateBuilder {
+    pub fn new() -> StateBuilder {
+        StateBuilder {
+            context: MockTransactionContext::new(),
+        }
+    }
+
+    pub fn with_agent(mut self, agent: proto::agent::Agent) -> StateBuilder {
+        let public_key = agent.get_public_key().to_string();
+        CertState::new(&mut self.context)
+            .set_agent(&public_key, agent)
+            .expect("Failed to set agent");
+        self
+    }
+
+    pub fn with_organization(
+        mut self,
+        organization: proto::organization::Organization,
+    ) -> StateBuilder {
+        let id = organization.get_id().to_string();
+        CertState::new(&mut self.context)
+            .set_organization(&id, organization)
+            .expect("Failed to set organization");
+        self
+    }
+
+    pub fn with_standard(mut self, standard: proto::standard::Standard) -> StateBuilder {
+        let id = standard.get_id().to_string();
+        CertState::new(&mut self.context)
+            .set_standard(&id, standard)
+            .expect("Failed to set standard");
+        self
+    }
+
+    pub fn with_certificate(
+        mut self,
+        certificate: proto::certificate::Certificate,
+    ) -> StateBuilder {
+        let id = certificate.get_id().to_string();
+        CertState::new(&mut self.context)
+            .set_certificate(&id, certificate)
+            .expect("Failed to set certificate");
+        self
+    }
+
+    pub fn with_request(mut self, request: proto::request::Request) -> StateBuilder {
+        let id = request.get_id().to_string();
+        CertState::new(&mut self.context)
+            .set_request(&id, request)
+            .expect("Failed to set request");
+        self
+    }
+
+    pub fn with_audit(mut self, audit: proto::audit::Audit) -> StateBuilder {
+        let id = audit.get_id().to_string();
+        CertState::new(&mut self.context)
+            .set_audit(&id, audit)
+            .expect("Failed to set audit");
+        self
+    }
+
+    pub fn with_non_conformance(
+        mut self,
+        non_conformance: proto::non_conformance::NonConformance,
+    ) -> StateBuilder {
+        let id = non_conformance.get_id().to_string();
+        CertState::new(&mut self.context)
+            .set_non_conformance(&id, non_conformance)
+            .expect("Failed to set non-conformance");
+        self
+    }
+
+    pub fn with_proposal(mut self, proposal: proto::proposal::Proposal) -> StateBuilder {
+        let id = proposal.get_id().to_string();
+        CertState::new(&mut self.context)
+            .set_proposal(&id, proposal)
+            .expect("Failed to set proposal");
+        self
+    }
+
+    pub fn with_accreditation_request(
+        mut self,
+        request: proto::accreditation_request::AccreditationRequest,
+    ) -> StateBuilder {
+        let id = request.get_id().to_string();
+        CertState::new(&mut self.context)
+            .set_accreditation_request(&id, request)
+            .expect("Failed to set accreditation request");
+        self
+    }
+
+    pub fn with_registry(mut self, operators: &[&str]) -> StateBuilder {
+        let mut registry = proto::registry::Registry::new();
+        registry.set_operators(protobuf::RepeatedField::from_vec(
+            operators
+                .iter()
+                .map(|operator| operator.to_string())
+                .collect(),
+        ));
+        CertState::new(&mut self.context)
+            .set_registry(registry)
+            .expect("Failed to set registry");
+        self
+    }
+
+    /// Stores a setting as it is recorded by the Settings family
+    pub fn with_setting(mut self, key: &str, value: &str) -> StateBuilder {
+        let mut entry = proto::setting::Setting_Entry::new();
+        entry.set_key(key.to_string());
+        entry.set_value(value.to_string());
+
+        let mut setting = proto::setting::Setting::new();
+        setting.set_entries(protobuf::RepeatedField::from_vec(vec![entry]));
+
+        let mut entries = HashMap::new();
+        entries.insert(
+            addressing::make_setting_address(key),
+            protobuf::Message::write_to_bytes(&setting).expect("Failed to serialize setting"),
+        );
+        self.context
+            .set_state(entries)
+            .expect("Failed to set setting");
+        self
+    }
+
+    /// Stores BlockInfo records so that the block time read by the handler is `timestamp`
+    pub fn with_block_time(mut self, timestamp: u64) -> StateBuilder {
+        let mut config = proto::block_info::BlockInfoConfig::new();
+        config.set_latest_block(1);
+        config.set_oldest_block(1);
+
+        let mut block_info = proto::block_info::BlockInfo::new();
+        block_info.set_block_num(1);
+        block_info.set_timestamp(timestamp);
+
+        let mut entries = HashMap::new();
+        entries.insert(
+            addressing::make_block_info_config_address(),
+            protobuf::Message::write_to_bytes(&config).expect("Failed to serialize config"),
+        );
+        entries.insert(
+            addressing::make_block_info_address(1),
+            protobuf::Message::write_to_bytes(&block_info).expect("Failed to serialize block"),
+        );
+        self.context
+            .set_state(entries)
+            .expect("Failed to set block info");
+        self
+    }
+
+    pub fn build(self) -> MockTransactionContext {
+        self.context
+    }
+}
+
+/// Returns an Agent, associated with the organization unless `organization_id` is empty
+pub fn agent(public_key: &str, organization_id: &str) -> proto::agent::Agent {
+    let mut agent = proto::agent::Agent::new();
+    agent.set_public_key(public_key.to_string());
+    agent.set_name(format!("Agent {}", public_key));
+    agent.set_organization_id(organization_id.to_string());
+    agent
+}
+
+/// Returns an Organization holding the given authorizations. Standards bodies and certifying
+/// bodies are approved by a registry operator.
+pub fn organization(
+    id: &str,
+    organization_type: proto::organization::Organization_Type,
+    authorizations: &[(&str, proto::organization::Organization_Authorization_Role)],
+) -> proto::organization::Organization {
+    let mut organization = proto::organization::Organization::new();
+    organization.set_id(id.to_string());
+    organization.set_name(format!("Organization {}", id));
+    organization.set_organization_type(organization_type);
+    organization.set_authorizations(protobuf::RepeatedField::from_vec(
+        authorizations
+            .iter()
+            .map(|&(public_key, role)| {
+                let mut authorization = proto::organization::Organization_Authorization::new();
+                authorization.set_public_key(public_key.to_string());
+                authorization.set_role(role);
+                authorization
+            })
+            .collect(),
+    ));
+    match organization_type {
+        proto::organization::Organization_Type::FACTORY => {
+            organization.set_factory_details(proto::organization::Factory::new())
+        }
+        proto::organization::Organization_Type::RETAILER => {
+            organization.set_retailer_details(proto::organization::Retailer::new())
+        }
+        _ => organization
+            .set_approval_status(proto::organization::Organization_ApprovalStatus::APPROVED),
+    }
+    organization
+}
+
+/// Returns a role definition granting the given permissions
+pub fn role_definition(
+    name: &str,
+    permissions: &[proto::organization::Organization_Permission],
+) -> proto::organization::Organization_RoleDefinition {
+    let mut role = proto::organization::Organization_RoleDefinition::new();
+    role.set_name(name.to_string());
+    role.set_permissions(permissions.to_vec());
+    role
+}
+
+/// Returns an accreditation granted by `accreditor_id`
+pub fn accreditation(
+    standard_id: &str,
+    standard_version: &str,
+    accreditor_id: &str,
+    valid_from: u64,
+    valid_to: u64,
+) -> proto::organization::CertifyingBody_Accreditation {
+    let mut accreditation = proto::organization::CertifyingBody_Accreditation::new();
+    accreditation.set_standard_id(standard_id.to_string());
+    accreditation.set_standard_version(standard_version.to_string());
+    accreditation.set_accreditor_id(accreditor_id.to_string());
+    accreditation.set_valid_from(valid_from);
+    accreditation.set_valid_to(valid_to);
+    accreditation
+}
+
+/// Returns a Standard with the given versions and approval dates, oldest first
+pub fn standard(
+    id: &str,
+    organization_id: &str,
+    versions: &[(&str, u64)],
+) -> proto::standard::Standard {
+    let mut standard = proto::standard::Standard::new();
+    standard.set_id(id.to_string());
+    standard.set_name(format!("Standard {}", id));
+    standard.set_organization_id(organization_id.to_string());
+    standard.set_versions(protobuf::RepeatedField::from_vec(
+        versions
+            .iter()
+            .map(|&(version, approval_date)| {
+                let mut standard_version = proto::standard::Standard_StandardVersion::new();
+                standard_version.set_version(version.to_string());
+                standard_version.set_approval_date(approval_date);
+                standard_version
+            })
+            .collect(),
+    ));
+    standard
+}
+
+/// Returns a Request from a factory to a certifying body
+pub fn request(
+    id: &str,
+    factory_id: &str,
+    certifying_body_id: &str,
+    standard_id: &str,
+    status: proto::request::Request_Status,
+) -> proto::request::Request {
+    let mut request = proto::request::Request::new();
+    request.set_id(id.to_string());
+    request.set_factory_id(factory_id.to_string());
+    request.set_certifying_body_id(certifying_body_id.to_string());
+    request.set_standard_id(standard_id.to_string());
+    request.set_status(status);
+    request
+}
+
+/// Returns an AccreditationRequest from a certifying body for a standard
+pub fn accreditation_request(
+    id: &str,
+    certifying_body_id: &str,
+    standards_body_id: &str,
+    standard_id: &str,
+    status: proto::accreditation_request::AccreditationRequest_Status,
+) -> proto::accreditation_request::AccreditationRequest {
+    let mut request = proto::accreditation_request::AccreditationRequest::new();
+    request.set_id(id.to_string());
+    request.set_certifying_body_id(certifying_body_id.to_string());
+    request.set_standards_body_id(standards_body_id.to_string());
+    request.set_standard_id(standard_id.to_string());
+    request.set_status(status);
+    request
+}
+
+/// Returns a valid Certificate
+pub fn certificate(
+    id: &str,
+    certifying_body_id: &str,
+    factory_id: &str,
+    standard_id: &str,
+    standard_version: &str,
+    valid_from: u64,
+    valid_to: u64,
+) -> proto::certificate::Certificate {
+    let mut certificate = proto::certificate::Certificate::new();
+    certificate.set_id(id.to_string());
+    certificate.set_certifying_body_id(certifying_body_id.to_string());
+    certificate.set_factory_id(factory_id.to_string());
+    certificate.set_standard_id(standard_id.to_string());
+    certificate.set_standard_version(standard_version.to_string());
+    certificate.set_valid_from(valid_from);
+    certificate.set_valid_to(valid_to);
+    certificate.set_status(proto::certificate::Certificate_Status::VALID);
+    certificate
+}
+
+/// Returns an Audit of a request
+pub fn audit(
+    id: &str,
+    request: &proto::request::Request,
+    outcome: proto::audit::Audit_Outcome,
+) -> proto::audit::Audit {
+    let mut audit = proto::audit::Audit::new();
+    audit.set_id(id.to_string());
+    audit.set_request_id(request.get_id().to_string());
+    audit.set_factory_id(request.get_factory_id().to_string());
+    audit.set_certifying_body_id(request.get_certifying_body_id().to_string());
+    audit.set_outcome(outcome);
+    audit
+}
+
+/// Returns a NonConformance raised by a certifying body against a factory
+pub fn non_conformance(
+    id: &str,
+    factory_id: &str,
+    certifying_body_id: &str,
+    status: proto::non_conformance::NonConformance_Status,
+) -> proto::non_conformance::NonConformance {
+    let mut non_conformance = proto::non_conformance::NonConformance::new();
+    non_conformance.set_id(id.to_string());
+    non_conformance.set_factory_id(factory_id.to_string());
+    non_conformance.set_certifying_body_id(certifying_body_id.to_string());
+    non_conformance.set_status(status);
+    non_conformance
+}
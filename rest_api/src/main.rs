@@ -22,6 +22,7 @@ extern crate serde;
 extern crate serde_derive;
 extern crate base64;
 extern crate bcrypt;
+extern crate crypto;
 extern crate futures;
 extern crate http;
 extern crate hyper;
@@ -48,8 +49,9 @@ use log4rs::config::{Appender, Config, Root};
 use log4rs::encode::pattern::PatternEncoder;
 use rocket::response::NamedFile;
 use route_handlers::{
-    agents, authorization, blockchain, blocks, certificates, factories, organizations, requests,
-    standards, standards_body,
+    accreditation_requests, agents, audits, authorization, blockchain, blocks, certificates,
+    documents, factories, organizations, proposals, requests, retailers, standards,
+    standards_body,
 };
 use std::path::{Path, PathBuf};
 use std::{env, io, process};
@@ -162,10 +164,18 @@ fn main() {
         .mount(
             "/api",
             routes![
+                accreditation_requests::fetch_accreditation_request,
+                accreditation_requests::fetch_accreditation_request_with_head_param,
+                accreditation_requests::list_accreditation_requests,
+                accreditation_requests::list_accreditation_requests_with_params,
                 agents::fetch_agent,
                 agents::fetch_agent_with_head_param,
                 agents::list_agents,
                 agents::list_agents_with_params,
+                audits::fetch_audit,
+                audits::fetch_audit_with_head_param,
+                audits::list_audits,
+                audits::list_audits_with_params,
                 authorization::create_user,
                 authorization::update_user,
                 authorization::authenticate,
@@ -187,10 +197,20 @@ fn main() {
                 organizations::fetch_organization_with_params,
                 organizations::list_organizations,
                 organizations::list_organizations_with_params,
+                proposals::fetch_proposal,
+                proposals::fetch_proposal_with_head_param,
+                proposals::list_proposals,
+                proposals::list_proposals_with_params,
+                retailers::list_suppliers,
+                retailers::list_suppliers_with_params,
                 certificates::fetch_certificate,
                 certificates::fetch_certificate_with_head_param,
                 certificates::list_certificates,
                 certificates::list_certificates_with_params,
+                documents::verify_document,
+                documents::verify_document_with_params,
+                standards::fetch_standard,
+                standards::fetch_standard_with_head_param,
                 standards::list_standards,
                 standards::list_standards_with_params,
                 standards_body::list_standards_belonging_to_org
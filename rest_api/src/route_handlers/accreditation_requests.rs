@@ -0,0 +1,216 @@
+use database::DbConn;
+use database_manager::custom_types::AccreditationRequestStatusEnum;
+use database_manager::models::AccreditationRequest;
+use database_manager::tables_schema::accreditation_requests;
+use diesel::prelude::*;
+use errors::ApiError;
+use paging::*;
+use rocket::http::uri::Uri;
+use rocket::request::Form;
+use rocket_contrib::json::JsonValue;
+
+#[derive(Serialize)]
+pub struct ApiAccreditationRequest {
+    id: String,
+    certifying_body_id: String,
+    standards_body_id: String,
+    standard_id: String,
+    status: AccreditationRequestStatusEnum,
+    request_date: i64,
+    rejection_reason: Option<String>,
+    standard_version: Option<String>,
+}
+
+impl<'a> From<&'a AccreditationRequest> for ApiAccreditationRequest {
+    fn from(request: &'a AccreditationRequest) -> Self {
+        ApiAccreditationRequest {
+            id: request.accreditation_request_id.clone(),
+            certifying_body_id: request.certifying_body_id.clone(),
+            standards_body_id: request.standards_body_id.clone(),
+            standard_id: request.standard_id.clone(),
+            status: request.status.clone(),
+            request_date: request.request_date,
+            rejection_reason: request.rejection_reason.clone(),
+            standard_version: request.standard_version.clone(),
+        }
+    }
+}
+
+#[get("/accreditation_requests/<request_id>")]
+pub fn fetch_accreditation_request(
+    request_id: String,
+    conn: DbConn,
+) -> Result<JsonValue, ApiError> {
+    fetch_accreditation_request_with_head_param(request_id, None, conn)
+}
+
+#[get("/accreditation_requests/<request_id>?<head_param..>")]
+pub fn fetch_accreditation_request_with_head_param(
+    request_id: String,
+    head_param: Option<Form<AccreditationRequestParams>>,
+    conn: DbConn,
+) -> Result<JsonValue, ApiError> {
+    let head_param = match head_param {
+        Some(param) => param.into_inner(),
+        None => Default::default(),
+    };
+    let head_block_num: i64 = get_head_block_num(head_param.head, &conn)?;
+
+    let request = accreditation_requests::table
+        .filter(accreditation_requests::accreditation_request_id.eq(request_id.to_string()))
+        .filter(accreditation_requests::start_block_num.le(head_block_num))
+        .filter(accreditation_requests::end_block_num.gt(head_block_num))
+        .first::<AccreditationRequest>(&*conn)
+        .optional()
+        .map_err(|err| ApiError::InternalError(err.to_string()))?;
+
+    let link = format!(
+        "/api/accreditation_requests/{}?head={}",
+        request_id, head_block_num
+    );
+
+    match request {
+        Some(request) => Ok(json!({
+            "data": ApiAccreditationRequest::from(&request),
+            "link": link,
+            "head": head_block_num,
+        })),
+        None => Err(ApiError::NotFound(format!(
+            "No accreditation request with the ID {} exists",
+            request_id
+        ))),
+    }
+}
+
+#[derive(Default, FromForm, Clone)]
+pub struct AccreditationRequestParams {
+    certifying_body_id: Option<String>,
+    standards_body_id: Option<String>,
+    standard_id: Option<String>,
+    status: Option<String>,
+    limit: Option<i64>,
+    offset: Option<i64>,
+    head: Option<i64>,
+}
+
+#[get("/accreditation_requests")]
+pub fn list_accreditation_requests(conn: DbConn) -> Result<JsonValue, ApiError> {
+    list_accreditation_requests_with_params(None, conn)
+}
+
+#[get("/accreditation_requests?<params..>")]
+pub fn list_accreditation_requests_with_params(
+    params: Option<Form<AccreditationRequestParams>>,
+    conn: DbConn,
+) -> Result<JsonValue, ApiError> {
+    let params = match params {
+        Some(param) => param.into_inner(),
+        None => Default::default(),
+    };
+    let head_block_num: i64 = get_head_block_num(params.head, &conn)?;
+
+    let mut requests_query = accreditation_requests::table
+        .filter(accreditation_requests::start_block_num.le(head_block_num))
+        .filter(accreditation_requests::end_block_num.gt(head_block_num))
+        .into_boxed();
+
+    let mut count_query = accreditation_requests::table
+        .filter(accreditation_requests::start_block_num.le(head_block_num))
+        .filter(accreditation_requests::end_block_num.gt(head_block_num))
+        .into_boxed();
+    let link_params = params.clone();
+
+    if let Some(certifying_body_id) = params.certifying_body_id {
+        requests_query = requests_query
+            .filter(accreditation_requests::certifying_body_id.eq(certifying_body_id.to_string()));
+        count_query = count_query
+            .filter(accreditation_requests::certifying_body_id.eq(certifying_body_id.to_string()));
+    }
+    if let Some(standards_body_id) = params.standards_body_id {
+        requests_query = requests_query
+            .filter(accreditation_requests::standards_body_id.eq(standards_body_id.to_string()));
+        count_query = count_query
+            .filter(accreditation_requests::standards_body_id.eq(standards_body_id.to_string()));
+    }
+    if let Some(standard_id) = params.standard_id {
+        requests_query =
+            requests_query.filter(accreditation_requests::standard_id.eq(standard_id.to_string()));
+        count_query =
+            count_query.filter(accreditation_requests::standard_id.eq(standard_id.to_string()));
+    }
+    if let Some(status) = params.status {
+        let status = match status.as_ref() {
+            "OPEN" => AccreditationRequestStatusEnum::Open,
+            "IN_REVIEW" => AccreditationRequestStatusEnum::InReview,
+            "APPROVED" => AccreditationRequestStatusEnum::Approved,
+            "REJECTED" => AccreditationRequestStatusEnum::Rejected,
+            _ => {
+                return Err(ApiError::BadRequest(format!(
+                    "Invalid accreditation request status: {}",
+                    status
+                )));
+            }
+        };
+        requests_query = requests_query.filter(accreditation_requests::status.eq(status.clone()));
+        count_query = count_query.filter(accreditation_requests::status.eq(status));
+    }
+
+    let total_count = count_query
+        .count()
+        .get_result(&*conn)
+        .map_err(|err| ApiError::InternalError(err.to_string()))?;
+    let paging_info = apply_paging(link_params, head_block_num, total_count)?;
+
+    requests_query = requests_query.limit(params.limit.unwrap_or(DEFAULT_LIMIT));
+    requests_query = requests_query.offset(params.offset.unwrap_or(DEFAULT_OFFSET));
+
+    let request_results = requests_query
+        .load::<AccreditationRequest>(&*conn)
+        .map_err(|err| ApiError::InternalError(err.to_string()))?;
+
+    Ok(json!({
+        "data": request_results.iter()
+            .map(ApiAccreditationRequest::from)
+            .collect::<Vec<_>>(),
+        "link": paging_info.get("link"),
+        "head": head_block_num,
+        "paging": paging_info.get("paging")
+    }))
+}
+
+fn apply_paging(
+    params: AccreditationRequestParams,
+    head: i64,
+    total_count: i64,
+) -> Result<JsonValue, ApiError> {
+    let mut link = String::from("/api/accreditation_requests?");
+
+    if let Some(certifying_body_id) = params.certifying_body_id {
+        link = format!(
+            "{}certifying_body_id={}&",
+            link,
+            Uri::percent_encode(&certifying_body_id)
+        );
+    }
+    if let Some(standards_body_id) = params.standards_body_id {
+        link = format!(
+            "{}standards_body_id={}&",
+            link,
+            Uri::percent_encode(&standards_body_id)
+        );
+    }
+    if let Some(standard_id) = params.standard_id {
+        link = format!("{}standard_id={}&", link, Uri::percent_encode(&standard_id));
+    }
+    if let Some(status) = params.status {
+        link = format!("{}status={}&", link, Uri::percent_encode(&status));
+    }
+    link = format!("{}head={}&", link, head);
+
+    get_response_paging_info(
+        params.limit,
+        params.offset,
+        link.to_string().clone(),
+        total_count,
+    )
+}
@@ -33,6 +33,8 @@ pub struct ApiAgent {
     created_on: i64,
     #[serde(skip_serializing_if = "Option::is_none")]
     organization: Option<ApiOrganization>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    successor_public_key: Option<String>,
 }
 
 impl ApiAgent {
@@ -42,6 +44,7 @@ impl ApiAgent {
             name: agent.name.clone(),
             created_on: agent.timestamp,
             organization: org.as_ref().map(ApiOrganization::from),
+            successor_public_key: agent.successor_public_key.clone(),
         }
     }
 }
@@ -122,11 +125,13 @@ pub fn list_agents_with_params(params: Option<Form<AgentParams>>, conn: DbConn)
     let mut agents_query = agents::table
         .filter(agents::start_block_num.le(head_block_num))
         .filter(agents::end_block_num.gt(head_block_num))
+        .filter(agents::successor_public_key.is_null())
         .into_boxed();
 
     let total_count = agents::table
         .filter(agents::start_block_num.le(head_block_num))
         .filter(agents::end_block_num.gt(head_block_num))
+        .filter(agents::successor_public_key.is_null())
         .count()
         .get_result(&*conn)
         .map_err(|err| ApiError::InternalError(err.to_string()))?;
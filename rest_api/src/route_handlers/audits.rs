@@ -0,0 +1,223 @@
+use database::DbConn;
+use database_manager::custom_types::{AuditOutcomeEnum, FindingSeverityEnum};
+use database_manager::models::{Audit, AuditFinding};
+use database_manager::tables_schema::{audit_findings, audits};
+use diesel::prelude::*;
+use errors::ApiError;
+use paging::*;
+use rocket::http::uri::Uri;
+use rocket::request::Form;
+use rocket_contrib::json::JsonValue;
+use std::collections::HashMap;
+
+#[derive(Serialize)]
+pub struct ApiAuditFinding {
+    description: String,
+    severity: FindingSeverityEnum,
+}
+
+impl<'a> From<&'a AuditFinding> for ApiAuditFinding {
+    fn from(finding: &'a AuditFinding) -> Self {
+        ApiAuditFinding {
+            description: finding.description.clone(),
+            severity: finding.severity.clone(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct ApiAudit {
+    id: String,
+    request_id: String,
+    factory_id: String,
+    certifying_body_id: String,
+    auditor_public_key: String,
+    audit_date: i64,
+    outcome: AuditOutcomeEnum,
+    findings: Vec<ApiAuditFinding>,
+}
+
+impl ApiAudit {
+    fn with_findings(audit: &Audit, findings: &[AuditFinding]) -> Self {
+        ApiAudit {
+            id: audit.audit_id.clone(),
+            request_id: audit.request_id.clone(),
+            factory_id: audit.factory_id.clone(),
+            certifying_body_id: audit.certifying_body_id.clone(),
+            auditor_public_key: audit.auditor_public_key.clone(),
+            audit_date: audit.audit_date,
+            outcome: audit.outcome.clone(),
+            findings: findings.iter().map(ApiAuditFinding::from).collect(),
+        }
+    }
+}
+
+#[get("/audits/<audit_id>")]
+pub fn fetch_audit(audit_id: String, conn: DbConn) -> Result<JsonValue, ApiError> {
+    fetch_audit_with_head_param(audit_id, None, conn)
+}
+
+#[get("/audits/<audit_id>?<head_param..>")]
+pub fn fetch_audit_with_head_param(
+    audit_id: String,
+    head_param: Option<Form<AuditParams>>,
+    conn: DbConn,
+) -> Result<JsonValue, ApiError> {
+    let head_param = match head_param {
+        Some(param) => param.into_inner(),
+        None => Default::default(),
+    };
+    let head_block_num: i64 = get_head_block_num(head_param.head, &conn)?;
+
+    let audit = audits::table
+        .filter(audits::audit_id.eq(audit_id.to_string()))
+        .filter(audits::start_block_num.le(head_block_num))
+        .filter(audits::end_block_num.gt(head_block_num))
+        .first::<Audit>(&*conn)
+        .optional()
+        .map_err(|err| ApiError::InternalError(err.to_string()))?;
+
+    match audit {
+        Some(audit) => {
+            let findings = audit_findings::table
+                .filter(audit_findings::audit_id.eq(audit_id.to_string()))
+                .filter(audit_findings::start_block_num.le(head_block_num))
+                .filter(audit_findings::end_block_num.gt(head_block_num))
+                .load::<AuditFinding>(&*conn)
+                .map_err(|err| ApiError::InternalError(err.to_string()))?;
+
+            let link = format!("/api/audits/{}?head={}", audit_id, head_block_num);
+            Ok(json!({
+                "data": ApiAudit::with_findings(&audit, &findings),
+                "link": link,
+                "head": head_block_num,
+            }))
+        }
+        None => Err(ApiError::NotFound(format!(
+            "No audit with the ID {} exists",
+            audit_id
+        ))),
+    }
+}
+
+#[derive(Default, FromForm, Clone)]
+pub struct AuditParams {
+    request_id: Option<String>,
+    factory_id: Option<String>,
+    certifying_body_id: Option<String>,
+    limit: Option<i64>,
+    offset: Option<i64>,
+    head: Option<i64>,
+}
+
+#[get("/audits")]
+pub fn list_audits(conn: DbConn) -> Result<JsonValue, ApiError> {
+    list_audits_with_params(None, conn)
+}
+
+#[get("/audits?<params..>")]
+pub fn list_audits_with_params(
+    params: Option<Form<AuditParams>>,
+    conn: DbConn,
+) -> Result<JsonValue, ApiError> {
+    let params = match params {
+        Some(param) => param.into_inner(),
+        None => Default::default(),
+    };
+    let head_block_num: i64 = get_head_block_num(params.head, &conn)?;
+
+    let mut audits_query = audits::table
+        .filter(audits::start_block_num.le(head_block_num))
+        .filter(audits::end_block_num.gt(head_block_num))
+        .into_boxed();
+
+    let mut count_query = audits::table
+        .filter(audits::start_block_num.le(head_block_num))
+        .filter(audits::end_block_num.gt(head_block_num))
+        .into_boxed();
+    let link_params = params.clone();
+
+    if let Some(request_id) = params.request_id {
+        audits_query = audits_query.filter(audits::request_id.eq(request_id.to_string()));
+        count_query = count_query.filter(audits::request_id.eq(request_id.to_string()));
+    }
+    if let Some(factory_id) = params.factory_id {
+        audits_query = audits_query.filter(audits::factory_id.eq(factory_id.to_string()));
+        count_query = count_query.filter(audits::factory_id.eq(factory_id.to_string()));
+    }
+    if let Some(certifying_body_id) = params.certifying_body_id {
+        audits_query =
+            audits_query.filter(audits::certifying_body_id.eq(certifying_body_id.to_string()));
+        count_query =
+            count_query.filter(audits::certifying_body_id.eq(certifying_body_id.to_string()));
+    }
+
+    let total_count = count_query
+        .count()
+        .get_result(&*conn)
+        .map_err(|err| ApiError::InternalError(err.to_string()))?;
+    let paging_info = apply_paging(link_params, head_block_num, total_count)?;
+
+    audits_query = audits_query.limit(params.limit.unwrap_or(DEFAULT_LIMIT));
+    audits_query = audits_query.offset(params.offset.unwrap_or(DEFAULT_OFFSET));
+
+    let audit_results = audits_query
+        .load::<Audit>(&*conn)
+        .map_err(|err| ApiError::InternalError(err.to_string()))?;
+
+    let audit_ids: Vec<String> = audit_results
+        .iter()
+        .map(|audit| audit.audit_id.clone())
+        .collect();
+
+    let finding_results: HashMap<String, Vec<AuditFinding>> = audit_findings::table
+        .filter(audit_findings::start_block_num.le(head_block_num))
+        .filter(audit_findings::end_block_num.gt(head_block_num))
+        .filter(audit_findings::audit_id.eq_any(audit_ids))
+        .load::<AuditFinding>(&*conn)
+        .map_err(|err| ApiError::InternalError(err.to_string()))?
+        .into_iter()
+        .fold(HashMap::new(), |mut acc, finding| {
+            acc.entry(finding.audit_id.to_string())
+                .or_insert_with(|| vec![])
+                .push(finding);
+            acc
+        });
+
+    Ok(json!({
+        "data": audit_results.iter()
+            .map(|audit| ApiAudit::with_findings(
+                audit,
+                finding_results.get(&audit.audit_id).unwrap_or(&vec![])
+            )).collect::<Vec<_>>(),
+        "link": paging_info.get("link"),
+        "head": head_block_num,
+        "paging": paging_info.get("paging")
+    }))
+}
+
+fn apply_paging(params: AuditParams, head: i64, total_count: i64) -> Result<JsonValue, ApiError> {
+    let mut link = String::from("/api/audits?");
+
+    if let Some(request_id) = params.request_id {
+        link = format!("{}request_id={}&", link, Uri::percent_encode(&request_id));
+    }
+    if let Some(factory_id) = params.factory_id {
+        link = format!("{}factory_id={}&", link, Uri::percent_encode(&factory_id));
+    }
+    if let Some(certifying_body_id) = params.certifying_body_id {
+        link = format!(
+            "{}certifying_body_id={}&",
+            link,
+            Uri::percent_encode(&certifying_body_id)
+        );
+    }
+    link = format!("{}head={}&", link, head);
+
+    get_response_paging_info(
+        params.limit,
+        params.offset,
+        link.to_string().clone(),
+        total_count,
+    )
+}
@@ -1,11 +1,15 @@
 use database::DbConn;
-use database_manager::models::{Certificate, Organization, Standard};
-use database_manager::tables_schema::{certificates, organizations, standards};
+use database_manager::custom_types::CertificateStatusEnum;
+use database_manager::models::{Certificate, CertificateSuspension, Organization, Standard};
+use database_manager::tables_schema::{
+    certificate_suspensions, certificates, organizations, standards,
+};
 use diesel::prelude::*;
 use errors::ApiError;
 use paging::*;
 use rocket::request::Form;
 use rocket_contrib::json::JsonValue;
+use std::collections::HashSet;
 
 #[derive(Serialize)]
 pub struct ApiCertificate {
@@ -19,6 +23,53 @@ pub struct ApiCertificate {
     standard_version: String,
     valid_from: i64,
     valid_to: i64,
+    status: CertificateStatusEnum,
+    revoked_at: Option<i64>,
+    previous_certificate_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    audit_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    suspensions: Option<Vec<ApiCertificateSuspension>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    renewal_chain: Option<Vec<ApiRenewalLink>>,
+}
+
+#[derive(Serialize)]
+pub struct ApiRenewalLink {
+    id: String,
+    standard_version: String,
+    valid_from: i64,
+    valid_to: i64,
+    status: CertificateStatusEnum,
+}
+
+impl<'a> From<&'a Certificate> for ApiRenewalLink {
+    fn from(certificate: &Certificate) -> Self {
+        ApiRenewalLink {
+            id: certificate.certificate_id.clone(),
+            standard_version: certificate.standard_version.clone(),
+            valid_from: certificate.valid_from,
+            valid_to: certificate.valid_to,
+            status: certificate.status.clone(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct ApiCertificateSuspension {
+    reason: String,
+    suspended_at: i64,
+    reinstated_at: Option<i64>,
+}
+
+impl From<CertificateSuspension> for ApiCertificateSuspension {
+    fn from(suspension: CertificateSuspension) -> Self {
+        ApiCertificateSuspension {
+            reason: suspension.reason,
+            suspended_at: suspension.suspended_at,
+            reinstated_at: suspension.reinstated_at,
+        }
+    }
 }
 
 impl From<(Certificate, Organization, Standard, Organization)> for ApiCertificate {
@@ -41,6 +92,12 @@ impl From<(Certificate, Organization, Standard, Organization)> for ApiCertificat
             standard_version: certificate.standard_version,
             valid_from: certificate.valid_from,
             valid_to: certificate.valid_to,
+            status: certificate.status,
+            revoked_at: certificate.revoked_at,
+            previous_certificate_id: certificate.previous_certificate_id,
+            audit_id: certificate.audit_id,
+            suspensions: None,
+            renewal_chain: None,
         }
     }
 }
@@ -105,10 +162,30 @@ pub fn fetch_certificate_with_head_param(
     );
 
     match result {
-        Some(cert_std_tuple) => Ok(json!({
-                "data": ApiCertificate::from(cert_std_tuple?),
+        Some(cert_std_tuple) => {
+            let cert_std_tuple = cert_std_tuple?;
+            let renewal_chain = fetch_renewal_chain(&conn, &cert_std_tuple.0, head_block_num)?;
+
+            let suspensions = certificate_suspensions::table
+                .filter(certificate_suspensions::certificate_id.eq(certificate_id.to_string()))
+                .filter(certificate_suspensions::start_block_num.le(head_block_num))
+                .filter(certificate_suspensions::end_block_num.gt(head_block_num))
+                .order_by(certificate_suspensions::suspended_at.asc())
+                .load::<CertificateSuspension>(&*conn)
+                .map_err(|err| ApiError::InternalError(err.to_string()))?
+                .into_iter()
+                .map(ApiCertificateSuspension::from)
+                .collect();
+
+            let mut certificate = ApiCertificate::from(cert_std_tuple);
+            certificate.suspensions = Some(suspensions);
+            certificate.renewal_chain = Some(renewal_chain);
+
+            Ok(json!({
+                "data": certificate,
                 "link": link,
-                "head": head_block_num, })),
+                "head": head_block_num, }))
+        }
         None => Err(ApiError::NotFound(format!(
             "No certificate with the ID {} exists",
             certificate_id
@@ -144,6 +221,7 @@ pub fn list_certificates_with_params(
     let mut certificate_query = certificates::table
         .filter(certificates::start_block_num.le(head_block_num))
         .filter(certificates::end_block_num.gt(head_block_num))
+        .filter(certificates::status.ne(CertificateStatusEnum::Revoked))
         .left_join(
             standards::table.on(standards::standard_id
                 .eq(certificates::standard_id)
@@ -161,6 +239,7 @@ pub fn list_certificates_with_params(
     let mut count_query = certificates::table
         .filter(certificates::start_block_num.le(head_block_num))
         .filter(certificates::end_block_num.gt(head_block_num))
+        .filter(certificates::status.ne(CertificateStatusEnum::Revoked))
         .into_boxed();
     let link_params = params.clone();
 
@@ -215,6 +294,69 @@ pub fn list_certificates_with_params(
                 "paging": paging_info.get("paging") }))
 }
 
+/// Returns the renewal chain the certificate belongs to, ordered from the
+/// original certificate to its most recent renewal.
+fn fetch_renewal_chain(
+    conn: &DbConn,
+    certificate: &Certificate,
+    head_block_num: i64,
+) -> Result<Vec<ApiRenewalLink>, ApiError> {
+    let mut visited = HashSet::new();
+    visited.insert(certificate.certificate_id.clone());
+
+    let mut predecessors = vec![];
+    let mut previous_id = certificate.previous_certificate_id.clone();
+    while let Some(id) = previous_id {
+        if !visited.insert(id.clone()) {
+            break;
+        }
+        let previous = certificates::table
+            .filter(certificates::certificate_id.eq(id))
+            .filter(certificates::start_block_num.le(head_block_num))
+            .filter(certificates::end_block_num.gt(head_block_num))
+            .first::<Certificate>(&**conn)
+            .optional()
+            .map_err(|err| ApiError::InternalError(err.to_string()))?;
+        match previous {
+            Some(previous) => {
+                previous_id = previous.previous_certificate_id.clone();
+                predecessors.push(previous);
+            }
+            None => break,
+        }
+    }
+    predecessors.reverse();
+
+    let mut successors = vec![];
+    let mut current_id = certificate.certificate_id.clone();
+    loop {
+        let next = certificates::table
+            .filter(certificates::previous_certificate_id.eq(current_id.clone()))
+            .filter(certificates::start_block_num.le(head_block_num))
+            .filter(certificates::end_block_num.gt(head_block_num))
+            .first::<Certificate>(&**conn)
+            .optional()
+            .map_err(|err| ApiError::InternalError(err.to_string()))?;
+        match next {
+            Some(next) => {
+                if !visited.insert(next.certificate_id.clone()) {
+                    break;
+                }
+                current_id = next.certificate_id.clone();
+                successors.push(next);
+            }
+            None => break,
+        }
+    }
+
+    Ok(predecessors
+        .iter()
+        .chain(Some(certificate))
+        .chain(successors.iter())
+        .map(ApiRenewalLink::from)
+        .collect())
+}
+
 fn require_org(conn: &DbConn, org_id: &str, head_block_num: i64) -> Result<Organization, ApiError> {
     organizations::table
         .filter(organizations::organization_id.eq(org_id))
@@ -0,0 +1,126 @@
+use crypto::digest::Digest;
+use crypto::sha2::Sha256;
+use database::DbConn;
+use database_manager::models::DocumentReference;
+use database_manager::tables_schema::document_references;
+use diesel::prelude::*;
+use errors::ApiError;
+use paging::*;
+use rocket::request::Form;
+use rocket::Data;
+use rocket_contrib::json::JsonValue;
+use std::io::Read;
+
+/// Largest evidence document, in bytes, that can be uploaded for verification
+const MAX_DOCUMENT_SIZE: u64 = 10 * 1024 * 1024;
+
+#[derive(Serialize)]
+pub struct ApiDocumentReference {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    certificate_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    request_id: Option<String>,
+    sha256: String,
+    media_type: String,
+    title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    uri: Option<String>,
+}
+
+impl From<DocumentReference> for ApiDocumentReference {
+    fn from(document: DocumentReference) -> Self {
+        ApiDocumentReference {
+            certificate_id: document.certificate_id,
+            request_id: document.request_id,
+            sha256: document.sha256,
+            media_type: document.media_type,
+            title: document.title,
+            uri: document.uri,
+        }
+    }
+}
+
+#[derive(Default, FromForm, Clone)]
+pub struct VerifyDocumentParams {
+    certificate_id: Option<String>,
+    request_id: Option<String>,
+    head: Option<i64>,
+}
+
+#[post(
+    "/documents/verify",
+    format = "application/octet-stream",
+    data = "<data>"
+)]
+pub fn verify_document(data: Data, conn: DbConn) -> Result<JsonValue, ApiError> {
+    verify_document_with_params(None, data, conn)
+}
+
+#[post(
+    "/documents/verify?<params..>",
+    format = "application/octet-stream",
+    data = "<data>"
+)]
+pub fn verify_document_with_params(
+    params: Option<Form<VerifyDocumentParams>>,
+    data: Data,
+    conn: DbConn,
+) -> Result<JsonValue, ApiError> {
+    let params = match params {
+        Some(param) => param.into_inner(),
+        None => Default::default(),
+    };
+    let head_block_num: i64 = get_head_block_num(params.head, &conn)?;
+
+    // Hash the document as it is read, reading one byte past the limit to detect larger bodies
+    let mut stream = data.open().take(MAX_DOCUMENT_SIZE + 1);
+    let mut sha = Sha256::new();
+    let mut buffer = [0; 8192];
+    let mut size: u64 = 0;
+    loop {
+        let read = stream
+            .read(&mut buffer)
+            .map_err(|err| ApiError::BadRequest(err.to_string()))?;
+        if read == 0 {
+            break;
+        }
+        size += read as u64;
+        if size > MAX_DOCUMENT_SIZE {
+            return Err(ApiError::BadRequest(format!(
+                "Document is larger than {} bytes",
+                MAX_DOCUMENT_SIZE
+            )));
+        }
+        sha.input(&buffer[..read]);
+    }
+    let sha256 = sha.result_str();
+
+    let mut documents_query = document_references::table
+        .filter(document_references::sha256.eq(sha256.to_string()))
+        .filter(document_references::start_block_num.le(head_block_num))
+        .filter(document_references::end_block_num.gt(head_block_num))
+        .into_boxed();
+
+    if let Some(certificate_id) = params.certificate_id {
+        documents_query = documents_query
+            .filter(document_references::certificate_id.eq(certificate_id.to_string()));
+    }
+    if let Some(request_id) = params.request_id {
+        documents_query =
+            documents_query.filter(document_references::request_id.eq(request_id.to_string()));
+    }
+
+    let documents = documents_query
+        .load::<DocumentReference>(&*conn)
+        .map_err(|err| ApiError::InternalError(err.to_string()))?;
+
+    Ok(json!({
+        "data": {
+            "sha256": sha256,
+            "verified": !documents.is_empty(),
+            "documents": documents.into_iter()
+                .map(ApiDocumentReference::from).collect::<Vec<_>>(),
+        },
+        "head": head_block_num,
+    }))
+}
@@ -1,12 +1,13 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use database::DbConn;
-use database_manager::custom_types::OrganizationTypeEnum;
+use database_manager::custom_types::{CertificateStatusEnum, OrganizationTypeEnum};
 use database_manager::models::{
-    Address, Authorization, Certificate, Contact, Organization, Standard,
+    Address, Authorization, Certificate, Contact, Organization, Standard, StandardEquivalence,
 };
 use database_manager::tables_schema::{
-    addresses, authorizations, certificates, contacts, organizations, standards,
+    addresses, authorizations, certificates, contacts, organizations, standard_equivalences,
+    standards,
 };
 use diesel::prelude::*;
 use errors::ApiError;
@@ -19,6 +20,7 @@ use route_handlers::organizations::ApiFactory;
 #[derive(Default, FromForm, Clone)]
 pub struct FactoryParams {
     name: Option<String>,
+    satisfies_standard: Option<String>,
     limit: Option<i64>,
     offset: Option<i64>,
     head: Option<i64>,
@@ -148,6 +150,14 @@ fn query_factories(params: Option<Form<FactoryParams>>, conn: DbConn) -> Result<
         count_query = count_query.filter(organizations::name.eq(name.to_string()));
     }
 
+    if let Some(standard_id) = params.satisfies_standard {
+        let factory_ids =
+            query_factories_satisfying_standard(&conn, head_block_num, &standard_id)?;
+        factories_query =
+            factories_query.filter(organizations::organization_id.eq_any(factory_ids.clone()));
+        count_query = count_query.filter(organizations::organization_id.eq_any(factory_ids));
+    }
+
     let total_count = count_query
         .count()
         .get_result(&*conn)
@@ -260,7 +270,7 @@ fn query_factories(params: Option<Form<FactoryParams>>, conn: DbConn) -> Result<
     }))
 }
 
-fn query_certifications(
+pub fn query_certifications(
     conn: DbConn,
     head_block_num: i64,
     factory_ids: &[String],
@@ -302,6 +312,72 @@ fn query_certifications(
         .collect()
 }
 
+/// Returns the ids of the factories holding a valid certificate that satisfies the standard,
+/// either because it was issued against the standard itself or against a standard version
+/// that is mutually recognized as equivalent to a version of it
+fn query_factories_satisfying_standard(
+    conn: &DbConn,
+    head_block_num: i64,
+    standard_id: &str,
+) -> Result<Vec<String>, ApiError> {
+    let equivalences = standard_equivalences::table
+        .filter(standard_equivalences::start_block_num.le(head_block_num))
+        .filter(standard_equivalences::end_block_num.gt(head_block_num))
+        .filter(
+            standard_equivalences::standard_id
+                .eq(standard_id.to_string())
+                .or(standard_equivalences::equivalent_standard_id.eq(standard_id.to_string())),
+        )
+        .load::<StandardEquivalence>(&**conn)
+        .map_err(|err| ApiError::InternalError(err.to_string()))?;
+
+    // An equivalence only counts once both standards have declared it
+    let equivalent_versions: HashSet<(String, String)> = equivalences
+        .iter()
+        .filter(|declared| {
+            declared.standard_id == standard_id
+                && equivalences.iter().any(|reciprocal| {
+                    reciprocal.standard_id == declared.equivalent_standard_id
+                        && reciprocal.version == declared.equivalent_standard_version
+                        && reciprocal.equivalent_standard_id == declared.standard_id
+                        && reciprocal.equivalent_standard_version == declared.version
+                })
+        })
+        .map(|declared| {
+            (
+                declared.equivalent_standard_id.clone(),
+                declared.equivalent_standard_version.clone(),
+            )
+        })
+        .collect();
+
+    let mut standard_ids: Vec<String> = equivalent_versions
+        .iter()
+        .map(|&(ref equivalent_standard_id, _)| equivalent_standard_id.clone())
+        .collect();
+    standard_ids.push(standard_id.to_string());
+
+    let factory_ids: HashSet<String> = certificates::table
+        .filter(certificates::start_block_num.le(head_block_num))
+        .filter(certificates::end_block_num.gt(head_block_num))
+        .filter(certificates::status.eq(CertificateStatusEnum::Valid))
+        .filter(certificates::standard_id.eq_any(standard_ids))
+        .load::<Certificate>(&**conn)
+        .map_err(|err| ApiError::InternalError(err.to_string()))?
+        .into_iter()
+        .filter(|certificate| {
+            certificate.standard_id == standard_id
+                || equivalent_versions.contains(&(
+                    certificate.standard_id.clone(),
+                    certificate.standard_version.clone(),
+                ))
+        })
+        .map(|certificate| certificate.factory_id)
+        .collect();
+
+    Ok(factory_ids.into_iter().collect())
+}
+
 fn apply_paging(
     params: FactoryParams,
     head: i64,
@@ -312,6 +388,13 @@ fn apply_paging(
     if let Some(name) = params.name {
         link = format!("{}name={}&", link, Uri::percent_encode(&name));
     }
+    if let Some(standard_id) = params.satisfies_standard {
+        link = format!(
+            "{}satisfies_standard={}&",
+            link,
+            Uri::percent_encode(&standard_id)
+        );
+    }
     link = format!("{}head={}&", link, head);
 
     if let Some(expand) = params.expand {
@@ -1,10 +1,15 @@
+pub mod accreditation_requests;
 pub mod agents;
+pub mod audits;
 pub mod authorization;
 pub mod blockchain;
 pub mod blocks;
 pub mod certificates;
+pub mod documents;
 pub mod factories;
 pub mod organizations;
+pub mod proposals;
 pub mod requests;
+pub mod retailers;
 pub mod standards;
 pub mod standards_body;
@@ -1,4 +1,5 @@
 use database::DbConn;
+use database_manager::custom_types::ApprovalStatusEnum;
 use database_manager::custom_types::OrganizationTypeEnum;
 use database_manager::custom_types::RoleEnum;
 use database_manager::models::{
@@ -55,6 +56,10 @@ impl ApiAddress {
 pub struct ApiAuthorization {
     public_key: String,
     role: RoleEnum,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    role_name: Option<String>,
+    valid_from: Option<i64>,
+    valid_to: Option<i64>,
 }
 
 impl ApiAuthorization {
@@ -62,6 +67,9 @@ impl ApiAuthorization {
         ApiAuthorization {
             public_key: db_authorization.public_key,
             role: db_authorization.role,
+            role_name: db_authorization.role_name,
+            valid_from: db_authorization.valid_from,
+            valid_to: db_authorization.valid_to,
         }
     }
 
@@ -69,6 +77,9 @@ impl ApiAuthorization {
         ApiAuthorization {
             public_key: db_authorization.public_key.clone(),
             role: db_authorization.role.clone(),
+            role_name: db_authorization.role_name.clone(),
+            valid_from: db_authorization.valid_from,
+            valid_to: db_authorization.valid_to,
         }
     }
 }
@@ -197,6 +208,7 @@ pub struct ApiCertifyingBody {
     contacts: Vec<ApiContact>,
     authorizations: Vec<ApiAuthorization>,
     organization_type: OrganizationTypeEnum,
+    approval_status: ApprovalStatusEnum,
 }
 
 impl ApiCertifyingBody {
@@ -217,6 +229,7 @@ impl ApiCertifyingBody {
                 .map(|auth| ApiAuthorization::from(auth))
                 .collect(),
             organization_type: db_organization.organization_type,
+            approval_status: db_organization.approval_status,
         }
     }
 }
@@ -228,6 +241,7 @@ pub struct ApiStandardsBody {
     contacts: Vec<ApiContact>,
     authorizations: Vec<ApiAuthorization>,
     organization_type: OrganizationTypeEnum,
+    approval_status: ApprovalStatusEnum,
 }
 
 impl ApiStandardsBody {
@@ -248,6 +262,38 @@ impl ApiStandardsBody {
                 .map(|auth| ApiAuthorization::from(auth))
                 .collect(),
             organization_type: db_organization.organization_type,
+            approval_status: db_organization.approval_status,
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct ApiRetailer {
+    id: String,
+    name: String,
+    contacts: Vec<ApiContact>,
+    authorizations: Vec<ApiAuthorization>,
+    organization_type: OrganizationTypeEnum,
+}
+
+impl ApiRetailer {
+    fn from(
+        db_organization: Organization,
+        db_contacts: Vec<Contact>,
+        db_authorizations: Vec<Authorization>,
+    ) -> Self {
+        ApiRetailer {
+            id: db_organization.organization_id.to_string(),
+            name: db_organization.name,
+            contacts: db_contacts
+                .into_iter()
+                .map(|contact| ApiContact::from(contact))
+                .collect(),
+            authorizations: db_authorizations
+                .into_iter()
+                .map(|auth| ApiAuthorization::from(auth))
+                .collect(),
+            organization_type: db_organization.organization_type,
         }
     }
 }
@@ -324,6 +370,11 @@ pub fn fetch_organization_with_params(
                     contact_results,
                     authorization_results
                 )),
+                OrganizationTypeEnum::Retailer => json!(ApiRetailer::from(
+                    org,
+                    contact_results,
+                    authorization_results
+                )),
                 OrganizationTypeEnum::UnsetType => json!({}),
             };
 
@@ -382,6 +433,7 @@ pub fn list_organizations_with_params(
     if let Some(organization_type) = params.organization_type {
         let org_type = match organization_type {
             1 => OrganizationTypeEnum::CertifyingBody,
+            4 => OrganizationTypeEnum::Retailer,
             _ => OrganizationTypeEnum::StandardsBody,
         };
 
@@ -493,6 +545,13 @@ pub fn list_organizations_with_params(
                             authorization_results.remove(&org_id).unwrap_or_else(|| vec![]),
                         ))
                     }
+                    OrganizationTypeEnum::Retailer => {
+                        json!(ApiRetailer::from(
+                            org,
+                            contact_results.remove(&org_id).unwrap_or_else(|| vec![]),
+                            authorization_results.remove(&org_id).unwrap_or_else(|| vec![]),
+                        ))
+                    }
                     OrganizationTypeEnum::UnsetType => json!({})
                 }
             }).collect::<Vec<_>>(),
@@ -0,0 +1,233 @@
+use database::DbConn;
+use database_manager::custom_types::ProposalStatusEnum;
+use database_manager::models::{Proposal, ProposalVote};
+use database_manager::tables_schema::{proposal_votes, proposals};
+use diesel::prelude::*;
+use errors::ApiError;
+use paging::*;
+use rocket::http::uri::Uri;
+use rocket::request::Form;
+use rocket_contrib::json::JsonValue;
+use std::collections::HashMap;
+
+#[derive(Serialize)]
+pub struct ApiProposalVote {
+    public_key: String,
+    accept: bool,
+}
+
+impl<'a> From<&'a ProposalVote> for ApiProposalVote {
+    fn from(vote: &'a ProposalVote) -> Self {
+        ApiProposalVote {
+            public_key: vote.public_key.clone(),
+            accept: vote.accept,
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct ApiProposal {
+    id: String,
+    organization_id: String,
+    proposer: String,
+    action: String,
+    payload: String,
+    required_approvals: i32,
+    status: ProposalStatusEnum,
+    proposed_at: i64,
+    votes: Vec<ApiProposalVote>,
+}
+
+impl ApiProposal {
+    fn with_votes(proposal: &Proposal, votes: &[ProposalVote]) -> Self {
+        ApiProposal {
+            id: proposal.proposal_id.clone(),
+            organization_id: proposal.organization_id.clone(),
+            proposer: proposal.proposer.clone(),
+            action: proposal.action.clone(),
+            payload: proposal.payload.clone(),
+            required_approvals: proposal.required_approvals,
+            status: proposal.status.clone(),
+            proposed_at: proposal.proposed_at,
+            votes: votes.iter().map(ApiProposalVote::from).collect(),
+        }
+    }
+}
+
+#[get("/proposals/<proposal_id>")]
+pub fn fetch_proposal(proposal_id: String, conn: DbConn) -> Result<JsonValue, ApiError> {
+    fetch_proposal_with_head_param(proposal_id, None, conn)
+}
+
+#[get("/proposals/<proposal_id>?<head_param..>")]
+pub fn fetch_proposal_with_head_param(
+    proposal_id: String,
+    head_param: Option<Form<ProposalParams>>,
+    conn: DbConn,
+) -> Result<JsonValue, ApiError> {
+    let head_param = match head_param {
+        Some(param) => param.into_inner(),
+        None => Default::default(),
+    };
+    let head_block_num: i64 = get_head_block_num(head_param.head, &conn)?;
+
+    let proposal = proposals::table
+        .filter(proposals::proposal_id.eq(proposal_id.to_string()))
+        .filter(proposals::start_block_num.le(head_block_num))
+        .filter(proposals::end_block_num.gt(head_block_num))
+        .first::<Proposal>(&*conn)
+        .optional()
+        .map_err(|err| ApiError::InternalError(err.to_string()))?;
+
+    match proposal {
+        Some(proposal) => {
+            let votes = proposal_votes::table
+                .filter(proposal_votes::proposal_id.eq(proposal_id.to_string()))
+                .filter(proposal_votes::start_block_num.le(head_block_num))
+                .filter(proposal_votes::end_block_num.gt(head_block_num))
+                .load::<ProposalVote>(&*conn)
+                .map_err(|err| ApiError::InternalError(err.to_string()))?;
+
+            let link = format!("/api/proposals/{}?head={}", proposal_id, head_block_num);
+            Ok(json!({
+                "data": ApiProposal::with_votes(&proposal, &votes),
+                "link": link,
+                "head": head_block_num,
+            }))
+        }
+        None => Err(ApiError::NotFound(format!(
+            "No proposal with the ID {} exists",
+            proposal_id
+        ))),
+    }
+}
+
+#[derive(Default, FromForm, Clone)]
+pub struct ProposalParams {
+    organization_id: Option<String>,
+    status: Option<String>,
+    limit: Option<i64>,
+    offset: Option<i64>,
+    head: Option<i64>,
+}
+
+#[get("/proposals")]
+pub fn list_proposals(conn: DbConn) -> Result<JsonValue, ApiError> {
+    list_proposals_with_params(None, conn)
+}
+
+#[get("/proposals?<params..>")]
+pub fn list_proposals_with_params(
+    params: Option<Form<ProposalParams>>,
+    conn: DbConn,
+) -> Result<JsonValue, ApiError> {
+    let params = match params {
+        Some(param) => param.into_inner(),
+        None => Default::default(),
+    };
+    let head_block_num: i64 = get_head_block_num(params.head, &conn)?;
+
+    let mut proposals_query = proposals::table
+        .filter(proposals::start_block_num.le(head_block_num))
+        .filter(proposals::end_block_num.gt(head_block_num))
+        .into_boxed();
+
+    let mut count_query = proposals::table
+        .filter(proposals::start_block_num.le(head_block_num))
+        .filter(proposals::end_block_num.gt(head_block_num))
+        .into_boxed();
+    let link_params = params.clone();
+
+    if let Some(organization_id) = params.organization_id {
+        proposals_query =
+            proposals_query.filter(proposals::organization_id.eq(organization_id.to_string()));
+        count_query =
+            count_query.filter(proposals::organization_id.eq(organization_id.to_string()));
+    }
+    if let Some(status) = params.status {
+        let status = match status.as_ref() {
+            "OPEN" => ProposalStatusEnum::Open,
+            "ACCEPTED" => ProposalStatusEnum::Accepted,
+            "REJECTED" => ProposalStatusEnum::Rejected,
+            "WITHDRAWN" => ProposalStatusEnum::Withdrawn,
+            _ => {
+                return Err(ApiError::BadRequest(format!(
+                    "Invalid proposal status: {}",
+                    status
+                )));
+            }
+        };
+        proposals_query = proposals_query.filter(proposals::status.eq(status.clone()));
+        count_query = count_query.filter(proposals::status.eq(status));
+    }
+
+    let total_count = count_query
+        .count()
+        .get_result(&*conn)
+        .map_err(|err| ApiError::InternalError(err.to_string()))?;
+    let paging_info = apply_paging(link_params, head_block_num, total_count)?;
+
+    proposals_query = proposals_query.limit(params.limit.unwrap_or(DEFAULT_LIMIT));
+    proposals_query = proposals_query.offset(params.offset.unwrap_or(DEFAULT_OFFSET));
+
+    let proposal_results = proposals_query
+        .load::<Proposal>(&*conn)
+        .map_err(|err| ApiError::InternalError(err.to_string()))?;
+
+    let proposal_ids: Vec<String> = proposal_results
+        .iter()
+        .map(|proposal| proposal.proposal_id.clone())
+        .collect();
+
+    let vote_results: HashMap<String, Vec<ProposalVote>> = proposal_votes::table
+        .filter(proposal_votes::start_block_num.le(head_block_num))
+        .filter(proposal_votes::end_block_num.gt(head_block_num))
+        .filter(proposal_votes::proposal_id.eq_any(proposal_ids))
+        .load::<ProposalVote>(&*conn)
+        .map_err(|err| ApiError::InternalError(err.to_string()))?
+        .into_iter()
+        .fold(HashMap::new(), |mut acc, vote| {
+            acc.entry(vote.proposal_id.to_string())
+                .or_insert_with(|| vec![])
+                .push(vote);
+            acc
+        });
+
+    Ok(json!({
+        "data": proposal_results.iter()
+            .map(|proposal| ApiProposal::with_votes(
+                proposal,
+                vote_results.get(&proposal.proposal_id).unwrap_or(&vec![])
+            )).collect::<Vec<_>>(),
+        "link": paging_info.get("link"),
+        "head": head_block_num,
+        "paging": paging_info.get("paging")
+    }))
+}
+
+fn apply_paging(
+    params: ProposalParams,
+    head: i64,
+    total_count: i64,
+) -> Result<JsonValue, ApiError> {
+    let mut link = String::from("/api/proposals?");
+
+    if let Some(organization_id) = params.organization_id {
+        link = format!(
+            "{}organization_id={}&",
+            link,
+            Uri::percent_encode(&organization_id)
+        );
+    }
+    if let Some(status) = params.status {
+        link = format!("{}status={}&", link, Uri::percent_encode(&status));
+    }
+    link = format!("{}head={}&", link, head);
+
+    get_response_paging_info(
+        params.limit,
+        params.offset,
+        link.to_string().clone(),
+        total_count,
+    )
+}
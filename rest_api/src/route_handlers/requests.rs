@@ -107,6 +107,7 @@ pub fn fetch_request_with_head_param(
 #[derive(Default, FromForm, Clone)]
 pub struct CertRequestParams {
     factory_id: Option<String>,
+    certifying_body_id: Option<String>,
     expand: Option<bool>,
     limit: Option<i64>,
     offset: Option<i64>,
@@ -120,6 +121,10 @@ pub struct ApiRequest {
     standard: StandardExpansion,
     status: RequestStatusEnum,
     request_date: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    certifying_body_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    decline_reason: Option<String>,
 }
 
 impl ApiRequest {
@@ -139,6 +144,8 @@ impl ApiRequest {
             },
             status: req.status,
             request_date: req.request_date,
+            certifying_body_id: req.certifying_body_id,
+            decline_reason: req.decline_reason,
         }
     }
 
@@ -149,6 +156,8 @@ impl ApiRequest {
             standard: StandardExpansion::Expanded(standard),
             status: req.status,
             request_date: req.request_date,
+            certifying_body_id: req.certifying_body_id,
+            decline_reason: req.decline_reason,
         }
     }
 }
@@ -194,6 +203,7 @@ fn query_requests(params: Option<Form<CertRequestParams>>, conn: DbConn) -> Resu
         .filter(requests::end_block_num.gt(head_block_num))
         .filter(requests::status.ne(RequestStatusEnum::Closed))
         .filter(requests::status.ne(RequestStatusEnum::Certified))
+        .filter(requests::status.ne(RequestStatusEnum::Declined))
         .into_boxed();
 
     let mut count_query = requests::table
@@ -206,6 +216,12 @@ fn query_requests(params: Option<Form<CertRequestParams>>, conn: DbConn) -> Resu
         requests_query = requests_query.filter(requests::factory_id.eq(factory_id.to_string()));
         count_query = count_query.filter(requests::factory_id.eq(factory_id.to_string()));
     }
+    if let Some(certifying_body_id) = params.certifying_body_id {
+        requests_query = requests_query
+            .filter(requests::certifying_body_id.eq(certifying_body_id.to_string()));
+        count_query =
+            count_query.filter(requests::certifying_body_id.eq(certifying_body_id.to_string()));
+    }
 
     let total_count = count_query
         .count()
@@ -355,6 +371,8 @@ fn fetch_expansions(
         });
 
     let standard_version_results: HashMap<String, Vec<StandardVersion>> = standard_versions::table
+        .filter(standard_versions::start_block_num.le(head_block_num))
+        .filter(standard_versions::end_block_num.gt(head_block_num))
         .filter(standard_versions::standard_id.eq_any(standard_ids))
         .order_by(standard_versions::approval_date.desc())
         .load::<StandardVersion>(&**conn)
@@ -387,6 +405,13 @@ fn apply_paging(
     if let Some(factory_id) = params.factory_id {
         link = format!("{}factory_id={}&", link, Uri::percent_encode(&factory_id));
     }
+    if let Some(certifying_body_id) = params.certifying_body_id {
+        link = format!(
+            "{}certifying_body_id={}&",
+            link,
+            Uri::percent_encode(&certifying_body_id)
+        );
+    }
     if let Some(expand) = params.expand {
         link = format!("{}expand={}&", link, expand);
     }
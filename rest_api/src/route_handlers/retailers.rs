@@ -0,0 +1,141 @@
+use std::collections::HashMap;
+
+use database::DbConn;
+use database_manager::custom_types::OrganizationTypeEnum;
+use database_manager::models::{
+    Address, Authorization, Certificate, Contact, Organization, RetailerFactory, Standard,
+};
+use database_manager::tables_schema::{
+    addresses, authorizations, contacts, organizations, retailer_factories,
+};
+use diesel::prelude::*;
+use errors::ApiError;
+use paging::*;
+use rocket::request::Form;
+use rocket_contrib::json::JsonValue;
+use route_handlers::factories::query_certifications;
+use route_handlers::organizations::ApiFactory;
+
+#[derive(Default, FromForm, Clone)]
+pub struct SupplierParams {
+    head: Option<i64>,
+}
+
+#[get("/retailers/<organization_id>/suppliers")]
+pub fn list_suppliers(organization_id: String, conn: DbConn) -> Result<JsonValue, ApiError> {
+    list_suppliers_with_params(organization_id, None, conn)
+}
+
+#[get("/retailers/<organization_id>/suppliers?<params..>")]
+pub fn list_suppliers_with_params(
+    organization_id: String,
+    params: Option<Form<SupplierParams>>,
+    conn: DbConn,
+) -> Result<JsonValue, ApiError> {
+    let params = match params {
+        Some(param) => param.into_inner(),
+        None => Default::default(),
+    };
+    let head_block_num: i64 = get_head_block_num(params.head, &conn)?;
+
+    let retailer = organizations::table
+        .filter(organizations::organization_type.eq(OrganizationTypeEnum::Retailer))
+        .filter(organizations::organization_id.eq(organization_id.to_string()))
+        .filter(organizations::start_block_num.le(head_block_num))
+        .filter(organizations::end_block_num.gt(head_block_num))
+        .first::<Organization>(&*conn)
+        .optional()
+        .map_err(|err| ApiError::InternalError(err.to_string()))?;
+
+    if retailer.is_none() {
+        return Err(ApiError::NotFound(format!(
+            "No retailer with the organization ID {} exists",
+            organization_id
+        )));
+    }
+
+    let supplier_ids: Vec<String> = retailer_factories::table
+        .filter(retailer_factories::retailer_id.eq(organization_id.to_string()))
+        .filter(retailer_factories::start_block_num.le(head_block_num))
+        .filter(retailer_factories::end_block_num.gt(head_block_num))
+        .load::<RetailerFactory>(&*conn)
+        .map_err(|err| ApiError::InternalError(err.to_string()))?
+        .into_iter()
+        .map(|supplier| supplier.factory_id)
+        .collect();
+
+    let factory_results: Vec<Organization> = organizations::table
+        .filter(organizations::organization_type.eq(OrganizationTypeEnum::Factory))
+        .filter(organizations::organization_id.eq_any(&supplier_ids))
+        .filter(organizations::start_block_num.le(head_block_num))
+        .filter(organizations::end_block_num.gt(head_block_num))
+        .order_by(organizations::organization_id.asc())
+        .load::<Organization>(&*conn)
+        .map_err(|err| ApiError::InternalError(err.to_string()))?;
+
+    let mut contact_results: HashMap<String, Vec<Contact>> = contacts::table
+        .filter(contacts::start_block_num.le(head_block_num))
+        .filter(contacts::end_block_num.gt(head_block_num))
+        .filter(contacts::organization_id.eq_any(&supplier_ids))
+        .load::<Contact>(&*conn)
+        .map_err(|err| ApiError::InternalError(err.to_string()))?
+        .into_iter()
+        .fold(HashMap::new(), |mut acc, contact| {
+            acc.entry(contact.organization_id.to_string())
+                .or_insert(vec![])
+                .push(contact);
+            acc
+        });
+
+    let mut authorization_results: HashMap<String, Vec<Authorization>> = authorizations::table
+        .filter(authorizations::start_block_num.le(head_block_num))
+        .filter(authorizations::end_block_num.gt(head_block_num))
+        .filter(authorizations::organization_id.eq_any(&supplier_ids))
+        .load::<Authorization>(&*conn)
+        .map_err(|err| ApiError::InternalError(err.to_string()))?
+        .into_iter()
+        .fold(HashMap::new(), |mut acc, authorization| {
+            acc.entry(authorization.organization_id.to_string())
+                .or_insert(vec![])
+                .push(authorization);
+            acc
+        });
+
+    let mut address_results: HashMap<String, Address> = addresses::table
+        .filter(addresses::start_block_num.le(head_block_num))
+        .filter(addresses::end_block_num.gt(head_block_num))
+        .filter(addresses::organization_id.eq_any(&supplier_ids))
+        .load::<Address>(&*conn)
+        .map_err(|err| ApiError::InternalError(err.to_string()))?
+        .into_iter()
+        .fold(HashMap::new(), |mut acc, address| {
+            acc.insert(address.organization_id.to_string(), address);
+            acc
+        });
+
+    let mut cert_results: HashMap<String, Vec<(Certificate, Standard, Organization)>> =
+        query_certifications(conn, head_block_num, &supplier_ids)?
+            .into_iter()
+            .fold(HashMap::new(), |mut acc, cert_info| {
+                acc.entry(cert_info.0.factory_id.to_string())
+                    .or_insert(vec![])
+                    .push(cert_info);
+                acc
+            });
+
+    Ok(json!({
+        "data": factory_results.into_iter()
+            .map(|factory| {
+                let org_id = factory.organization_id.clone();
+                ApiFactory::with_certificate_expanded(
+                    factory,
+                    address_results.remove(&org_id).unwrap_or_else(|| Address::default()),
+                    contact_results.remove(&org_id).unwrap_or_else(|| vec![]),
+                    authorization_results.remove(&org_id).unwrap_or_else(|| vec![]),
+                    cert_results.remove(&org_id).unwrap_or_else(|| vec![]),
+                )
+            }).collect::<Vec<_>>(),
+        "link": format!("/api/retailers/{}/suppliers?head={}", organization_id, head_block_num),
+        "head": head_block_num,
+    }))
+}
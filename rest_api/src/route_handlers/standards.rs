@@ -1,6 +1,7 @@
 use database::DbConn;
-use database_manager::models::{Standard, StandardVersion};
-use database_manager::tables_schema::standards;
+use database_manager::custom_types::{DataFieldTypeEnum, StandardStatusEnum};
+use database_manager::models::{Standard, StandardDataField, StandardVersion};
+use database_manager::tables_schema::{standard_data_fields, standard_versions, standards};
 use diesel::prelude::*;
 use errors::ApiError;
 use paging::get_head_block_num;
@@ -11,6 +12,7 @@ use std::collections::HashMap;
 #[derive(Default, FromForm, Clone)]
 pub struct StandardParams {
     organization_id: Option<String>,
+    status: Option<String>,
     head: Option<i64>,
 }
 
@@ -19,6 +21,7 @@ pub struct ApiStandard {
     standard_id: String,
     organization_id: String,
     name: String,
+    status: StandardStatusEnum,
     versions: Vec<ApiVersion>,
 }
 
@@ -28,6 +31,32 @@ pub struct ApiVersion {
     external_link: String,
     description: String,
     approval_date: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    retirement_date: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    data_schema: Option<Vec<ApiDataField>>,
+}
+
+#[derive(Serialize)]
+pub struct ApiDataField {
+    name: String,
+    data_type: DataFieldTypeEnum,
+    required: bool,
+    allowed_values: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pattern: Option<String>,
+}
+
+impl<'a> From<&'a StandardDataField> for ApiDataField {
+    fn from(data_field: &StandardDataField) -> Self {
+        ApiDataField {
+            name: data_field.name.clone(),
+            data_type: data_field.data_type.clone(),
+            required: data_field.required,
+            allowed_values: data_field.allowed_values.clone(),
+            pattern: data_field.pattern.clone(),
+        }
+    }
 }
 
 impl From<(Standard, Vec<StandardVersion>)> for ApiStandard {
@@ -37,6 +66,7 @@ impl From<(Standard, Vec<StandardVersion>)> for ApiStandard {
             standard_id: standard.standard_id,
             organization_id: standard.organization_id,
             name: standard.name,
+            status: standard.status,
             versions: version
                 .iter()
                 .map(|version| ApiVersion {
@@ -44,6 +74,8 @@ impl From<(Standard, Vec<StandardVersion>)> for ApiStandard {
                     external_link: version.link.clone(),
                     description: version.description.clone(),
                     approval_date: version.approval_date,
+                    retirement_date: version.retirement_date,
+                    data_schema: None,
                 })
                 .collect::<Vec<ApiVersion>>(),
         }
@@ -57,6 +89,7 @@ impl<'a> From<(&'a Standard, &'a Vec<StandardVersion>)> for ApiStandard {
             standard_id: standard.standard_id.clone(),
             organization_id: standard.organization_id.clone(),
             name: standard.name.clone(),
+            status: standard.status.clone(),
             versions: version
                 .iter()
                 .map(|version| ApiVersion {
@@ -64,12 +97,81 @@ impl<'a> From<(&'a Standard, &'a Vec<StandardVersion>)> for ApiStandard {
                     external_link: version.link.clone(),
                     description: version.description.clone(),
                     approval_date: version.approval_date,
+                    retirement_date: version.retirement_date,
+                    data_schema: None,
                 })
                 .collect::<Vec<ApiVersion>>(),
         }
     }
 }
 
+#[get("/standards/<standard_id>")]
+pub fn fetch_standard(standard_id: String, conn: DbConn) -> Result<JsonValue, ApiError> {
+    fetch_standard_with_head_param(standard_id, None, conn)
+}
+
+#[get("/standards/<standard_id>?<head_param..>")]
+pub fn fetch_standard_with_head_param(
+    standard_id: String,
+    head_param: Option<Form<StandardParams>>,
+    conn: DbConn,
+) -> Result<JsonValue, ApiError> {
+    let head_param = match head_param {
+        Some(param) => param.into_inner(),
+        None => Default::default()
+    };
+    let head_block_num: i64 = get_head_block_num(head_param.head, &conn)?;
+
+    let standard = standards::table
+        .filter(standards::standard_id.eq(standard_id.to_string()))
+        .filter(standards::start_block_num.le(head_block_num))
+        .filter(standards::end_block_num.gt(head_block_num))
+        .first::<Standard>(&*conn)
+        .optional()
+        .map_err(|err| ApiError::InternalError(err.to_string()))?;
+
+    match standard {
+        Some(standard) => {
+            let versions = standard_versions::table
+                .filter(standard_versions::standard_id.eq(standard_id.to_string()))
+                .filter(standard_versions::start_block_num.le(head_block_num))
+                .filter(standard_versions::end_block_num.gt(head_block_num))
+                .order_by(standard_versions::approval_date.asc())
+                .load::<StandardVersion>(&*conn)
+                .map_err(|err| ApiError::InternalError(err.to_string()))?;
+
+            let data_fields = standard_data_fields::table
+                .filter(standard_data_fields::standard_id.eq(standard_id.to_string()))
+                .filter(standard_data_fields::start_block_num.le(head_block_num))
+                .filter(standard_data_fields::end_block_num.gt(head_block_num))
+                .order_by(standard_data_fields::id.asc())
+                .load::<StandardDataField>(&*conn)
+                .map_err(|err| ApiError::InternalError(err.to_string()))?;
+
+            let mut api_standard = ApiStandard::from((&standard, &versions));
+            for api_version in &mut api_standard.versions {
+                api_version.data_schema = Some(
+                    data_fields
+                        .iter()
+                        .filter(|data_field| data_field.version == api_version.version)
+                        .map(ApiDataField::from)
+                        .collect(),
+                );
+            }
+
+            let link = format!("/api/standards/{}?head={}", standard_id, head_block_num);
+            Ok(json!({
+                "data": api_standard,
+                "link": link,
+                "head": head_block_num, }))
+        }
+        None => Err(ApiError::NotFound(format!(
+            "No standard with the standard id {} exists",
+            standard_id
+        ))),
+    }
+}
+
 #[get("/standards")]
 pub fn list_standards(conn: DbConn) -> Result<JsonValue, ApiError> {
     list_standards_with_params(None, conn)
@@ -94,6 +196,18 @@ pub fn list_standards_with_params(
         standards_query =
             standards_query.filter(standards::organization_id.eq(organization_id.to_string()));
     }
+    if let Some(status) = params.status {
+        standards_query = match status.as_ref() {
+            "ACTIVE" => standards_query.filter(standards::status.ne(StandardStatusEnum::Retired)),
+            "RETIRED" => standards_query.filter(standards::status.eq(StandardStatusEnum::Retired)),
+            _ => {
+                return Err(ApiError::BadRequest(format!(
+                    "Invalid standard status: {}",
+                    status
+                )))
+            }
+        };
+    }
 
     let standards = standards_query
         .load::<Standard>(&*conn)
@@ -1,5 +1,9 @@
 use common::addressing::{get_address_type, get_family_namespace_prefix, AddressSpace};
-use common::proto::{agent, certificate, organization, request, standard};
+use common::events::EVENT_PREFIX;
+use common::proto::{
+    accreditation_request, agent, audit, certificate, non_conformance, organization, proposal,
+    registry, request, standard,
+};
 use database::{
     custom_types::*,
     data_manager::{DataManager, OperationType, MAX_BLOCK_NUM},
@@ -42,6 +46,10 @@ impl EventHandler {
         for change in state_changes {
             operations.push(self.parse_operation(&change, &block)?);
         }
+        let family_events = self.parse_family_events(&events, &block);
+        if !family_events.is_empty() {
+            operations.push(OperationType::CreateEvents(family_events));
+        }
         Ok((block, operations))
     }
 
@@ -97,6 +105,44 @@ impl EventHandler {
             .collect())
     }
 
+    /// Converts the Certificate Registry events emitted in a block into objects that can be
+    /// inserted in the database. Each event is identified by the block id and its position
+    /// among the block's events.
+    fn parse_family_events(
+        &self,
+        events: &[Event],
+        block: &Block,
+    ) -> Vec<(NewEvent, Vec<NewEventAttribute>)> {
+        events
+            .into_iter()
+            .filter(|e| e.get_event_type().starts_with(EVENT_PREFIX))
+            .enumerate()
+            .map(|(index, event)| {
+                let event_id = format!("{}-{}", block.block_id, index);
+                let attributes = event
+                    .get_attributes()
+                    .iter()
+                    .map(|attribute| NewEventAttribute {
+                        start_block_num: block.block_num,
+                        end_block_num: MAX_BLOCK_NUM,
+                        event_id: event_id.to_string(),
+                        key: attribute.get_key().to_string(),
+                        value: attribute.get_value().to_string(),
+                    })
+                    .collect();
+                (
+                    NewEvent {
+                        start_block_num: block.block_num,
+                        end_block_num: MAX_BLOCK_NUM,
+                        event_id,
+                        event_type: event.get_event_type().to_string(),
+                    },
+                    attributes,
+                )
+            })
+            .collect()
+    }
+
     fn get_namespace_regex(&self) -> Regex {
         let namespace = get_family_namespace_prefix();
         Regex::new(&format!(r"^{}", namespace)).unwrap()
@@ -153,6 +199,36 @@ impl EventHandler {
                     OperationType::CreateRequest(request_container.to_models(block.block_num));
                 Ok(transaction)
             }
+            AddressSpace::Audit => {
+                let mut audit_container: audit::AuditContainer =
+                    Self::unpack_data(state.get_value());
+                let transaction =
+                    OperationType::CreateAudit(audit_container.to_models(block.block_num));
+                Ok(transaction)
+            }
+            AddressSpace::NonConformance => {
+                let mut non_conformance_container: non_conformance::NonConformanceContainer =
+                    Self::unpack_data(state.get_value());
+                let transaction = OperationType::CreateNonConformance(
+                    non_conformance_container.to_models(block.block_num),
+                );
+                Ok(transaction)
+            }
+            AddressSpace::Proposal => {
+                let mut proposal_container: proposal::ProposalContainer =
+                    Self::unpack_data(state.get_value());
+                let transaction =
+                    OperationType::CreateProposal(proposal_container.to_models(block.block_num));
+                Ok(transaction)
+            }
+            AddressSpace::AccreditationRequest => {
+                let mut accreditation_request_container: accreditation_request::AccreditationRequestContainer =
+                    Self::unpack_data(state.get_value());
+                let transaction = OperationType::CreateAccreditationRequest(
+                    accreditation_request_container.to_models(block.block_num),
+                );
+                Ok(transaction)
+            }
             AddressSpace::Standard => {
                 let mut standard_container: standard::StandardContainer =
                     Self::unpack_data(state.get_value());
@@ -160,6 +236,21 @@ impl EventHandler {
                     OperationType::CreateStandard(standard_container.to_models(block.block_num));
                 Ok(transaction)
             }
+            AddressSpace::Registry => {
+                let registry: registry::Registry = Self::unpack_data(state.get_value());
+                let transaction = OperationType::CreateRegistryOperators(
+                    registry
+                        .get_operators()
+                        .iter()
+                        .map(|public_key| NewRegistryOperator {
+                            public_key: public_key.to_string(),
+                            start_block_num: block.block_num,
+                            end_block_num: MAX_BLOCK_NUM,
+                        })
+                        .collect(),
+                );
+                Ok(transaction)
+            }
             AddressSpace::AnotherFamily => Err(SubscriberError::EventParseError(
                 "Address didnt match any existent state data
                 types in the Certificate Registry Namespace."
@@ -180,6 +271,7 @@ impl FromStateAtBlock<organization::Organization>
         Option<NewAddress>,
         Vec<NewAuthorization>,
         Vec<NewContact>,
+        Option<Vec<NewRetailerFactory>>,
     )
 {
     fn at_block(block_num: i64, org: &organization::Organization) -> Self {
@@ -194,8 +286,17 @@ impl FromStateAtBlock<organization::Organization>
                     OrganizationTypeEnum::StandardsBody
                 }
                 organization::Organization_Type::FACTORY => OrganizationTypeEnum::Factory,
+                organization::Organization_Type::RETAILER => OrganizationTypeEnum::Retailer,
                 organization::Organization_Type::UNSET_TYPE => OrganizationTypeEnum::UnsetType,
             },
+            approval_status: match org.approval_status {
+                organization::Organization_ApprovalStatus::PENDING => ApprovalStatusEnum::Pending,
+                organization::Organization_ApprovalStatus::APPROVED => ApprovalStatusEnum::Approved,
+                organization::Organization_ApprovalStatus::REJECTED => ApprovalStatusEnum::Rejected,
+                organization::Organization_ApprovalStatus::UNSET_APPROVAL => {
+                    ApprovalStatusEnum::UnsetApproval
+                }
+            },
             start_block_num: block_num,
             end_block_num: MAX_BLOCK_NUM,
         };
@@ -232,10 +333,23 @@ impl FromStateAtBlock<organization::Organization>
                     organization::Organization_Authorization_Role::TRANSACTOR => {
                         RoleEnum::Transactor
                     }
+                    organization::Organization_Authorization_Role::CUSTOM => RoleEnum::Custom,
                     organization::Organization_Authorization_Role::UNSET_ROLE => {
                         RoleEnum::UnsetRole
                     }
                 },
+                role_name: match auth.get_role_name() {
+                    "" => None,
+                    role_name => Some(role_name.to_string()),
+                },
+                valid_from: match auth.get_valid_from() {
+                    0 => None,
+                    valid_from => Some(valid_from as i64),
+                },
+                valid_to: match auth.get_valid_to() {
+                    0 => None,
+                    valid_to => Some(valid_to as i64),
+                },
                 start_block_num: block_num,
                 end_block_num: MAX_BLOCK_NUM,
             })
@@ -283,12 +397,29 @@ impl FromStateAtBlock<organization::Organization>
             _ => None,
         };
 
+        let new_suppliers = match org.get_organization_type() {
+            organization::Organization_Type::RETAILER => Some(
+                org.get_retailer_details()
+                    .get_supplier_ids()
+                    .iter()
+                    .map(|factory_id| NewRetailerFactory {
+                        retailer_id: org.id.clone(),
+                        factory_id: factory_id.to_string(),
+                        start_block_num: block_num,
+                        end_block_num: MAX_BLOCK_NUM,
+                    })
+                    .collect(),
+            ),
+            _ => None,
+        };
+
         (
             new_org,
             new_accreditations,
             new_address,
             new_auths,
             new_contacts,
+            new_suppliers,
         )
     }
 }
@@ -304,6 +435,10 @@ impl FromStateAtBlock<agent::Agent> for NewAgent {
             },
             name: agent.get_name().to_string(),
             timestamp: agent.get_timestamp() as i64,
+            successor_public_key: match agent.get_successor_public_key() {
+                "" => None,
+                _ => Some(agent.get_successor_public_key().to_string()),
+            },
             start_block_num: block_num,
             end_block_num: MAX_BLOCK_NUM,
         }
@@ -311,9 +446,15 @@ impl FromStateAtBlock<agent::Agent> for NewAgent {
 }
 
 containerize!(certificate::Certificate, certificate::CertificateContainer);
-impl FromStateAtBlock<certificate::Certificate> for NewCertificate {
+impl FromStateAtBlock<certificate::Certificate>
+    for (
+        NewCertificate,
+        Vec<NewCertificateSuspension>,
+        Vec<NewDocumentReference>,
+    )
+{
     fn at_block(block_num: i64, certificate: &certificate::Certificate) -> Self {
-        NewCertificate {
+        let new_certificate = NewCertificate {
             certificate_id: certificate.get_id().to_string(),
             certifying_body_id: certificate.get_certifying_body_id().to_string(),
             factory_id: certificate.get_factory_id().to_string(),
@@ -321,16 +462,72 @@ impl FromStateAtBlock<certificate::Certificate> for NewCertificate {
             standard_version: certificate.get_standard_version().to_string(),
             valid_from: certificate.get_valid_from() as i64,
             valid_to: certificate.get_valid_to() as i64,
+            status: match certificate.get_status() {
+                certificate::Certificate_Status::VALID => CertificateStatusEnum::Valid,
+                certificate::Certificate_Status::REVOKED => CertificateStatusEnum::Revoked,
+                certificate::Certificate_Status::SUSPENDED => CertificateStatusEnum::Suspended,
+                certificate::Certificate_Status::UNSET_STATUS => CertificateStatusEnum::UnsetStatus,
+            },
+            revoked_at: match certificate.get_status() {
+                certificate::Certificate_Status::REVOKED => {
+                    Some(certificate.get_revoked_at() as i64)
+                }
+                _ => None,
+            },
+            previous_certificate_id: match certificate.get_previous_certificate_id() {
+                "" => None,
+                _ => Some(certificate.get_previous_certificate_id().to_string()),
+            },
+            audit_id: match certificate.get_audit_id() {
+                "" => None,
+                _ => Some(certificate.get_audit_id().to_string()),
+            },
             start_block_num: block_num,
             end_block_num: MAX_BLOCK_NUM,
-        }
+        };
+
+        let new_suspensions = certificate
+            .get_suspensions()
+            .iter()
+            .map(|suspension| NewCertificateSuspension {
+                certificate_id: certificate.get_id().to_string(),
+                reason: suspension.get_reason().to_string(),
+                suspended_at: suspension.get_suspended_at() as i64,
+                reinstated_at: match suspension.get_reinstated_at() {
+                    0 => None,
+                    reinstated_at => Some(reinstated_at as i64),
+                },
+                start_block_num: block_num,
+                end_block_num: MAX_BLOCK_NUM,
+            })
+            .collect();
+
+        let new_documents = certificate
+            .get_documents()
+            .iter()
+            .map(|document| NewDocumentReference {
+                certificate_id: Some(certificate.get_id().to_string()),
+                request_id: None,
+                sha256: document.get_sha256().to_string(),
+                media_type: document.get_media_type().to_string(),
+                title: document.get_title().to_string(),
+                uri: match document.get_uri() {
+                    "" => None,
+                    uri => Some(uri.to_string()),
+                },
+                start_block_num: block_num,
+                end_block_num: MAX_BLOCK_NUM,
+            })
+            .collect();
+
+        (new_certificate, new_suspensions, new_documents)
     }
 }
 
 containerize!(request::Request, request::RequestContainer);
-impl FromStateAtBlock<request::Request> for NewRequest {
+impl FromStateAtBlock<request::Request> for (NewRequest, Vec<NewDocumentReference>) {
     fn at_block(block_num: i64, request: &request::Request) -> Self {
-        NewRequest {
+        let new_request = NewRequest {
             request_id: request.get_id().to_string(),
             factory_id: request.get_factory_id().to_string(),
             standard_id: request.get_standard_id().to_string(),
@@ -339,9 +536,217 @@ impl FromStateAtBlock<request::Request> for NewRequest {
                 request::Request_Status::IN_PROGRESS => RequestStatusEnum::InProgress,
                 request::Request_Status::CLOSED => RequestStatusEnum::Closed,
                 request::Request_Status::CERTIFIED => RequestStatusEnum::Certified,
+                request::Request_Status::ACCEPTED => RequestStatusEnum::Accepted,
+                request::Request_Status::DECLINED => RequestStatusEnum::Declined,
                 request::Request_Status::UNSET_STATUS => RequestStatusEnum::UnsetStatus,
             },
             request_date: request.get_request_date() as i64,
+            certifying_body_id: match request.get_certifying_body_id() {
+                "" => None,
+                _ => Some(request.get_certifying_body_id().to_string()),
+            },
+            decline_reason: match request.get_decline_reason() {
+                "" => None,
+                _ => Some(request.get_decline_reason().to_string()),
+            },
+            start_block_num: block_num,
+            end_block_num: MAX_BLOCK_NUM,
+        };
+
+        let new_documents = request
+            .get_documents()
+            .iter()
+            .map(|document| NewDocumentReference {
+                certificate_id: None,
+                request_id: Some(request.get_id().to_string()),
+                sha256: document.get_sha256().to_string(),
+                media_type: document.get_media_type().to_string(),
+                title: document.get_title().to_string(),
+                uri: match document.get_uri() {
+                    "" => None,
+                    uri => Some(uri.to_string()),
+                },
+                start_block_num: block_num,
+                end_block_num: MAX_BLOCK_NUM,
+            })
+            .collect();
+
+        (new_request, new_documents)
+    }
+}
+
+containerize!(audit::Audit, audit::AuditContainer);
+impl FromStateAtBlock<audit::Audit> for (NewAudit, Vec<NewAuditFinding>) {
+    fn at_block(block_num: i64, audit: &audit::Audit) -> Self {
+        let new_audit = NewAudit {
+            audit_id: audit.get_id().to_string(),
+            request_id: audit.get_request_id().to_string(),
+            factory_id: audit.get_factory_id().to_string(),
+            certifying_body_id: audit.get_certifying_body_id().to_string(),
+            auditor_public_key: audit.get_auditor_public_key().to_string(),
+            audit_date: audit.get_audit_date() as i64,
+            outcome: match audit.get_outcome() {
+                audit::Audit_Outcome::PASSED => AuditOutcomeEnum::Passed,
+                audit::Audit_Outcome::FAILED => AuditOutcomeEnum::Failed,
+                audit::Audit_Outcome::UNSET_OUTCOME => AuditOutcomeEnum::UnsetOutcome,
+            },
+            start_block_num: block_num,
+            end_block_num: MAX_BLOCK_NUM,
+        };
+
+        let new_findings = audit
+            .get_findings()
+            .iter()
+            .map(|finding| NewAuditFinding {
+                audit_id: audit.get_id().to_string(),
+                description: finding.get_description().to_string(),
+                severity: match finding.get_severity() {
+                    audit::Audit_Finding_Severity::MINOR => FindingSeverityEnum::Minor,
+                    audit::Audit_Finding_Severity::MAJOR => FindingSeverityEnum::Major,
+                    audit::Audit_Finding_Severity::CRITICAL => FindingSeverityEnum::Critical,
+                    audit::Audit_Finding_Severity::UNSET_SEVERITY => {
+                        FindingSeverityEnum::UnsetSeverity
+                    }
+                },
+                start_block_num: block_num,
+                end_block_num: MAX_BLOCK_NUM,
+            })
+            .collect();
+
+        (new_audit, new_findings)
+    }
+}
+
+containerize!(
+    non_conformance::NonConformance,
+    non_conformance::NonConformanceContainer
+);
+impl FromStateAtBlock<non_conformance::NonConformance> for NewNonConformance {
+    fn at_block(block_num: i64, non_conformance: &non_conformance::NonConformance) -> Self {
+        NewNonConformance {
+            non_conformance_id: non_conformance.get_id().to_string(),
+            factory_id: non_conformance.get_factory_id().to_string(),
+            certifying_body_id: non_conformance.get_certifying_body_id().to_string(),
+            audit_id: match non_conformance.get_audit_id() {
+                "" => None,
+                _ => Some(non_conformance.get_audit_id().to_string()),
+            },
+            description: non_conformance.get_description().to_string(),
+            severity: match non_conformance.get_severity() {
+                audit::Audit_Finding_Severity::MINOR => FindingSeverityEnum::Minor,
+                audit::Audit_Finding_Severity::MAJOR => FindingSeverityEnum::Major,
+                audit::Audit_Finding_Severity::CRITICAL => FindingSeverityEnum::Critical,
+                audit::Audit_Finding_Severity::UNSET_SEVERITY => FindingSeverityEnum::UnsetSeverity,
+            },
+            status: match non_conformance.get_status() {
+                non_conformance::NonConformance_Status::OPEN => NonConformanceStatusEnum::Open,
+                non_conformance::NonConformance_Status::CORRECTIVE_ACTION_SUBMITTED => {
+                    NonConformanceStatusEnum::CorrectiveActionSubmitted
+                }
+                non_conformance::NonConformance_Status::CLOSED => NonConformanceStatusEnum::Closed,
+                non_conformance::NonConformance_Status::UNSET_STATUS => {
+                    NonConformanceStatusEnum::UnsetStatus
+                }
+            },
+            corrective_action_plan: match non_conformance.get_corrective_action_plan() {
+                "" => None,
+                _ => Some(non_conformance.get_corrective_action_plan().to_string()),
+            },
+            raised_at: non_conformance.get_raised_at() as i64,
+            corrective_action_submitted_at: match non_conformance
+                .get_corrective_action_submitted_at()
+            {
+                0 => None,
+                submitted_at => Some(submitted_at as i64),
+            },
+            closed_at: match non_conformance.get_closed_at() {
+                0 => None,
+                closed_at => Some(closed_at as i64),
+            },
+            start_block_num: block_num,
+            end_block_num: MAX_BLOCK_NUM,
+        }
+    }
+}
+
+containerize!(proposal::Proposal, proposal::ProposalContainer);
+impl FromStateAtBlock<proposal::Proposal> for (NewProposal, Vec<NewProposalVote>) {
+    fn at_block(block_num: i64, proposal: &proposal::Proposal) -> Self {
+        let new_proposal = NewProposal {
+            proposal_id: proposal.get_id().to_string(),
+            organization_id: proposal.get_organization_id().to_string(),
+            proposer: proposal.get_proposer().to_string(),
+            action: format!("{:?}", proposal.get_payload().get_action()),
+            payload: protobuf::text_format::print_to_string(proposal.get_payload()),
+            required_approvals: proposal.get_required_approvals() as i32,
+            status: match proposal.get_status() {
+                proposal::Proposal_Status::OPEN => ProposalStatusEnum::Open,
+                proposal::Proposal_Status::ACCEPTED => ProposalStatusEnum::Accepted,
+                proposal::Proposal_Status::REJECTED => ProposalStatusEnum::Rejected,
+                proposal::Proposal_Status::WITHDRAWN => ProposalStatusEnum::Withdrawn,
+                proposal::Proposal_Status::UNSET_STATUS => ProposalStatusEnum::UnsetStatus,
+            },
+            proposed_at: proposal.get_proposed_at() as i64,
+            start_block_num: block_num,
+            end_block_num: MAX_BLOCK_NUM,
+        };
+
+        let new_votes = proposal
+            .get_votes()
+            .iter()
+            .map(|vote| NewProposalVote {
+                proposal_id: proposal.get_id().to_string(),
+                public_key: vote.get_public_key().to_string(),
+                accept: vote.get_accept(),
+                start_block_num: block_num,
+                end_block_num: MAX_BLOCK_NUM,
+            })
+            .collect();
+
+        (new_proposal, new_votes)
+    }
+}
+
+containerize!(
+    accreditation_request::AccreditationRequest,
+    accreditation_request::AccreditationRequestContainer
+);
+impl FromStateAtBlock<accreditation_request::AccreditationRequest> for NewAccreditationRequest {
+    fn at_block(
+        block_num: i64,
+        accreditation_request: &accreditation_request::AccreditationRequest,
+    ) -> Self {
+        NewAccreditationRequest {
+            accreditation_request_id: accreditation_request.get_id().to_string(),
+            certifying_body_id: accreditation_request.get_certifying_body_id().to_string(),
+            standards_body_id: accreditation_request.get_standards_body_id().to_string(),
+            standard_id: accreditation_request.get_standard_id().to_string(),
+            status: match accreditation_request.get_status() {
+                accreditation_request::AccreditationRequest_Status::OPEN => {
+                    AccreditationRequestStatusEnum::Open
+                }
+                accreditation_request::AccreditationRequest_Status::IN_REVIEW => {
+                    AccreditationRequestStatusEnum::InReview
+                }
+                accreditation_request::AccreditationRequest_Status::APPROVED => {
+                    AccreditationRequestStatusEnum::Approved
+                }
+                accreditation_request::AccreditationRequest_Status::REJECTED => {
+                    AccreditationRequestStatusEnum::Rejected
+                }
+                accreditation_request::AccreditationRequest_Status::UNSET_STATUS => {
+                    AccreditationRequestStatusEnum::UnsetStatus
+                }
+            },
+            request_date: accreditation_request.get_request_date() as i64,
+            rejection_reason: match accreditation_request.get_rejection_reason() {
+                "" => None,
+                _ => Some(accreditation_request.get_rejection_reason().to_string()),
+            },
+            standard_version: match accreditation_request.get_standard_version() {
+                "" => None,
+                _ => Some(accreditation_request.get_standard_version().to_string()),
+            },
             start_block_num: block_num,
             end_block_num: MAX_BLOCK_NUM,
         }
@@ -349,12 +754,24 @@ impl FromStateAtBlock<request::Request> for NewRequest {
 }
 
 containerize!(standard::Standard, standard::StandardContainer);
-impl FromStateAtBlock<standard::Standard> for (NewStandard, Vec<NewStandardVersion>) {
+impl FromStateAtBlock<standard::Standard>
+    for (
+        NewStandard,
+        Vec<NewStandardVersion>,
+        Vec<NewStandardDataField>,
+        Vec<NewStandardEquivalence>,
+    )
+{
     fn at_block(block_num: i64, standard: &standard::Standard) -> Self {
         let db_standard = NewStandard {
             standard_id: standard.id.clone(),
             organization_id: standard.organization_id.clone(),
             name: standard.name.clone(),
+            status: match standard.status {
+                standard::Standard_Status::ACTIVE => StandardStatusEnum::Active,
+                standard::Standard_Status::RETIRED => StandardStatusEnum::Retired,
+                standard::Standard_Status::UNSET_STATUS => StandardStatusEnum::UnsetStatus,
+            },
             start_block_num: block_num,
             end_block_num: MAX_BLOCK_NUM,
         };
@@ -368,11 +785,69 @@ impl FromStateAtBlock<standard::Standard> for (NewStandard, Vec<NewStandardVersi
                 link: version.link.clone(),
                 description: version.description.clone(),
                 approval_date: version.approval_date as i64,
+                retirement_date: match version.retirement_date {
+                    0 => None,
+                    retirement_date => Some(retirement_date as i64),
+                },
+                start_block_num: block_num,
+                end_block_num: MAX_BLOCK_NUM,
+            })
+            .collect();
+
+        let db_data_fields = standard
+            .versions
+            .iter()
+            .flat_map(|version| {
+                version
+                    .data_schema
+                    .iter()
+                    .map(move |field| NewStandardDataField {
+                        standard_id: standard.id.clone(),
+                        version: version.version.clone(),
+                        name: field.get_name().to_string(),
+                        data_type: match field.get_data_type() {
+                            standard::Standard_DataField_Type::STRING => DataFieldTypeEnum::String,
+                            standard::Standard_DataField_Type::INTEGER => {
+                                DataFieldTypeEnum::Integer
+                            }
+                            standard::Standard_DataField_Type::DECIMAL => {
+                                DataFieldTypeEnum::Decimal
+                            }
+                            standard::Standard_DataField_Type::BOOLEAN => {
+                                DataFieldTypeEnum::Boolean
+                            }
+                            standard::Standard_DataField_Type::ENUMERATION => {
+                                DataFieldTypeEnum::Enumeration
+                            }
+                            standard::Standard_DataField_Type::UNSET_TYPE => {
+                                DataFieldTypeEnum::UnsetType
+                            }
+                        },
+                        required: field.get_required(),
+                        allowed_values: field.get_allowed_values().to_vec(),
+                        pattern: match field.get_pattern() {
+                            "" => None,
+                            pattern => Some(pattern.to_string()),
+                        },
+                        start_block_num: block_num,
+                        end_block_num: MAX_BLOCK_NUM,
+                    })
+            })
+            .collect();
+
+        let db_equivalences = standard
+            .equivalences
+            .iter()
+            .map(|equivalence| NewStandardEquivalence {
+                standard_id: standard.id.clone(),
+                version: equivalence.version.clone(),
+                equivalent_standard_id: equivalence.equivalent_standard_id.clone(),
+                equivalent_standard_version: equivalence.equivalent_standard_version.clone(),
                 start_block_num: block_num,
                 end_block_num: MAX_BLOCK_NUM,
             })
             .collect();
 
-        (db_standard, db_versions)
+        (db_standard, db_versions, db_data_fields, db_equivalences)
     }
 }
@@ -1,4 +1,5 @@
 use common::addressing::get_family_namespace_prefix;
+use common::events::EVENT_TYPES;
 use errors::SubscriberError;
 use event_handler::EventHandler;
 use protobuf;
@@ -19,7 +20,7 @@ use uuid::Uuid;
 const NULL_BLOCK_ID: &str = "0000000000000000";
 const KNOWN_COUNT: usize = 10;
 
-/// Subscribes to the validator for block-commit and state-delta events
+/// Subscribes to the validator for block-commit, state-delta and Certificate Registry events
 /// Listens to events and calls the event handler to parse event and submit the data to the reporting database
 pub struct Subscriber {
     sender: ZmqMessageSender,
@@ -183,11 +184,12 @@ impl Subscriber {
         let block_subscription = self.get_block_commit_subscription();
         let state_delta_subscription = self.get_state_delta_subscription();
 
+        let mut subscriptions = vec![block_subscription, state_delta_subscription];
+        subscriptions.extend(self.get_family_event_subscriptions());
+
         let mut event_subscription_request = ClientEventsSubscribeRequest::new();
-        event_subscription_request.set_subscriptions(protobuf::RepeatedField::from_vec(vec![
-            block_subscription,
-            state_delta_subscription,
-        ]));
+        event_subscription_request
+            .set_subscriptions(protobuf::RepeatedField::from_vec(subscriptions));
         event_subscription_request.set_last_known_block_ids(protobuf::RepeatedField::from_vec(
             last_known_block_ids.to_vec(),
         ));
@@ -220,4 +222,15 @@ impl Subscriber {
         state_delta_subscription.set_filters(repeated_field);
         state_delta_subscription
     }
+
+    fn get_family_event_subscriptions(&self) -> Vec<EventSubscription> {
+        EVENT_TYPES
+            .iter()
+            .map(|event_type| {
+                let mut subscription = EventSubscription::new();
+                subscription.set_event_type(event_type.to_string());
+                subscription
+            })
+            .collect()
+    }
 }